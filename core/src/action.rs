@@ -1,3 +1,4 @@
 pub mod get;
+pub mod machine;
 pub mod plock;
 pub mod set;
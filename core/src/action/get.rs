@@ -8,6 +8,7 @@ use self::{
 
 pub mod global;
 pub mod kit;
+pub mod machine;
 pub mod pattern;
 pub mod settings;
 pub mod sound;
@@ -1,22 +1,38 @@
+use crate::address::validate_index;
 use crate::api::global_action_type::*;
 use crate::api::global_enum_type::*;
+use crate::atom::SymbolRef;
+use crate::atom::{Atom, AtomValue};
 use crate::error::EnumError::InvalidEnumType;
 use crate::error::IdentifierError;
 use crate::error::RytmExternalError;
-use median::atom::{Atom, AtomValue};
-use median::outlet::OutAnything;
-use median::symbol::SymbolRef;
+use crate::output::Outlet;
+use crate::selection::TRACK_COUNT;
 use rytm_rs::object::Global;
-use std::convert::TryFrom;
-use std::ffi::CString;
 
 pub fn handle_global_get_enum_value(
     global: &Global,
     enum_type: &str,
     enum_value: &str,
-    out: &OutAnything,
+    out: &Outlet,
 ) -> Result<(), RytmExternalError> {
-    let value: &str = match enum_type {
+    let value = global_get_enum_value(global, enum_type, enum_value)?;
+
+    let enum_type_atom = Atom::from(SymbolRef::from(enum_type));
+    let index_atom = Atom::from(AtomValue::Int(global.index() as isize));
+    let enum_value_atom = Atom::from(SymbolRef::from(value));
+
+    out.send(&[index_atom, enum_type_atom, enum_value_atom][..]);
+
+    Ok(())
+}
+
+pub fn global_get_enum_value(
+    global: &Global,
+    enum_type: &str,
+    enum_value: &str,
+) -> Result<&'static str, RytmExternalError> {
+    Ok(match enum_type {
         METRONOME_TIME_SIGNATURE => global.metronome_settings().time_signature().into(),
 
         ROUTING_USB_IN_OPTIONS => global.routing().usb_in().into(),
@@ -90,26 +106,32 @@ pub fn handle_global_get_enum_value(
         PERFORMANCE_CHANNEL => global.midi_config().channels().performance_channel().into(),
 
         other => return Err(InvalidEnumType(other.to_owned()).into()),
-    };
+    })
+}
+
+pub fn handle_global_get_action(
+    global: &Global,
+    action: &str,
+    maybe_next_atom: Option<&Atom>,
+    out: &Outlet,
+) -> Result<(), RytmExternalError> {
+    let value = global_get_value(global, action, maybe_next_atom)?;
 
-    let enum_type_atom = Atom::from(SymbolRef::try_from(enum_type).unwrap());
+    let action_atom = Atom::from(SymbolRef::from(action));
     let index_atom = Atom::from(AtomValue::Int(global.index() as isize));
-    let enum_value_atom = Atom::from(SymbolRef::try_from(value).unwrap());
+    let value_atom = Atom::from(value);
 
-    if let Err(_stack_overflow_err) = out.send(&[index_atom, enum_type_atom, enum_value_atom][..]) {
-        // Stack overflow ignore
-    }
+    out.send(&[index_atom, action_atom, value_atom][..]);
 
     Ok(())
 }
 
-pub fn handle_global_get_action(
+pub fn global_get_value(
     global: &Global,
     action: &str,
     maybe_next_atom: Option<&Atom>,
-    out: &OutAnything,
-) -> Result<(), RytmExternalError> {
-    let value: isize = match action {
+) -> Result<isize, RytmExternalError> {
+    Ok(match action {
         VERSION => global.structure_version() as isize,
         INDEX => global.index() as isize,
         IS_WORK_BUFFER => isize::from(global.is_work_buffer()),
@@ -121,10 +143,8 @@ pub fn handle_global_get_action(
         ROUTE_TO_MAIN => {
             if let Some(atom) = maybe_next_atom {
                 if let Some(AtomValue::Int(value)) = atom.get_value() {
-                    global
-                        .routing()
-                        .is_track_routed_to_main(value as usize)
-                        .into()
+                    let track_index = validate_index(value, TRACK_COUNT, "Track")?;
+                    global.routing().is_track_routed_to_main(track_index).into()
                 } else {
                     return Err(
                         "Invalid getter format: routetomain should be followed by an integer track index."
@@ -141,7 +161,8 @@ pub fn handle_global_get_action(
         SEND_TO_FX => {
             if let Some(atom) = maybe_next_atom {
                 if let Some(AtomValue::Int(value)) = atom.get_value() {
-                    global.routing().is_track_sent_to_fx(value as usize).into()
+                    let track_index = validate_index(value, TRACK_COUNT, "Track")?;
+                    global.routing().is_track_sent_to_fx(track_index).into()
                 } else {
                     return Err(
                         "Invalid getter format: sendtofx should be followed by a track index (integer)"
@@ -172,15 +193,5 @@ pub fn handle_global_get_action(
         METRONOME_VOLUME => global.metronome_settings().volume() as isize,
 
         other => return Err(IdentifierError::InvalidType(other.to_owned()).into()),
-    };
-
-    let action_atom = Atom::from(SymbolRef::from(CString::new(action).unwrap()));
-    let index_atom = Atom::from(AtomValue::Int(global.index() as isize));
-    let value_atom = Atom::from(value);
-
-    if let Err(_stack_overflow_err) = out.send(&[index_atom, action_atom, value_atom][..]) {
-        // Stack overflow ignore
-    }
-
-    Ok(())
+    })
 }
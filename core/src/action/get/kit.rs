@@ -2,29 +2,42 @@ use crate::api::kit_action_type::*;
 use crate::api::kit_element_type::*;
 use crate::api::kit_enum_type::*;
 use crate::api::sound_kit::handle_sound_kit_get;
+use crate::atom::SymbolRef;
+use crate::atom::{Atom, AtomValue};
 use crate::error::EnumError::InvalidEnumType;
 use crate::error::IdentifierError;
 use crate::error::RytmExternalError;
-use median::atom::{Atom, AtomValue};
-use median::outlet::OutAnything;
-use median::symbol::SymbolRef;
+use crate::output::Outlet;
 use rytm_rs::object::Kit;
 use rytm_rs::object::Sound;
-use std::convert::TryFrom;
-use std::ffi::CString;
 
 pub fn handle_kit_get_action(
     kit: &Kit,
     action: SymbolRef,
-    out: &OutAnything,
+    out: &Outlet,
     maybe_index_atom: Option<&Atom>,
 ) -> Result<(), RytmExternalError> {
-    let action_str = action.to_string()?;
+    let action_str = action.to_string();
 
-    let value_atom: Atom = match action_str.as_str() {
+    let value_atom = kit_get_value(kit, &action_str, maybe_index_atom)?;
+
+    let action_atom = Atom::from(action);
+    let kit_index_atom = Atom::from(AtomValue::Int(kit.index() as isize));
+
+    out.send(&[kit_index_atom, action_atom, value_atom][..]);
+
+    Ok(())
+}
+
+pub fn kit_get_value(
+    kit: &Kit,
+    action: &str,
+    maybe_index_atom: Option<&Atom>,
+) -> Result<Atom, RytmExternalError> {
+    Ok(match action {
         VERSION => (kit.structure_version() as isize).into(),
         INDEX => (kit.index() as isize).into(),
-        NAME => SymbolRef::from(CString::new(kit.name()).unwrap()).into(),
+        NAME => SymbolRef::from(kit.name().trim_end_matches(char::from(0))).into(),
 
         CONTROL_IN_1_MOD_AMT => {
             let index = maybe_index_atom.ok_or_else(|| {
@@ -95,31 +108,38 @@ pub fn handle_kit_get_action(
         FX_LFO_DEPTH => f64::from(kit.fx_lfo().depth()).into(),
 
         FX_DISTORTION_DELAY_OVERDRIVE => (kit.fx_distortion().delay_overdrive() as isize).into(),
-        FX_DISTORTION_DELAY_POST => (kit.fx_distortion().delay_post() as isize).into(),
-        FX_DISTORTION_REVERB_POST => (kit.fx_distortion().reverb_post() as isize).into(),
+        FX_DISTORTION_DELAY_POST => isize::from(kit.fx_distortion().delay_post()).into(),
+        FX_DISTORTION_REVERB_POST => isize::from(kit.fx_distortion().reverb_post()).into(),
         FX_DISTORTION_AMOUNT => (kit.fx_distortion().amount() as isize).into(),
-        FX_DISTORTION_SYMMETRY => (kit.fx_distortion().symmetry() as isize).into(),
+        FX_DISTORTION_SYMMETRY => kit.fx_distortion().symmetry().into(),
 
         other => return Err(IdentifierError::InvalidType(other.to_owned()).into()),
-    };
+    })
+}
 
-    let action_atom = Atom::from(action);
+pub fn handle_kit_get_enum_value(
+    kit: &Kit,
+    enum_type: &str,
+    enum_value: &str,
+    out: &Outlet,
+) -> Result<(), RytmExternalError> {
+    let enum_value = kit_get_enum_value(kit, enum_type, enum_value)?;
+
+    let enum_type_atom = Atom::from(SymbolRef::from(enum_type));
     let kit_index_atom = Atom::from(AtomValue::Int(kit.index() as isize));
+    let enum_value_atom = Atom::from(SymbolRef::from(enum_value));
 
-    if let Err(_stack_overflow_err) = out.send(&[kit_index_atom, action_atom, value_atom][..]) {
-        // Stack overflow ignore
-    }
+    out.send(&[kit_index_atom, enum_type_atom, enum_value_atom][..]);
 
     Ok(())
 }
 
-pub fn handle_kit_get_enum_value(
+pub fn kit_get_enum_value(
     kit: &Kit,
     enum_type: &str,
     enum_value: &str,
-    out: &OutAnything,
-) -> Result<(), RytmExternalError> {
-    let enum_value: &str = match enum_type {
+) -> Result<&'static str, RytmExternalError> {
+    Ok(match enum_type {
         CONTROL_IN_1_MOD_TARGET => match enum_value.parse::<usize>().map_err(|_| {
             RytmExternalError::from("Invalid getter format: ctrlinmod1target:<integer> is the correct format. Example: ctrlinmod1target:2")
         })? {
@@ -155,42 +175,47 @@ pub fn handle_kit_get_enum_value(
         FX_COMP_SIDE_CHAIN_EQ => (*kit.fx_compressor().side_chain_eq()).into(),
 
         other => return Err(InvalidEnumType(other.to_owned()).into()),
-    };
+    })
+}
+
+pub fn handle_kit_get_kit_element(
+    kit: &Kit,
+    element_type: &str,
+    element_index: usize,
+    out: &Outlet,
+) -> Result<(), RytmExternalError> {
+    let value_atom = kit_get_element_value(kit, element_type, element_index)?;
 
-    let enum_type_atom = Atom::from(SymbolRef::try_from(enum_type).unwrap());
+    let element_type_atom = Atom::from(SymbolRef::from(element_type));
     let kit_index_atom = Atom::from(AtomValue::Int(kit.index() as isize));
-    let enum_value_atom = Atom::from(SymbolRef::try_from(enum_value).unwrap());
+    let element_index_atom = Atom::from(AtomValue::Int(element_index as isize));
 
-    if let Err(_stack_overflow_err) =
-        out.send(&[kit_index_atom, enum_type_atom, enum_value_atom][..])
-    {
-        // Stack overflow ignore
-    }
+    out.send(
+        &[
+            kit_index_atom,
+            element_index_atom,
+            element_type_atom,
+            value_atom,
+        ][..],
+    );
 
     Ok(())
 }
 
-pub fn handle_kit_get_kit_element(
+pub fn kit_get_element_value(
     kit: &Kit,
     element_type: &str,
     element_index: usize,
-    out: &OutAnything,
-) -> Result<(), RytmExternalError> {
-    let value_atom: Atom = match element_type {
+) -> Result<Atom, RytmExternalError> {
+    Ok(match element_type {
         TRACK_LEVEL => (kit.track_level(element_index)? as isize).into(),
-        TRACK_RETRIG_RATE => (SymbolRef::from(
-            CString::new(Into::<&str>::into(
-                kit.track_retrig_settings(element_index)?.rate(),
-            ))
-            .unwrap(),
-        ))
+        TRACK_RETRIG_RATE => (SymbolRef::from(Into::<&str>::into(
+            kit.track_retrig_settings(element_index)?.rate(),
+        )))
         .into(),
-        TRACK_RETRIG_LENGTH => (SymbolRef::from(
-            CString::new(Into::<&str>::into(
-                kit.track_retrig_settings(element_index)?.length(),
-            ))
-            .unwrap(),
-        ))
+        TRACK_RETRIG_LENGTH => (SymbolRef::from(Into::<&str>::into(
+            kit.track_retrig_settings(element_index)?.length(),
+        )))
         .into(),
         TRACK_RETRIG_VEL_OFFSET => {
             (kit.track_retrig_settings(element_index)?.velocity_curve() as isize).into()
@@ -200,31 +225,14 @@ pub fn handle_kit_get_kit_element(
         }
 
         other => return Err(IdentifierError::InvalidType(other.to_owned()).into()),
-    };
-
-    let element_type_atom = Atom::from(SymbolRef::try_from(element_type).unwrap());
-    let kit_index_atom = Atom::from(AtomValue::Int(kit.index() as isize));
-    let element_index_atom = Atom::from(AtomValue::Int(element_index as isize));
-
-    if let Err(_stack_overflow_err) = out.send(
-        &[
-            kit_index_atom,
-            element_index_atom,
-            element_type_atom,
-            value_atom,
-        ][..],
-    ) {
-        // Stack overflow ignore
-    }
-
-    Ok(())
+    })
 }
 
 pub fn handle_kit_get_kit_sound(
     sound: &Sound,
     atoms: &[Atom],
     slice_from_index: usize,
-    out: &OutAnything,
+    out: &Outlet,
 ) -> Result<(), RytmExternalError> {
     handle_sound_kit_get(sound, &atoms[slice_from_index..], out)
 }
@@ -0,0 +1,397 @@
+use crate::action::machine::{
+    bd_acoustic_waveform_to_string, bd_sharp_waveform_to_string, invalid_machine_parameter,
+    sy_chip_speed_to_string, sy_chip_waveform_to_string, sy_raw_waveform_1_to_string,
+    sy_raw_waveform_2_to_string,
+};
+use crate::api::machine_parameter_type::*;
+use crate::api::sound_machine_enum_type::*;
+use crate::atom::Atom;
+use crate::atom::AtomValue;
+use crate::atom::SymbolRef;
+use crate::error::RytmExternalError;
+use crate::output::Outlet;
+use rytm_rs::object::sound::machine::MachineParameters;
+use rytm_rs::object::Sound;
+
+pub fn handle_machine_get_enum_value(
+    sound: &Sound,
+    enum_type: &str,
+    out: &Outlet,
+) -> Result<(), RytmExternalError> {
+    let value = machine_get_enum_value(sound, enum_type)?;
+
+    let enum_type_atom = Atom::from(SymbolRef::from(enum_type));
+    let index_atom = Atom::from(AtomValue::Int(sound.index() as isize));
+    let enum_value_atom = Atom::from(SymbolRef::from(value.as_str()));
+
+    out.send(&[index_atom, enum_type_atom, enum_value_atom][..]);
+
+    Ok(())
+}
+
+pub fn machine_get_enum_value(sound: &Sound, enum_type: &str) -> Result<String, RytmExternalError> {
+    let machine_type = sound.machine_type();
+    Ok(match (sound.machine_parameters(), enum_type) {
+        (MachineParameters::BdAcoustic(mp), BD_ACOUSTIC_WAVEFORM) => {
+            bd_acoustic_waveform_to_string(mp.get_wav())
+        }
+        (MachineParameters::BdSharp(mp), BD_SHARP_WAVEFORM) => {
+            bd_sharp_waveform_to_string(mp.get_wav())
+        }
+        (MachineParameters::SyChip(mp), SY_CHIP_WAVEFORM) => {
+            sy_chip_waveform_to_string(mp.get_wav())
+        }
+        (MachineParameters::SyChip(mp), SY_CHIP_SPEED) => sy_chip_speed_to_string(mp.get_spd()),
+        (MachineParameters::SyRaw(mp), SY_RAW_WAVEFORM_1) => {
+            sy_raw_waveform_1_to_string(mp.get_wav1())
+        }
+        (MachineParameters::SyRaw(mp), SY_RAW_WAVEFORM_2) => {
+            sy_raw_waveform_2_to_string(mp.get_wav2())
+        }
+        (_, other) => return Err(invalid_machine_parameter(machine_type, other)),
+    })
+}
+
+pub fn handle_machine_get_action(
+    sound: &Sound,
+    action: &str,
+    out: &Outlet,
+) -> Result<(), RytmExternalError> {
+    let value_atom = machine_get_value(sound, action)?;
+
+    let action_atom = Atom::from(SymbolRef::from(action));
+    let index_atom = Atom::from(AtomValue::Int(sound.index() as isize));
+    out.send(&[index_atom, action_atom, value_atom][..]);
+
+    Ok(())
+}
+
+pub fn machine_get_value(sound: &Sound, action: &str) -> Result<Atom, RytmExternalError> {
+    let machine_type = sound.machine_type();
+    Ok(match sound.machine_parameters() {
+        MachineParameters::BdHard(mp) => match action {
+            LEV => (mp.get_lev() as isize).into(),
+            TUN => f64::from(mp.get_tun()).into(),
+            DEC => (mp.get_dec() as isize).into(),
+            HLD => (mp.get_hld() as isize).into(),
+            SWT => (mp.get_swt() as isize).into(),
+            SNP => (mp.get_snp() as isize).into(),
+            WAV => (mp.get_wav() as isize).into(),
+            TIC => (mp.get_tic() as isize).into(),
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::BdClassic(mp) => match action {
+            LEV => (mp.get_lev() as isize).into(),
+            TUN => f64::from(mp.get_tun()).into(),
+            DEC => (mp.get_dec() as isize).into(),
+            HLD => (mp.get_hld() as isize).into(),
+            SWT => (mp.get_swt() as isize).into(),
+            SWD => (mp.get_swd() as isize).into(),
+            WAV => (mp.get_wav() as isize).into(),
+            TRA => (mp.get_tra() as isize).into(),
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::SdHard(mp) => match action {
+            LEV => (mp.get_lev() as isize).into(),
+            TUN => f64::from(mp.get_tun()).into(),
+            DEC => (mp.get_dec() as isize).into(),
+            SWD => (mp.get_swd() as isize).into(),
+            TIC => (mp.get_tic() as isize).into(),
+            NOD => (mp.get_nod() as isize).into(),
+            NOL => (mp.get_nol() as isize).into(),
+            SWT => (mp.get_swt() as isize).into(),
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::SdClassic(mp) => match action {
+            LEV => (mp.get_lev() as isize).into(),
+            TUN => f64::from(mp.get_tun()).into(),
+            DEC => (mp.get_dec() as isize).into(),
+            DET => f64::from(mp.get_det()).into(),
+            SNP => (mp.get_snp() as isize).into(),
+            NOD => (mp.get_nod() as isize).into(),
+            NOL => (mp.get_nol() as isize).into(),
+            BAL => mp.get_bal().into(),
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::RsHard(mp) => match action {
+            LEV => (mp.get_lev() as isize).into(),
+            TUN => f64::from(mp.get_tun()).into(),
+            DEC => (mp.get_dec() as isize).into(),
+            SWD => (mp.get_swd() as isize).into(),
+            TIC => (mp.get_tic() as isize).into(),
+            NOL => (mp.get_nol() as isize).into(),
+            SYN => (mp.get_syn() as isize).into(),
+            SWT => (mp.get_swt() as isize).into(),
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::RsClassic(mp) => match action {
+            LEV => (mp.get_lev() as isize).into(),
+            T1 => f64::from(mp.get_t1()).into(),
+            DEC => (mp.get_dec() as isize).into(),
+            BAL => mp.get_bal().into(),
+            T2 => f64::from(mp.get_t2()).into(),
+            SYM => mp.get_sym().into(),
+            NOL => (mp.get_nol() as isize).into(),
+            TIC => (mp.get_tic() as isize).into(),
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::CpClassic(mp) => match action {
+            LEV => (mp.get_lev() as isize).into(),
+            TON => (mp.get_ton() as isize).into(),
+            NOD => (mp.get_nod() as isize).into(),
+            NUM => (mp.get_num() as isize).into(),
+            RAT => (mp.get_rat() as isize).into(),
+            NOL => (mp.get_nol() as isize).into(),
+            RND => (mp.get_rnd() as isize).into(),
+            CPT => (mp.get_cpt() as isize).into(),
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::BtClassic(mp) => match action {
+            LEV => (mp.get_lev() as isize).into(),
+            TUN => mp.get_tun().into(),
+            DEC => (mp.get_dec() as isize).into(),
+            NOL => (mp.get_nol() as isize).into(),
+            SNP => (mp.get_snp() as isize).into(),
+            SWD => (mp.get_swd() as isize).into(),
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::XtClassic(mp) => match action {
+            LEV => (mp.get_lev() as isize).into(),
+            TUN => mp.get_tun().into(),
+            DEC => (mp.get_dec() as isize).into(),
+            SWD => (mp.get_swd() as isize).into(),
+            SWT => (mp.get_swt() as isize).into(),
+            NOD => (mp.get_nod() as isize).into(),
+            NOL => (mp.get_nol() as isize).into(),
+            TON => mp.get_ton().into(),
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::ChClassic(mp) => match action {
+            LEV => (mp.get_lev() as isize).into(),
+            TUN => mp.get_tun().into(),
+            DEC => (mp.get_dec() as isize).into(),
+            COL => mp.get_col().into(),
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::OhClassic(mp) => match action {
+            LEV => (mp.get_lev() as isize).into(),
+            TUN => mp.get_tun().into(),
+            DEC => (mp.get_dec() as isize).into(),
+            COL => mp.get_col().into(),
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::CyClassic(mp) => match action {
+            LEV => (mp.get_lev() as isize).into(),
+            TUN => mp.get_tun().into(),
+            DEC => (mp.get_dec() as isize).into(),
+            COL => mp.get_col().into(),
+            TON => mp.get_ton().into(),
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::CbClassic(mp) => match action {
+            LEV => (mp.get_lev() as isize).into(),
+            TUN => mp.get_tun().into(),
+            DEC => (mp.get_dec() as isize).into(),
+            DET => (mp.get_det() as isize).into(),
+            PW1 => mp.get_pw1().into(),
+            PW2 => mp.get_pw2().into(),
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::BdFm(mp) => match action {
+            LEV => (mp.get_lev() as isize).into(),
+            TUN => f64::from(mp.get_tun()).into(),
+            DEC => (mp.get_dec() as isize).into(),
+            FMA => (mp.get_fma() as isize).into(),
+            SWT => (mp.get_swt() as isize).into(),
+            FMS => (mp.get_fms() as isize).into(),
+            FMD => (mp.get_fmd() as isize).into(),
+            FMT => f64::from(mp.get_fmt()).into(),
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::SdFm(mp) => match action {
+            LEV => (mp.get_lev() as isize).into(),
+            TUN => f64::from(mp.get_tun()).into(),
+            DEC => (mp.get_dec() as isize).into(),
+            FMT => f64::from(mp.get_fmt()).into(),
+            FMD => (mp.get_fmd() as isize).into(),
+            NOD => (mp.get_nod() as isize).into(),
+            NOL => (mp.get_nol() as isize).into(),
+            FMA => (mp.get_fma() as isize).into(),
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::UtNoise(mp) => match action {
+            LEV => (mp.get_lev() as isize).into(),
+            LPF => (mp.get_lpf() as isize).into(),
+            DEC => (mp.get_dec() as isize).into(),
+            HPF => (mp.get_hpf() as isize).into(),
+            LPQ => (mp.get_lpq() as isize).into(),
+            ATK => (mp.get_atk() as isize).into(),
+            SWT => (mp.get_swt() as isize).into(),
+            SWD => mp.get_swd().into(),
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::UtImpulse(mp) => match action {
+            LEV => (mp.get_lev() as isize).into(),
+            ATK => (mp.get_atk() as isize).into(),
+            DEC => (mp.get_dec() as isize).into(),
+            POL => (mp.get_pol() as isize).into(),
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::ChMetallic(mp) => match action {
+            LEV => (mp.get_lev() as isize).into(),
+            TUN => mp.get_tun().into(),
+            DEC => (mp.get_dec() as isize).into(),
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::OhMetallic(mp) => match action {
+            LEV => (mp.get_lev() as isize).into(),
+            TUN => mp.get_tun().into(),
+            DEC => (mp.get_dec() as isize).into(),
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::CyMetallic(mp) => match action {
+            LEV => (mp.get_lev() as isize).into(),
+            TUN => mp.get_tun().into(),
+            DEC => (mp.get_dec() as isize).into(),
+            TON => mp.get_ton().into(),
+            TRD => (mp.get_trd() as isize).into(),
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::CbMetallic(mp) => match action {
+            LEV => (mp.get_lev() as isize).into(),
+            TUN => mp.get_tun().into(),
+            DEC => (mp.get_dec() as isize).into(),
+            DET => (mp.get_det() as isize).into(),
+            PW1 => mp.get_pw1().into(),
+            PW2 => mp.get_pw2().into(),
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::BdPlastic(mp) => match action {
+            LEV => (mp.get_lev() as isize).into(),
+            TUN => f64::from(mp.get_tun()).into(),
+            DEC => (mp.get_dec() as isize).into(),
+            TYP => (mp.get_typ() as isize).into(),
+            MOD => (mp.get_mod_level() as isize).into(),
+            SWT => (mp.get_swt() as isize).into(),
+            SWD => (mp.get_swd() as isize).into(),
+            TIC => (mp.get_tic() as isize).into(),
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::BdSilky(mp) => match action {
+            LEV => (mp.get_lev() as isize).into(),
+            TUN => f64::from(mp.get_tun()).into(),
+            DEC => (mp.get_dec() as isize).into(),
+            HLD => (mp.get_hld() as isize).into(),
+            SWT => (mp.get_swt() as isize).into(),
+            SWD => (mp.get_swd() as isize).into(),
+            DUS => (mp.get_dus() as isize).into(),
+            CLK => (mp.get_clk() as isize).into(),
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::SdNatural(mp) => match action {
+            LEV => (mp.get_lev() as isize).into(),
+            TUN => f64::from(mp.get_tun()).into(),
+            BDY => (mp.get_bdy() as isize).into(),
+            DEC => (mp.get_dec() as isize).into(),
+            BAL => (mp.get_bal() as isize).into(),
+            LPF => (mp.get_lpf() as isize).into(),
+            HPF => (mp.get_hpf() as isize).into(),
+            RES => (mp.get_res() as isize).into(),
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::HhBasic(mp) => match action {
+            LEV => (mp.get_lev() as isize).into(),
+            TUN => mp.get_tun().into(),
+            DEC => (mp.get_dec() as isize).into(),
+            TON => mp.get_ton().into(),
+            TRD => (mp.get_trd() as isize).into(),
+            RST => isize::from(mp.get_rst()).into(),
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::CyRide(mp) => match action {
+            LEV => (mp.get_lev() as isize).into(),
+            TUN => mp.get_tun().into(),
+            DEC => (mp.get_dec() as isize).into(),
+            TYP => (mp.get_typ() as isize).into(),
+            HIT => (mp.get_hit() as isize).into(),
+            C1 => (mp.get_c1() as isize).into(),
+            C2 => (mp.get_c2() as isize).into(),
+            C3 => (mp.get_c3() as isize).into(),
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::BdSharp(mp) => match action {
+            LEV => (mp.get_lev() as isize).into(),
+            TUN => f64::from(mp.get_tun()).into(),
+            DEC => (mp.get_dec() as isize).into(),
+            HLD => (mp.get_hld() as isize).into(),
+            SWT => (mp.get_swt() as isize).into(),
+            SWD => (mp.get_swd() as isize).into(),
+            TIC => (mp.get_tic() as isize).into(),
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::SyDualVco(mp) => match action {
+            LEV => (mp.get_lev() as isize).into(),
+            TUN => f64::from(mp.get_tun()).into(),
+            DEC1 => (mp.get_dec1() as isize).into(),
+            DET => f64::from(mp.get_det()).into(),
+            DEC2 => (mp.get_dec2() as isize).into(),
+            BAL => mp.get_bal().into(),
+            BND => mp.get_bnd().into(),
+            CFG => (mp.get_cfg() as isize).into(),
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::SyChip(mp) => match action {
+            LEV => (mp.get_lev() as isize).into(),
+            TUN => f64::from(mp.get_tun()).into(),
+            DEC => (mp.get_dec() as isize).into(),
+            OF2 => mp.get_of2().into(),
+            OF3 => mp.get_of3().into(),
+            OF4 => mp.get_of4().into(),
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::BdAcoustic(mp) => match action {
+            LEV => (mp.get_lev() as isize).into(),
+            TUN => f64::from(mp.get_tun()).into(),
+            DEC => (mp.get_dec() as isize).into(),
+            HLD => (mp.get_hld() as isize).into(),
+            SWT => (mp.get_swt() as isize).into(),
+            SWD => (mp.get_swd() as isize).into(),
+            IMP => (mp.get_imp() as isize).into(),
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::SdAcoustic(mp) => match action {
+            LEV => (mp.get_lev() as isize).into(),
+            TUN => f64::from(mp.get_tun()).into(),
+            BDY => (mp.get_bdy() as isize).into(),
+            NOD => (mp.get_nod() as isize).into(),
+            NOL => (mp.get_nol() as isize).into(),
+            HLD => (mp.get_hld() as isize).into(),
+            SWD => (mp.get_swd() as isize).into(),
+            IMP => (mp.get_imp() as isize).into(),
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::SyRaw(mp) => match action {
+            LEV => (mp.get_lev() as isize).into(),
+            TUN => f64::from(mp.get_tun()).into(),
+            DEC2 => (mp.get_dec2() as isize).into(),
+            DET => f64::from(mp.get_det()).into(),
+            NLEV => (mp.get_nlev() as isize).into(),
+            BAL => mp.get_bal().into(),
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::HhLab(mp) => match action {
+            LEV => (mp.get_lev() as isize).into(),
+            OSC1 => (mp.get_osc1() as isize).into(),
+            DEC => (mp.get_dec() as isize).into(),
+            OSC2 => (mp.get_osc2() as isize).into(),
+            OSC3 => (mp.get_osc3() as isize).into(),
+            OSC4 => (mp.get_osc4() as isize).into(),
+            OSC5 => (mp.get_osc5() as isize).into(),
+            OSC6 => (mp.get_osc6() as isize).into(),
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::Disable | MachineParameters::Unset => {
+            return Err(invalid_machine_parameter(machine_type, action))
+        }
+    })
+}
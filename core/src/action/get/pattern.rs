@@ -1,14 +1,11 @@
-use std::convert::TryFrom;
-
 use crate::api::pattern_action_type::*;
 use crate::api::pattern_enum_type::*;
 use crate::error::EnumError::InvalidEnumType;
 use crate::error::IdentifierError;
 use crate::error::RytmExternalError;
-use median::{
-    atom::{Atom, AtomValue},
-    outlet::OutAnything,
-    symbol::SymbolRef,
+use crate::{
+    atom::{Atom, AtomValue, SymbolRef},
+    output::Outlet,
 };
 use rytm_rs::object::Pattern;
 
@@ -18,15 +15,15 @@ use super::GetAction;
 pub struct PatternGetAction<'a> {
     pub pattern: &'a Pattern,
     pub action: SymbolRef,
-    pub out: &'a OutAnything,
+    pub out: &'a Outlet,
 }
 
 pub fn pattern_get(
     action_or_enum_type: SymbolRef,
     pattern: &Pattern,
-    out: &OutAnything,
+    out: &Outlet,
 ) -> Result<(), RytmExternalError> {
-    if let Some((enum_type, _)) = action_or_enum_type.to_string()?.split_once(':') {
+    if let Some((enum_type, _)) = action_or_enum_type.to_string().split_once(':') {
         handle_pattern_enum_get_action(pattern, enum_type, out)
     } else {
         handle_get_action(GetAction::Pattern(PatternGetAction {
@@ -44,7 +41,17 @@ pub fn handle_pattern_get_action(action: PatternGetAction) -> Result<(), RytmExt
         out,
     } = action;
 
-    let value_atom: Atom = match action.to_string()?.as_str() {
+    let value_atom = pattern_get_value(pattern, &action.to_string())?;
+    let action_atom = Atom::from(action);
+    let index_atom = Atom::from(AtomValue::Int(pattern.index() as isize));
+
+    out.send(&[index_atom, action_atom, value_atom][..]);
+
+    Ok(())
+}
+
+pub fn pattern_get_value(pattern: &Pattern, action: &str) -> Result<Atom, RytmExternalError> {
+    Ok(match action {
         IS_WORK_BUFFER => Atom::from(isize::from(pattern.is_work_buffer_pattern())),
         VERSION => Atom::from(pattern.structure_version() as isize),
         INDEX => Atom::from(pattern.index() as isize),
@@ -56,37 +63,32 @@ pub fn handle_pattern_get_action(action: PatternGetAction) -> Result<(), RytmExt
         BPM => Atom::from(f64::from(pattern.bpm())),
 
         other => return Err(IdentifierError::InvalidType(other.to_owned()).into()),
-    };
+    })
+}
 
-    let action_atom = Atom::from(action);
+pub fn handle_pattern_enum_get_action(
+    pattern: &Pattern,
+    enum_type: &str,
+    out: &Outlet,
+) -> Result<(), RytmExternalError> {
+    let value = pattern_get_enum_value(pattern, enum_type)?;
+    let enum_type_atom = Atom::from(SymbolRef::from(enum_type));
     let index_atom = Atom::from(AtomValue::Int(pattern.index() as isize));
+    let enum_value_atom = Atom::from(SymbolRef::from(value));
 
-    if let Err(_stack_overflow_err) = out.send(&[index_atom, action_atom, value_atom][..]) {
-        // Stack overflow ignore
-    }
+    out.send(&[index_atom, enum_type_atom, enum_value_atom][..]);
 
     Ok(())
 }
 
-pub fn handle_pattern_enum_get_action(
+pub fn pattern_get_enum_value(
     pattern: &Pattern,
     enum_type: &str,
-    out: &OutAnything,
-) -> Result<(), RytmExternalError> {
-    let value: &str = match enum_type {
+) -> Result<&'static str, RytmExternalError> {
+    Ok(match enum_type {
         SPEED => pattern.speed().into(),
         TIME_MODE => pattern.time_mode().into(),
 
         other => return Err(InvalidEnumType(other.to_owned()).into()),
-    };
-
-    let enum_type_atom = Atom::from(SymbolRef::try_from(enum_type).unwrap());
-    let index_atom = Atom::from(AtomValue::Int(pattern.index() as isize));
-    let enum_value_atom = Atom::from(SymbolRef::try_from(value).unwrap());
-
-    if let Err(_stack_overflow_err) = out.send(&[index_atom, enum_type_atom, enum_value_atom][..]) {
-        // Stack overflow ignore
-    }
-
-    Ok(())
+    })
 }
@@ -1,21 +1,33 @@
 use crate::api::settings_action_type::*;
 use crate::api::settings_enum_type::*;
+use crate::atom::Atom;
+use crate::atom::SymbolRef;
 use crate::error::EnumError::InvalidEnumType;
 use crate::error::IdentifierError;
 use crate::error::RytmExternalError;
-use median::atom::Atom;
-use median::outlet::OutAnything;
-use median::symbol::SymbolRef;
+use crate::output::Outlet;
 use rytm_rs::object::Settings;
-use std::convert::TryFrom;
-use std::ffi::CString;
 
 pub fn handle_settings_get_enum_value(
     settings: &Settings,
     enum_type: &str,
-    out: &OutAnything,
+    out: &Outlet,
 ) -> Result<(), RytmExternalError> {
-    let value: &str = match enum_type {
+    let value = settings_get_enum_value(settings, enum_type)?;
+
+    let enum_type_atom = Atom::from(SymbolRef::from(enum_type));
+    let enum_value_atom = Atom::from(SymbolRef::from(value));
+
+    out.send(&[Atom::from(0isize), enum_type_atom, enum_value_atom][..]);
+
+    Ok(())
+}
+
+pub fn settings_get_enum_value(
+    settings: &Settings,
+    enum_type: &str,
+) -> Result<&'static str, RytmExternalError> {
+    Ok(match enum_type {
         PARAMETER_MENU_ITEM => settings.selected_parameter_menu_item().into(),
         FX_PARAMETER_MENU_ITEM => settings.selected_fx_menu_item().into(),
         SEQUENCER_MODE => settings.selected_mode().into(),
@@ -23,27 +35,30 @@ pub fn handle_settings_get_enum_value(
         SAMPLE_RECORDER_SOURCE => settings.sample_recorder_source().into(),
         SAMPLE_RECORDER_RECORDING_LENGTH => settings.sample_recorder_recording_length().into(),
         other => return Err(InvalidEnumType(other.to_owned()).into()),
-    };
+    })
+}
 
-    let enum_type_atom = Atom::from(SymbolRef::try_from(enum_type).unwrap());
-    let enum_value_atom = Atom::from(SymbolRef::try_from(value).unwrap());
+pub fn handle_settings_get_action(
+    settings: &Settings,
+    action: &str,
+    maybe_next_atom: Option<&Atom>,
+    out: &Outlet,
+) -> Result<(), RytmExternalError> {
+    let value_atom = settings_get_value(settings, action, maybe_next_atom)?;
 
-    if let Err(_stack_overflow_err) =
-        out.send(&[Atom::from(0isize), enum_type_atom, enum_value_atom][..])
-    {
-        // Stack overflow ignore
-    }
+    let action_atom = Atom::from(SymbolRef::from(action));
+    out.send(&[Atom::from(0isize), action_atom, value_atom][..]);
 
     Ok(())
 }
 
-pub fn handle_settings_get_action(
+pub fn settings_get_value(
     settings: &Settings,
     action: &str,
     maybe_next_atom: Option<&Atom>,
-    out: &OutAnything,
-) -> Result<(), RytmExternalError> {
-    let value_atom: Atom = match action {
+) -> Result<Atom, RytmExternalError> {
+    Ok(match action {
+        VERSION => Atom::from(settings.structure_version() as isize),
         BPM_PROJECT => Atom::from(f64::from(settings.bpm())),
         SELECTED_TRACK => Atom::from(settings.selected_track() as isize),
         SELECTED_PAGE => Atom::from(settings.selected_page() as isize),
@@ -61,12 +76,5 @@ pub fn handle_settings_get_action(
         }
 
         other => return Err(IdentifierError::InvalidType(other.to_owned()).into()),
-    };
-
-    let action_atom = Atom::from(SymbolRef::from(CString::new(action).unwrap()));
-    if let Err(_stack_overflow_err) = out.send(&[Atom::from(0isize), action_atom, value_atom][..]) {
-        // Stack overflow ignore
-    }
-
-    Ok(())
+    })
 }
@@ -1,22 +1,40 @@
+use crate::action::get::machine::{machine_get_enum_value, machine_get_value};
+use crate::api::machine_parameter_type::MACHINE_PARAMETER_TYPES;
 use crate::api::sound_action_type::*;
 use crate::api::sound_enum_type::*;
+use crate::api::sound_machine_enum_type::SOUND_MACHINE_ENUM_TYPES;
+use crate::atom::Atom;
+use crate::atom::AtomValue;
+use crate::atom::SymbolRef;
 use crate::error::EnumError::InvalidEnumType;
 use crate::error::IdentifierError;
 use crate::error::RytmExternalError;
-use median::atom::Atom;
-use median::atom::AtomValue;
-use median::outlet::OutAnything;
-use median::symbol::SymbolRef;
+use crate::output::Outlet;
+use rytm_rs::object::sound::SoundType;
 use rytm_rs::object::Sound;
-use std::convert::TryFrom;
-use std::ffi::CString;
 
 pub fn handle_sound_get_enum_value(
     sound: &Sound,
     enum_type: &str,
     enum_value: &str,
-    out: &OutAnything,
+    out: &Outlet,
 ) -> Result<(), RytmExternalError> {
+    let value = sound_get_enum_value(sound, enum_type, enum_value)?;
+
+    let enum_type_atom = Atom::from(SymbolRef::from(enum_type));
+    let index_atom = Atom::from(AtomValue::Int(sound.index() as isize));
+    let enum_value_atom = Atom::from(SymbolRef::from(value));
+
+    out.send(&[index_atom, enum_type_atom, enum_value_atom][..]);
+
+    Ok(())
+}
+
+pub fn sound_get_enum_value(
+    sound: &Sound,
+    enum_type: &str,
+    enum_value: &str,
+) -> Result<String, RytmExternalError> {
     let value: &str = match enum_type {
         MACHINE_TYPE => sound.machine_type().into(),
         LFO_DESTINATION => sound.lfo().destination().into(),
@@ -55,31 +73,56 @@ pub fn handle_sound_get_enum_value(
         LFO_MODE => sound.lfo().mode().into(),
         SOUND_SETTINGS_CHROMATIC_MODE => sound.settings().chromatic_mode().into(),
 
+        machine_enum_type if SOUND_MACHINE_ENUM_TYPES.contains(&machine_enum_type) => {
+            return machine_get_enum_value(sound, machine_enum_type)
+        }
+
         other => return Err(InvalidEnumType(other.to_owned()).into()),
     };
 
-    let enum_type_atom = Atom::from(SymbolRef::try_from(enum_type).unwrap());
-    let index_atom = Atom::from(AtomValue::Int(sound.index() as isize));
-    let enum_value_atom = Atom::from(SymbolRef::try_from(value).unwrap());
+    Ok(value.to_owned())
+}
+
+pub fn handle_sound_get_action(
+    sound: &Sound,
+    action: &str,
+    maybe_index_atom: Option<&Atom>,
+    out: &Outlet,
+) -> Result<(), RytmExternalError> {
+    let value_atom = sound_get_value(sound, action, maybe_index_atom)?;
 
-    if let Err(_stack_overflow_err) = out.send(&[index_atom, enum_type_atom, enum_value_atom][..]) {
-        // Stack overflow ignore
-    }
+    let action_atom = Atom::from(SymbolRef::from(action));
+    let index_atom = Atom::from(AtomValue::Int(sound.index() as isize));
+    out.send(&[index_atom, action_atom, value_atom][..]);
 
     Ok(())
 }
 
-pub fn handle_sound_get_action(
+pub fn sound_get_value(
     sound: &Sound,
     action: &str,
     maybe_index_atom: Option<&Atom>,
-    out: &OutAnything,
-) -> Result<(), RytmExternalError> {
-    let value_atom: Atom = match action {
-        NAME => Atom::from(SymbolRef::from(CString::new(sound.name()).unwrap())),
+) -> Result<Atom, RytmExternalError> {
+    Ok(match action {
+        VERSION => (sound.structure_version() as isize).into(),
+        INDEX => (sound.index() as isize).into(),
+        NAME => Atom::from(SymbolRef::from(
+            sound.name().trim_end_matches(char::from(0)),
+        )),
+        IS_POOL => isize::from(sound.is_pool_sound()).into(),
+        IS_KIT => isize::from(sound.is_part_of_a_kit_query()).into(),
+        IS_WORK_BUFFER => isize::from(sound.is_work_buffer_sound()).into(),
+        // Only the sounds of kits have a kit number.
+        KIT_NUMBER => sound.kit_number().map_or_else(
+            || Atom::from(SymbolRef::from("none")),
+            |kit_number| (kit_number as isize).into(),
+        ),
+        SOUND_TYPE => Atom::from(SymbolRef::from(match sound.sound_type() {
+            SoundType::Pool => "pool",
+            SoundType::WorkBuffer => "workbuffer",
+            SoundType::KitQuery => "kit",
+        })),
         ACCENT_LEVEL => (sound.accent_level() as isize).into(),
-        // TODO: MACHINE
-        // MACHINE => (sound.machine() as isize).into(),
         AMP_ATTACK => (sound.amplitude().attack() as isize).into(),
         AMP_HOLD => (sound.amplitude().hold() as isize).into(),
 
@@ -155,14 +198,10 @@ pub fn handle_sound_get_action(
         VELOCITY_TO_VOLUME => isize::from(sound.settings().velocity_to_volume()).into(),
         LEGACY_FX_SEND => isize::from(sound.settings().legacy_fx_send()).into(),
 
-        other => return Err(IdentifierError::InvalidType(other.to_owned()).into()),
-    };
-
-    let action_atom = Atom::from(SymbolRef::from(CString::new(action).unwrap()));
-    let index_atom = Atom::from(AtomValue::Int(sound.index() as isize));
-    if let Err(_stack_overflow_err) = out.send(&[index_atom, action_atom, value_atom][..]) {
-        // Stack overflow ignore
-    }
+        machine_parameter if MACHINE_PARAMETER_TYPES.contains(&machine_parameter) => {
+            return machine_get_value(sound, machine_parameter)
+        }
 
-    Ok(())
+        other => return Err(IdentifierError::InvalidType(other.to_owned()).into()),
+    })
 }
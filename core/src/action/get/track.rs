@@ -1,14 +1,11 @@
-use std::convert::TryFrom;
-
 use crate::api::track_action_type::*;
 use crate::api::track_enum_type::*;
 use crate::error::EnumError::InvalidEnumType;
 use crate::error::IdentifierError;
 use crate::error::RytmExternalError;
-use median::{
-    atom::{Atom, AtomValue},
-    outlet::OutAnything,
-    symbol::SymbolRef,
+use crate::{
+    atom::{Atom, AtomValue, SymbolRef},
+    output::Outlet,
 };
 use rytm_rs::object::pattern::track::Track;
 
@@ -18,15 +15,15 @@ use super::GetAction;
 pub struct TrackGetAction<'a> {
     pub track: &'a Track,
     pub action: SymbolRef,
-    pub out: &'a OutAnything,
+    pub out: &'a Outlet,
 }
 
 pub fn track_get(
     action_or_enum_type: SymbolRef,
     track: &rytm_rs::object::pattern::track::Track,
-    out: &OutAnything,
+    out: &Outlet,
 ) -> Result<(), RytmExternalError> {
-    if let Some((enum_type, _)) = action_or_enum_type.to_string()?.split_once(':') {
+    if let Some((enum_type, _)) = action_or_enum_type.to_string().split_once(':') {
         handle_track_enum_get_action(track, enum_type, out)
     } else {
         handle_get_action(GetAction::Track(TrackGetAction {
@@ -40,7 +37,20 @@ pub fn track_get(
 pub fn handle_track_get_action(action: TrackGetAction) -> Result<(), RytmExternalError> {
     let TrackGetAction { action, track, out } = action;
 
-    let value: usize = match action.to_string()?.as_str() {
+    let value = track_get_value(track, &action.to_string())?;
+    let action_atom = Atom::from(action);
+    let index_atom = Atom::from(AtomValue::Int(track.index() as isize));
+    let pattern_index_atom = Atom::from(AtomValue::Int(track.owner_pattern_index() as isize));
+    // No range problems here.
+    let value_atom = Atom::from(AtomValue::Int(value as isize));
+
+    out.send(&[pattern_index_atom, index_atom, action_atom, value_atom][..]);
+
+    Ok(())
+}
+
+pub fn track_get_value(track: &Track, action: &str) -> Result<usize, RytmExternalError> {
+    Ok(match action {
         INDEX => track.index(),
         OWNER_INDEX => track.owner_pattern_index(),
         DEF_TRIG_NOTE => track.default_trig_note(),
@@ -57,51 +67,41 @@ pub fn handle_track_get_action(action: TrackGetAction) -> Result<(), RytmExterna
         EUCLIDEAN_TRO => track.euclidean_tro(),
 
         other => return Err(IdentifierError::InvalidType(other.to_owned()).into()),
-    };
-
-    let action_atom = Atom::from(action);
-    let index_atom = Atom::from(AtomValue::Int(track.index() as isize));
-    let pattern_index_atom = Atom::from(AtomValue::Int(track.owner_pattern_index() as isize));
-    // No range problems here.
-    let value_atom = Atom::from(AtomValue::Int(value as isize));
-
-    if let Err(_stack_overflow_err) =
-        out.send(&[pattern_index_atom, index_atom, action_atom, value_atom][..])
-    {
-        // Stack overflow ignore
-    }
-
-    Ok(())
+    })
 }
 
 pub fn handle_track_enum_get_action(
     track: &Track,
     enum_type: &str,
-    out: &OutAnything,
+    out: &Outlet,
 ) -> Result<(), RytmExternalError> {
-    let value: &str = match enum_type {
-        ROOT_NOTE => track.root_note().into(),
-        PAD_SCALE => track.pad_scale().into(),
-        DEFAULT_NOTE_LENGTH => track.default_trig_note_length().into(),
-
-        other => return Err(InvalidEnumType(other.to_owned()).into()),
-    };
-
-    let enum_type_atom = Atom::from(SymbolRef::try_from(enum_type).unwrap());
+    let value = track_get_enum_value(track, enum_type)?;
+    let enum_type_atom = Atom::from(SymbolRef::from(enum_type));
     let index_atom = Atom::from(AtomValue::Int(track.index() as isize));
     let pattern_index_atom = Atom::from(AtomValue::Int(track.owner_pattern_index() as isize));
-    let enum_value_atom = Atom::from(SymbolRef::try_from(value).unwrap());
+    let enum_value_atom = Atom::from(SymbolRef::from(value));
 
-    if let Err(_stack_overflow_err) = out.send(
+    out.send(
         &[
             pattern_index_atom,
             index_atom,
             enum_type_atom,
             enum_value_atom,
         ][..],
-    ) {
-        // Stack overflow ignore
-    }
+    );
 
     Ok(())
 }
+
+pub fn track_get_enum_value(
+    track: &Track,
+    enum_type: &str,
+) -> Result<&'static str, RytmExternalError> {
+    Ok(match enum_type {
+        ROOT_NOTE => track.root_note().into(),
+        PAD_SCALE => track.pad_scale().into(),
+        DEFAULT_NOTE_LENGTH => track.default_trig_note_length().into(),
+
+        other => return Err(InvalidEnumType(other.to_owned()).into()),
+    })
+}
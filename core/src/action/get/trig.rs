@@ -1,14 +1,11 @@
-use std::convert::TryFrom;
-
 use crate::api::trig_action_type::*;
 use crate::api::trig_enum_type::*;
 use crate::error::EnumError::InvalidEnumType;
 use crate::error::IdentifierError;
 use crate::error::RytmExternalError;
-use median::{
-    atom::{Atom, AtomValue},
-    outlet::OutAnything,
-    symbol::SymbolRef,
+use crate::{
+    atom::{Atom, AtomValue, SymbolRef},
+    output::Outlet,
 };
 use rytm_rs::object::pattern::{track::trig::HoldsTrigFlags, Trig};
 
@@ -18,15 +15,15 @@ use super::GetAction;
 pub struct TrigGetAction<'a> {
     pub trig: &'a Trig,
     pub action: SymbolRef,
-    pub out: &'a OutAnything,
+    pub out: &'a Outlet,
 }
 
 pub fn trig_get(
     action_or_enum_type: SymbolRef,
     trig: &rytm_rs::object::pattern::track::trig::Trig,
-    out: &OutAnything,
+    out: &Outlet,
 ) -> Result<(), RytmExternalError> {
-    if let Some((enum_type, _)) = action_or_enum_type.to_string()?.split_once(':') {
+    if let Some((enum_type, _)) = action_or_enum_type.to_string().split_once(':') {
         handle_trig_enum_get_action(trig, enum_type, out)
     } else {
         handle_get_action(GetAction::Trig(TrigGetAction {
@@ -40,66 +37,76 @@ pub fn trig_get(
 pub fn handle_trig_get_action(action: TrigGetAction) -> Result<(), RytmExternalError> {
     let TrigGetAction { action, trig, out } = action;
 
-    let value: isize = match action.to_string()?.as_str() {
+    let value = trig_get_value(trig, &action.to_string())?;
+    let action_atom = Atom::from(action);
+    let index_atom = Atom::from(AtomValue::Int(trig.index() as isize));
+    let track_index_atom = Atom::from(AtomValue::Int(trig.track_index() as isize));
+    let value_atom = Atom::from(AtomValue::Int(value));
+
+    out.send(&[track_index_atom, index_atom, action_atom, value_atom][..]);
+
+    Ok(())
+}
+
+pub fn trig_get_value(trig: &Trig, action: &str) -> Result<isize, RytmExternalError> {
+    Ok(match action {
         ENABLE => trig.enabled_trig().into(),
         RETRIG => trig.enabled_retrig().into(),
         MUTE => trig.enabled_mute().into(),
         ACCENT => trig.enabled_accent().into(),
         SWING => trig.enabled_swing().into(),
         SLIDE => trig.enabled_slide().into(),
-        // TODO: Do the rest of the flags..
+        PARAMETER_LOCK_LFO_SWITCH => trig.enabled_parameter_lock_lfo_switch().into(),
+        PARAMETER_LOCK_LFO => trig.enabled_parameter_lock_lfo().into(),
+        PARAMETER_LOCK_SYNTH_SWITCH => trig.enabled_parameter_lock_synth_switch().into(),
+        PARAMETER_LOCK_SYNTH => trig.enabled_parameter_lock_synth().into(),
+        PARAMETER_LOCK_SAMPLE_SWITCH => trig.enabled_parameter_lock_sample_switch().into(),
+        PARAMETER_LOCK_SAMPLE => trig.enabled_parameter_lock_sample().into(),
+        PARAMETER_LOCK_ENV_SWITCH => trig.enabled_parameter_lock_env_switch().into(),
+        PARAMETER_LOCK_ENV => trig.enabled_parameter_lock_env().into(),
         NOTE => trig.note() as isize,
         VELOCITY => trig.velocity() as isize,
         RETRIG_VELOCITY_OFFSET => trig.retrig_velocity_offset(),
         SOUND_LOCK => trig.sound_lock() as isize,
 
         other => return Err(IdentifierError::InvalidType(other.to_owned()).into()),
-    };
-
-    let action_atom = Atom::from(action);
-    let index_atom = Atom::from(AtomValue::Int(trig.index() as isize));
-    let track_index_atom = Atom::from(AtomValue::Int(trig.track_index() as isize));
-    let value_atom = Atom::from(AtomValue::Int(value));
-
-    if let Err(_stack_overflow_err) =
-        out.send(&[track_index_atom, index_atom, action_atom, value_atom][..])
-    {
-        // Stack overflow ignore
-    }
-
-    Ok(())
+    })
 }
 
 pub fn handle_trig_enum_get_action(
     trig: &Trig,
     enum_type: &str,
-    out: &OutAnything,
+    out: &Outlet,
 ) -> Result<(), RytmExternalError> {
-    let value: &str = match enum_type {
-        MICRO_TIME => trig.micro_timing().into(),
-        NOTE_LENGTH => trig.note_length().into(),
-        RETRIG_LENGTH => trig.retrig_length().into(),
-        RETRIG_RATE => trig.retrig_rate().into(),
-        TRIG_CONDITION => trig.trig_condition().into(),
-
-        other => return Err(InvalidEnumType(other.to_owned()).into()),
-    };
-
-    let enum_type_atom = Atom::from(SymbolRef::try_from(enum_type).unwrap());
+    let value = trig_get_enum_value(trig, enum_type)?;
+    let enum_type_atom = Atom::from(SymbolRef::from(enum_type));
     let index_atom = Atom::from(AtomValue::Int(trig.index() as isize));
     let track_index_atom = Atom::from(AtomValue::Int(trig.track_index() as isize));
-    let enum_value_atom = Atom::from(SymbolRef::try_from(value).unwrap());
+    let enum_value_atom = Atom::from(SymbolRef::from(value));
 
-    if let Err(_stack_overflow_err) = out.send(
+    out.send(
         &[
             track_index_atom,
             index_atom,
             enum_type_atom,
             enum_value_atom,
         ][..],
-    ) {
-        // Stack overflow ignore
-    }
+    );
 
     Ok(())
 }
+
+pub fn trig_get_enum_value(
+    trig: &Trig,
+    enum_type: &str,
+) -> Result<&'static str, RytmExternalError> {
+    Ok(match enum_type {
+        MICRO_TIME => trig.micro_timing().into(),
+        NOTE_LENGTH => trig.note_length().into(),
+        RETRIG_LENGTH => trig.retrig_length().into(),
+        RETRIG_RATE => trig.retrig_rate().into(),
+        TRIG_CONDITION => trig.trig_condition().into(),
+
+        other => return Err(InvalidEnumType(other.to_owned()).into()),
+    })
+}
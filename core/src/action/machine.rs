@@ -0,0 +1,354 @@
+use crate::api::{machine_parameter_type, sound_machine_enum_type};
+use crate::error::{IdentifierError, RytmExternalError};
+use rytm_rs::object::sound::machine::{
+    BdAcousticWaveform, BdSharpWaveform, SyChipSpeed, SyChipWaveform, SyRawWaveform1,
+    SyRawWaveform2,
+};
+use rytm_rs::object::sound::types::MachineType;
+
+// The order of the variants follow the integer representation of the enums in the SDK.
+
+const BD_ACOUSTIC_WAVEFORMS: &[&str] = &[
+    "sina", "sinb", "asina", "asinb", "tria", "trib", "ssawa", "ssawb", "sawa", "sawb", "sqra",
+    "sqrb",
+];
+const BD_SHARP_WAVEFORMS: &[&str] = BD_ACOUSTIC_WAVEFORMS;
+const SY_CHIP_WAVEFORMS: &[&str] = &[
+    "sin", "asin", "tri", "ssaw", "saw", "sqr", "noise", "anm1", "anm2", "anm3", "anm4", "anm5",
+    "pwm+", "pwm-", "trib", "tri+", "tri++", "trix", "sawb", "saw+", "saw++", "sawx", "sqrb",
+    "sqr+", "sqr++", "sqrx", "tbl1", "tbl2", "tbl3",
+];
+const SY_CHIP_SPEEDS: &[&str] = &[
+    "128t", "128", "64t", "128d", "64", "32t", "64d", "32", "16t", "32d", "16", "8t", "16d", "8",
+    "4t", "8d", "4", "2t", "4d", "2", "1t", "2d", "1", "1d", "1.0hz", "1.56hz", "1.88hz", "2hz",
+    "3.13hz", "3.75hz", "4hz", "5hz", "6.25hz", "7.5hz", "10hz", "12.5hz", "15hz", "20hz", "25hz",
+    "30hz", "40hz", "50hz", "60hz", "75hz", "100hz", "120hz", "150hz", "180hz", "200hz", "240hz",
+    "250hz", "300hz", "350hz", "360hz", "400hz", "420hz", "480hz", "240s", "200s", "150s", "120s",
+    "100s", "60s", "50s", "30s", "25s",
+];
+const SY_RAW_WAVEFORMS_1: &[&str] = &["sin", "asin", "tri", "ssaw", "asaw", "saw", "ring"];
+const SY_RAW_WAVEFORMS_2: &[&str] = &["sineA", "ssawA", "sineB", "ssawB"];
+
+pub fn bd_acoustic_waveform_to_string(wav: BdAcousticWaveform) -> String {
+    BD_ACOUSTIC_WAVEFORMS[u8::from(wav) as usize].to_owned()
+}
+
+pub fn bd_sharp_waveform_to_string(wav: BdSharpWaveform) -> String {
+    BD_SHARP_WAVEFORMS[u8::from(wav) as usize].to_owned()
+}
+
+pub fn sy_chip_waveform_to_string(wav: SyChipWaveform) -> String {
+    let wav = u8::from(wav) as usize;
+    SY_CHIP_WAVEFORMS.get(wav).map_or_else(
+        // Rest of the range represents the pulse width percentage.
+        || format!("{}%", wav + 1 - SY_CHIP_WAVEFORMS.len()),
+        |wav| (*wav).to_owned(),
+    )
+}
+
+pub fn sy_chip_speed_to_string(spd: SyChipSpeed) -> String {
+    SY_CHIP_SPEEDS[u8::from(spd) as usize].to_owned()
+}
+
+pub fn sy_raw_waveform_1_to_string(wav1: SyRawWaveform1) -> String {
+    SY_RAW_WAVEFORMS_1[u8::from(wav1) as usize].to_owned()
+}
+
+pub fn sy_raw_waveform_2_to_string(wav2: SyRawWaveform2) -> String {
+    SY_RAW_WAVEFORMS_2[u8::from(wav2) as usize].to_owned()
+}
+
+/// Lists the machine parameter identifiers and machine enum types which belong to a machine.
+pub const fn machine_parameter_types_of(machine_type: MachineType) -> &'static [&'static str] {
+    match machine_type {
+        MachineType::BdHard => &[
+            machine_parameter_type::LEV,
+            machine_parameter_type::TUN,
+            machine_parameter_type::DEC,
+            machine_parameter_type::HLD,
+            machine_parameter_type::SWT,
+            machine_parameter_type::SNP,
+            machine_parameter_type::WAV,
+            machine_parameter_type::TIC,
+        ],
+        MachineType::BdClassic => &[
+            machine_parameter_type::LEV,
+            machine_parameter_type::TUN,
+            machine_parameter_type::DEC,
+            machine_parameter_type::HLD,
+            machine_parameter_type::SWT,
+            machine_parameter_type::SWD,
+            machine_parameter_type::WAV,
+            machine_parameter_type::TRA,
+        ],
+        MachineType::SdHard => &[
+            machine_parameter_type::LEV,
+            machine_parameter_type::TUN,
+            machine_parameter_type::DEC,
+            machine_parameter_type::SWD,
+            machine_parameter_type::TIC,
+            machine_parameter_type::NOD,
+            machine_parameter_type::NOL,
+            machine_parameter_type::SWT,
+        ],
+        MachineType::SdClassic => &[
+            machine_parameter_type::LEV,
+            machine_parameter_type::TUN,
+            machine_parameter_type::DEC,
+            machine_parameter_type::DET,
+            machine_parameter_type::SNP,
+            machine_parameter_type::NOD,
+            machine_parameter_type::NOL,
+            machine_parameter_type::BAL,
+        ],
+        MachineType::RsHard => &[
+            machine_parameter_type::LEV,
+            machine_parameter_type::TUN,
+            machine_parameter_type::DEC,
+            machine_parameter_type::SWD,
+            machine_parameter_type::TIC,
+            machine_parameter_type::NOL,
+            machine_parameter_type::SYN,
+            machine_parameter_type::SWT,
+        ],
+        MachineType::RsClassic => &[
+            machine_parameter_type::LEV,
+            machine_parameter_type::T1,
+            machine_parameter_type::DEC,
+            machine_parameter_type::BAL,
+            machine_parameter_type::T2,
+            machine_parameter_type::SYM,
+            machine_parameter_type::NOL,
+            machine_parameter_type::TIC,
+        ],
+        MachineType::CpClassic => &[
+            machine_parameter_type::LEV,
+            machine_parameter_type::TON,
+            machine_parameter_type::NOD,
+            machine_parameter_type::NUM,
+            machine_parameter_type::RAT,
+            machine_parameter_type::NOL,
+            machine_parameter_type::RND,
+            machine_parameter_type::CPT,
+        ],
+        MachineType::BtClassic => &[
+            machine_parameter_type::LEV,
+            machine_parameter_type::TUN,
+            machine_parameter_type::DEC,
+            machine_parameter_type::NOL,
+            machine_parameter_type::SNP,
+            machine_parameter_type::SWD,
+        ],
+        MachineType::XtClassic => &[
+            machine_parameter_type::LEV,
+            machine_parameter_type::TUN,
+            machine_parameter_type::DEC,
+            machine_parameter_type::SWD,
+            machine_parameter_type::SWT,
+            machine_parameter_type::NOD,
+            machine_parameter_type::NOL,
+            machine_parameter_type::TON,
+        ],
+        MachineType::ChClassic | MachineType::OhClassic => &[
+            machine_parameter_type::LEV,
+            machine_parameter_type::TUN,
+            machine_parameter_type::DEC,
+            machine_parameter_type::COL,
+        ],
+        MachineType::CyClassic => &[
+            machine_parameter_type::LEV,
+            machine_parameter_type::TUN,
+            machine_parameter_type::DEC,
+            machine_parameter_type::COL,
+            machine_parameter_type::TON,
+        ],
+        MachineType::CbClassic | MachineType::CbMetallic => &[
+            machine_parameter_type::LEV,
+            machine_parameter_type::TUN,
+            machine_parameter_type::DEC,
+            machine_parameter_type::DET,
+            machine_parameter_type::PW1,
+            machine_parameter_type::PW2,
+        ],
+        MachineType::BdFm => &[
+            machine_parameter_type::LEV,
+            machine_parameter_type::TUN,
+            machine_parameter_type::DEC,
+            machine_parameter_type::FMA,
+            machine_parameter_type::SWT,
+            machine_parameter_type::FMS,
+            machine_parameter_type::FMD,
+            machine_parameter_type::FMT,
+        ],
+        MachineType::SdFm => &[
+            machine_parameter_type::LEV,
+            machine_parameter_type::TUN,
+            machine_parameter_type::DEC,
+            machine_parameter_type::FMT,
+            machine_parameter_type::FMD,
+            machine_parameter_type::NOD,
+            machine_parameter_type::NOL,
+            machine_parameter_type::FMA,
+        ],
+        MachineType::UtNoise => &[
+            machine_parameter_type::LEV,
+            machine_parameter_type::LPF,
+            machine_parameter_type::DEC,
+            machine_parameter_type::HPF,
+            machine_parameter_type::LPQ,
+            machine_parameter_type::ATK,
+            machine_parameter_type::SWT,
+            machine_parameter_type::SWD,
+        ],
+        MachineType::UtImpulse => &[
+            machine_parameter_type::LEV,
+            machine_parameter_type::ATK,
+            machine_parameter_type::DEC,
+            machine_parameter_type::POL,
+        ],
+        MachineType::ChMetallic | MachineType::OhMetallic => &[
+            machine_parameter_type::LEV,
+            machine_parameter_type::TUN,
+            machine_parameter_type::DEC,
+        ],
+        MachineType::CyMetallic => &[
+            machine_parameter_type::LEV,
+            machine_parameter_type::TUN,
+            machine_parameter_type::DEC,
+            machine_parameter_type::TON,
+            machine_parameter_type::TRD,
+        ],
+        MachineType::BdPlastic => &[
+            machine_parameter_type::LEV,
+            machine_parameter_type::TUN,
+            machine_parameter_type::DEC,
+            machine_parameter_type::TYP,
+            machine_parameter_type::MOD,
+            machine_parameter_type::SWT,
+            machine_parameter_type::SWD,
+            machine_parameter_type::TIC,
+        ],
+        MachineType::BdSilky => &[
+            machine_parameter_type::LEV,
+            machine_parameter_type::TUN,
+            machine_parameter_type::DEC,
+            machine_parameter_type::HLD,
+            machine_parameter_type::SWT,
+            machine_parameter_type::SWD,
+            machine_parameter_type::DUS,
+            machine_parameter_type::CLK,
+        ],
+        MachineType::SdNatural => &[
+            machine_parameter_type::LEV,
+            machine_parameter_type::TUN,
+            machine_parameter_type::BDY,
+            machine_parameter_type::DEC,
+            machine_parameter_type::BAL,
+            machine_parameter_type::LPF,
+            machine_parameter_type::HPF,
+            machine_parameter_type::RES,
+        ],
+        MachineType::HhBasic => &[
+            machine_parameter_type::LEV,
+            machine_parameter_type::TUN,
+            machine_parameter_type::DEC,
+            machine_parameter_type::TON,
+            machine_parameter_type::TRD,
+            machine_parameter_type::RST,
+        ],
+        MachineType::CyRide => &[
+            machine_parameter_type::LEV,
+            machine_parameter_type::TUN,
+            machine_parameter_type::DEC,
+            machine_parameter_type::TYP,
+            machine_parameter_type::HIT,
+            machine_parameter_type::C1,
+            machine_parameter_type::C2,
+            machine_parameter_type::C3,
+        ],
+        MachineType::BdSharp => &[
+            machine_parameter_type::LEV,
+            machine_parameter_type::TUN,
+            machine_parameter_type::DEC,
+            machine_parameter_type::HLD,
+            machine_parameter_type::SWT,
+            machine_parameter_type::SWD,
+            machine_parameter_type::TIC,
+            sound_machine_enum_type::BD_SHARP_WAVEFORM,
+        ],
+        MachineType::SyDualVco => &[
+            machine_parameter_type::LEV,
+            machine_parameter_type::TUN,
+            machine_parameter_type::DEC1,
+            machine_parameter_type::DET,
+            machine_parameter_type::DEC2,
+            machine_parameter_type::BAL,
+            machine_parameter_type::BND,
+            machine_parameter_type::CFG,
+        ],
+        MachineType::SyChip => &[
+            machine_parameter_type::LEV,
+            machine_parameter_type::TUN,
+            machine_parameter_type::DEC,
+            machine_parameter_type::OF2,
+            machine_parameter_type::OF3,
+            machine_parameter_type::OF4,
+            sound_machine_enum_type::SY_CHIP_WAVEFORM,
+            sound_machine_enum_type::SY_CHIP_SPEED,
+        ],
+        MachineType::BdAcoustic => &[
+            machine_parameter_type::LEV,
+            machine_parameter_type::TUN,
+            machine_parameter_type::DEC,
+            machine_parameter_type::HLD,
+            machine_parameter_type::SWT,
+            machine_parameter_type::SWD,
+            machine_parameter_type::IMP,
+            sound_machine_enum_type::BD_ACOUSTIC_WAVEFORM,
+        ],
+        MachineType::SdAcoustic => &[
+            machine_parameter_type::LEV,
+            machine_parameter_type::TUN,
+            machine_parameter_type::BDY,
+            machine_parameter_type::NOD,
+            machine_parameter_type::NOL,
+            machine_parameter_type::HLD,
+            machine_parameter_type::SWD,
+            machine_parameter_type::IMP,
+        ],
+        MachineType::SyRaw => &[
+            machine_parameter_type::LEV,
+            machine_parameter_type::TUN,
+            machine_parameter_type::DEC2,
+            machine_parameter_type::DET,
+            machine_parameter_type::NLEV,
+            machine_parameter_type::BAL,
+            sound_machine_enum_type::SY_RAW_WAVEFORM_1,
+            sound_machine_enum_type::SY_RAW_WAVEFORM_2,
+        ],
+        MachineType::HhLab => &[
+            machine_parameter_type::LEV,
+            machine_parameter_type::OSC1,
+            machine_parameter_type::DEC,
+            machine_parameter_type::OSC2,
+            machine_parameter_type::OSC3,
+            machine_parameter_type::OSC4,
+            machine_parameter_type::OSC5,
+            machine_parameter_type::OSC6,
+        ],
+        MachineType::Disable | MachineType::Unset => &[],
+    }
+}
+
+pub fn invalid_machine_parameter(machine_type: MachineType, identifier: &str) -> RytmExternalError {
+    let machine: &str = machine_type.into();
+    let parameters = machine_parameter_types_of(machine_type);
+    let parameters = if parameters.is_empty() {
+        "none".to_owned()
+    } else {
+        parameters.join(", ")
+    };
+
+    IdentifierError::InvalidMachineParameter(identifier.to_owned(), machine.to_owned(), parameters)
+        .into()
+}
@@ -1,18 +1,40 @@
-use crate::{api::plock_type::*, error::RytmExternalError};
-use median::atom::Atom;
-use median::outlet::OutAnything;
-use rytm_rs::object::pattern::Trig;
+use crate::atom::Atom;
+use crate::output::Outlet;
+use crate::{
+    api::{
+        machine_parameter_type::MACHINE_PARAMETER_TYPES, plock_type::*,
+        sound_machine_enum_type::SOUND_MACHINE_ENUM_TYPES,
+    },
+    error::RytmExternalError,
+};
+use rytm_rs::object::{pattern::Trig, Sound};
 
 use self::clear::{handle_trig_plock_clear_action, handle_trig_plock_clear_enum_value};
 use self::get::{handle_trig_plock_get_action, handle_trig_plock_get_enum_value};
+use self::machine::{
+    handle_trig_plock_clear_machine_action, handle_trig_plock_clear_machine_enum_value,
+    handle_trig_plock_get_machine_action, handle_trig_plock_get_machine_enum_value,
+    handle_trig_plock_set_machine_action, handle_trig_plock_set_machine_enum_value,
+};
 use self::set::{handle_trig_plock_set_action, handle_trig_plock_set_enum_value};
 
 pub mod clear;
 pub mod get;
+pub mod machine;
 pub mod set;
 
+/// Machine parameter locks need the sound of the track which the trig belongs to.
+pub(crate) fn sound_for_machine_plock(
+    maybe_sound: Option<&Sound>,
+) -> Result<&Sound, RytmExternalError> {
+    maybe_sound.ok_or_else(|| {
+        "Invalid plock: Machine parameters can only be locked in tracks 0 to 11 which have a sound assigned.".into()
+    })
+}
+
 pub fn handle_trig_plock_setter_action(
     trig: &Trig,
+    maybe_sound: Option<&Sound>,
     selector: &str,
     atoms: &[Atom],
     slice_index: usize,
@@ -20,14 +42,24 @@ pub fn handle_trig_plock_setter_action(
     // If so, the next atom must either be an action or an enum value.
     if let Some(atom) = atoms.get(slice_index) {
         let action_or_enum_value = atom.get_symbol();
-        let action_or_enum_value_str = action_or_enum_value.to_string()?;
+        let action_or_enum_value_str = action_or_enum_value.to_string();
 
         let enum_pair = action_or_enum_value_str.split_once(':');
 
         return match selector {
             PLOCK_SET => {
                 if let Some((enum_type, enum_value)) = enum_pair {
+                    if SOUND_MACHINE_ENUM_TYPES.contains(&enum_type) {
+                        let sound = sound_for_machine_plock(maybe_sound)?;
+                        return handle_trig_plock_set_machine_enum_value(trig, sound, enum_type, enum_value);
+                    }
                     handle_trig_plock_set_enum_value(trig, enum_type, enum_value)
+                } else if MACHINE_PARAMETER_TYPES.contains(&action_or_enum_value_str.as_str()) {
+                    let sound = sound_for_machine_plock(maybe_sound)?;
+                    let parameter_atom = atoms.get(slice_index + 1).ok_or_else(|| {
+                        RytmExternalError::from("Invalid format: A parameter or enum should follow a plockset action.")
+                    })?;
+                    handle_trig_plock_set_machine_action(trig, sound, &action_or_enum_value_str, parameter_atom)
                 } else {
                     handle_trig_plock_set_action(trig, &action_or_enum_value, atoms, slice_index + 1)
                 }
@@ -37,7 +69,14 @@ pub fn handle_trig_plock_setter_action(
             }
             PLOCK_CLEAR => {
                 if let Some((enum_type, _)) = enum_pair {
+                    if SOUND_MACHINE_ENUM_TYPES.contains(&enum_type) {
+                        let sound = sound_for_machine_plock(maybe_sound)?;
+                        return handle_trig_plock_clear_machine_enum_value(trig, sound, enum_type);
+                    }
                     handle_trig_plock_clear_enum_value(trig, enum_type)
+                } else if MACHINE_PARAMETER_TYPES.contains(&action_or_enum_value_str.as_str()) {
+                    let sound = sound_for_machine_plock(maybe_sound)?;
+                    handle_trig_plock_clear_machine_action(trig, sound, &action_or_enum_value_str)
                 } else {
                     handle_trig_plock_clear_action(trig, &action_or_enum_value)
                 }
@@ -51,15 +90,16 @@ pub fn handle_trig_plock_setter_action(
 
 pub fn handle_trig_plock_getter_action(
     trig: &Trig,
+    maybe_sound: Option<&Sound>,
     selector: &str,
     atoms: &[Atom],
     slice_index: usize,
-    out: &OutAnything,
+    out: &Outlet,
 ) -> Result<(), RytmExternalError> {
     // If so, the next atom must either be an action or an enum value.
     if let Some(atom) = atoms.get(slice_index) {
         let action_or_enum_value = atom.get_symbol();
-        let action_or_enum_value_str = action_or_enum_value.to_string()?;
+        let action_or_enum_value_str = action_or_enum_value.to_string();
 
         let enum_pair = action_or_enum_value_str.split_once(':');
 
@@ -69,7 +109,14 @@ pub fn handle_trig_plock_getter_action(
             }
             PLOCK_GET => {
                 if let Some((enum_type, _)) = enum_pair {
+                    if SOUND_MACHINE_ENUM_TYPES.contains(&enum_type) {
+                        let sound = sound_for_machine_plock(maybe_sound)?;
+                        return handle_trig_plock_get_machine_enum_value(trig, sound, enum_type, out);
+                    }
                     handle_trig_plock_get_enum_value(trig, enum_type, out)
+                } else if MACHINE_PARAMETER_TYPES.contains(&action_or_enum_value_str.as_str()) {
+                    let sound = sound_for_machine_plock(maybe_sound)?;
+                    handle_trig_plock_get_machine_action(trig, sound, &action_or_enum_value_str, out)
                 } else {
                     handle_trig_plock_get_action(trig, action_or_enum_value, out)
                 }
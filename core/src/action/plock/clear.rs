@@ -1,16 +1,16 @@
 use crate::api::kit_action_type;
 use crate::api::sound_action_type;
-use crate::error::EnumError::InvalidEnumType;
+use crate::atom::SymbolRef;
+use crate::error::EnumError::{InvalidEnumType, NotParameterLockable};
 use crate::error::IdentifierError;
 use crate::error::RytmExternalError;
-use median::symbol::SymbolRef;
 use rytm_rs::object::pattern::Trig;
 
 pub fn handle_trig_plock_clear_action(
     trig: &Trig,
     action: &SymbolRef,
 ) -> Result<(), RytmExternalError> {
-    let action_str = action.to_string()?;
+    let action_str = action.to_string();
 
     match action_str.as_str() {
         kit_action_type::FX_DELAY_TIME => Ok(trig.plock_clear_fx_delay_time()?),
@@ -39,10 +39,19 @@ pub fn handle_trig_plock_clear_action(
         kit_action_type::FX_LFO_FADE => Ok(trig.plock_clear_fx_lfo_fade()?),
         kit_action_type::FX_LFO_START_PHASE_OR_SLEW => Ok(trig.plock_clear_fx_lfo_start_phase()?),
         kit_action_type::FX_LFO_DEPTH => Ok(trig.plock_clear_fx_lfo_depth()?),
-        //
-        // TODO: Do the dist setters after fixing the dist in the SDK
-        // TODO: MACHINE Do Machine plocks
-        //
+
+        kit_action_type::FX_DISTORTION_DELAY_OVERDRIVE => {
+            Ok(trig.plock_clear_fx_distortion_delay_overdrive()?)
+        }
+        kit_action_type::FX_DISTORTION_DELAY_POST => {
+            Ok(trig.plock_clear_fx_distortion_delay_post()?)
+        }
+        kit_action_type::FX_DISTORTION_REVERB_POST => {
+            Ok(trig.plock_clear_fx_distortion_reverb_post()?)
+        }
+        kit_action_type::FX_DISTORTION_AMOUNT => Ok(trig.plock_clear_fx_distortion_amount()?),
+        kit_action_type::FX_DISTORTION_SYMMETRY => Ok(trig.plock_clear_fx_distortion_symmetry()?),
+
         sound_action_type::AMP_ATTACK => Ok(trig.plock_clear_amplitude_attack()?),
         sound_action_type::AMP_HOLD => Ok(trig.plock_clear_amplitude_hold()?),
         sound_action_type::AMP_DECAY => Ok(trig.plock_clear_amplitude_decay()?),
@@ -92,8 +101,7 @@ pub fn handle_trig_plock_clear_enum_value(
         kit_enum_type::FX_COMP_SIDE_CHAIN_EQ => Ok(trig.plock_clear_fx_compressor_side_chain_eq()?),
         kit_enum_type::FX_LFO_DESTINATION => Ok(trig.plock_clear_fx_lfo_destination()?),
 
-        // TODO: MACHINE
-        // sound_enum_type::MACHINE_PARAMETERS => todo!(),
+        sound_enum_type::MACHINE_TYPE => Err(NotParameterLockable(enum_type.to_owned()).into()),
         sound_enum_type::LFO_DESTINATION => Ok(trig.plock_clear_lfo_destination()?),
         sound_enum_type::FILTER_TYPE => Ok(trig.plock_clear_filter_type()?),
         sound_enum_type::LFO_MULTIPLIER => Ok(trig.plock_clear_lfo_multiplier()?),
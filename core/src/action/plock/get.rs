@@ -2,24 +2,44 @@ use crate::api::kit_action_type;
 use crate::api::kit_enum_type;
 use crate::api::sound_action_type;
 use crate::api::sound_enum_type;
-use crate::error::EnumError::InvalidEnumType;
+use crate::atom::SymbolRef;
+use crate::atom::{Atom, AtomValue};
+use crate::error::EnumError::{InvalidEnumType, NotParameterLockable};
 use crate::error::IdentifierError;
 use crate::error::RytmExternalError;
-use median::atom::{Atom, AtomValue};
-use median::outlet::OutAnything;
-use median::symbol::SymbolRef;
+use crate::output::Outlet;
 use rytm_rs::object::pattern::Trig;
-use std::convert::TryFrom;
-use std::ffi::CString;
 
 pub fn handle_trig_plock_get_action(
     trig: &Trig,
     action: SymbolRef,
-    out: &OutAnything,
+    out: &Outlet,
 ) -> Result<(), RytmExternalError> {
-    let action_str = action.to_string()?;
+    let action_str = action.to_string();
 
-    let value_atom: Option<Atom> = match action_str.as_str() {
+    let value_atom = trig_plock_get_value(trig, &action_str)?;
+    let action_atom = Atom::from(action);
+    let index_atom = Atom::from(AtomValue::Int(trig.index() as isize));
+
+    if let Some(value_atom) = value_atom {
+        out.send(&[action_atom, index_atom, value_atom][..]);
+    } else {
+        // Send the value as "unset" for a plock which is not set.
+        out.send(
+            &[
+                action_atom,
+                index_atom,
+                Atom::from(SymbolRef::from("unset")),
+            ][..],
+        );
+    }
+
+    Ok(())
+}
+
+/// Returns `None` for a parameter which is not locked.
+pub fn trig_plock_get_value(trig: &Trig, action: &str) -> Result<Option<Atom>, RytmExternalError> {
+    Ok(match action {
         kit_action_type::FX_DELAY_TIME => trig
             .plock_get_fx_delay_time()?
             .map(|val| Atom::from(val as isize)),
@@ -88,10 +108,23 @@ pub fn handle_trig_plock_get_action(
         kit_action_type::FX_LFO_DEPTH => trig
             .plock_get_fx_lfo_depth()?
             .map(|val| Atom::from(f64::from(val))),
-        //
-        // TODO: Do the dist setters after fixing the dist in the SDK
-        // TODO: MACHINE Do Machine plocks
-        //
+
+        kit_action_type::FX_DISTORTION_DELAY_OVERDRIVE => trig
+            .plock_get_fx_distortion_delay_overdrive()?
+            .map(|val| Atom::from(val as isize)),
+        kit_action_type::FX_DISTORTION_DELAY_POST => trig
+            .plock_get_fx_distortion_delay_post()?
+            .map(|val| Atom::from(isize::from(val))),
+        kit_action_type::FX_DISTORTION_REVERB_POST => trig
+            .plock_get_fx_distortion_reverb_post()?
+            .map(|val| Atom::from(isize::from(val))),
+        kit_action_type::FX_DISTORTION_AMOUNT => trig
+            .plock_get_fx_distortion_amount()?
+            .map(|val| Atom::from(val as isize)),
+        kit_action_type::FX_DISTORTION_SYMMETRY => {
+            trig.plock_get_fx_distortion_symmetry()?.map(Atom::from)
+        }
+
         sound_action_type::AMP_ATTACK => trig
             .plock_get_amplitude_attack()?
             .map(|val| Atom::from(val as isize)),
@@ -168,36 +201,43 @@ pub fn handle_trig_plock_get_action(
             .map(|val| Atom::from(val as isize)),
 
         other => return Err(IdentifierError::InvalidType(other.to_owned()).into()),
-    };
-    let action_atom = Atom::from(action);
+    })
+}
+
+pub fn handle_trig_plock_get_enum_value(
+    trig: &Trig,
+    enum_type: &str,
+    out: &Outlet,
+) -> Result<(), RytmExternalError> {
+    let enum_value = trig_plock_get_enum_value(trig, enum_type)?;
+    let enum_type_atom = Atom::from(SymbolRef::from(enum_type));
     let index_atom = Atom::from(AtomValue::Int(trig.index() as isize));
 
-    if let Some(value_atom) = value_atom {
-        if let Err(_stack_overflow_err) = out.send(&[action_atom, index_atom, value_atom][..]) {
-            // Stack overflow ignore
-        }
+    if let Some(enum_value) = enum_value {
+        let enum_value_atom = Atom::from(SymbolRef::from(enum_value));
+        out.send(&[enum_type_atom, index_atom, enum_value_atom][..]);
+        //..
     } else {
         // Send the value as "unset" for a plock which is not set.
-        if let Err(_stack_overflow_err) = out.send(
+
+        out.send(
             &[
-                action_atom,
+                enum_type_atom,
                 index_atom,
-                Atom::from(SymbolRef::from(CString::new("unset").unwrap())),
+                Atom::from(SymbolRef::from("unset")),
             ][..],
-        ) {
-            // Stack overflow ignore
-        }
+        );
     }
 
     Ok(())
 }
 
-pub fn handle_trig_plock_get_enum_value(
+/// Returns `None` for a parameter which is not locked.
+pub fn trig_plock_get_enum_value(
     trig: &Trig,
     enum_type: &str,
-    out: &OutAnything,
-) -> Result<(), RytmExternalError> {
-    let enum_value: Option<&str> = match enum_type {
+) -> Result<Option<&'static str>, RytmExternalError> {
+    Ok(match enum_type {
         kit_enum_type::FX_COMP_ATTACK => trig.plock_get_fx_compressor_attack()?.map(Into::into),
         kit_enum_type::FX_COMP_RELEASE => trig.plock_get_fx_compressor_release()?.map(Into::into),
         kit_enum_type::FX_COMP_RATIO => trig.plock_get_fx_compressor_ratio()?.map(Into::into),
@@ -206,8 +246,9 @@ pub fn handle_trig_plock_get_enum_value(
             .map(Into::into),
         kit_enum_type::FX_LFO_DESTINATION => trig.plock_get_fx_lfo_destination()?.map(Into::into),
 
-        // TODO:
-        // sound_enum_type::MACHINE_PARAMETERS => todo!(.map(|val|val.into()),
+        sound_enum_type::MACHINE_TYPE => {
+            return Err(NotParameterLockable(enum_type.to_owned()).into())
+        }
         sound_enum_type::LFO_DESTINATION => trig.plock_get_lfo_destination()?.map(Into::into),
         sound_enum_type::FILTER_TYPE => trig.plock_get_filter_type()?.map(Into::into),
         sound_enum_type::LFO_MULTIPLIER => trig.plock_get_lfo_multiplier()?.map(Into::into),
@@ -215,31 +256,5 @@ pub fn handle_trig_plock_get_enum_value(
         sound_enum_type::LFO_MODE => trig.plock_get_lfo_mode()?.map(Into::into),
 
         other => return Err(InvalidEnumType(other.to_owned()).into()),
-    };
-    let enum_type_atom = Atom::from(SymbolRef::try_from(enum_type).unwrap());
-    let index_atom = Atom::from(AtomValue::Int(trig.index() as isize));
-
-    if let Some(enum_value) = enum_value {
-        let enum_value_atom = Atom::from(SymbolRef::try_from(enum_value).unwrap());
-        if let Err(_stack_overflow_err) =
-            out.send(&[enum_type_atom, index_atom, enum_value_atom][..])
-        {
-            // Stack overflow ignore
-        }
-        //..
-    } else {
-        // Send the value as "unset" for a plock which is not set.
-
-        if let Err(_stack_overflow_err) = out.send(
-            &[
-                enum_type_atom,
-                index_atom,
-                Atom::from(SymbolRef::from(CString::new("unset").unwrap())),
-            ][..],
-        ) {
-            // Stack overflow ignore
-        }
-    }
-
-    Ok(())
+    })
 }
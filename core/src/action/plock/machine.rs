@@ -0,0 +1,1539 @@
+use crate::action::machine::{
+    bd_acoustic_waveform_to_string, bd_sharp_waveform_to_string, invalid_machine_parameter,
+    sy_chip_speed_to_string, sy_chip_waveform_to_string, sy_raw_waveform_1_to_string,
+    sy_raw_waveform_2_to_string,
+};
+use crate::api::machine_parameter_type::*;
+use crate::api::sound_machine_enum_type::*;
+use crate::atom::SymbolRef;
+use crate::atom::{Atom, AtomValue};
+use crate::error::RytmExternalError;
+use crate::output::Outlet;
+use crate::util::get_bool_from_0_or_1;
+use rytm_rs::object::pattern::Trig;
+use rytm_rs::object::sound::machine::MachineParameters;
+use rytm_rs::object::Sound;
+use std::convert::TryInto;
+
+// Machine parameter locks are stored in the pattern but they're reached through the sound of the kit which the pattern is linked to.
+// The sound passed to these functions should be the sound which is assigned to the track of the trig.
+
+pub fn handle_trig_plock_get_machine_action(
+    trig: &Trig,
+    sound: &Sound,
+    action: &str,
+    out: &Outlet,
+) -> Result<(), RytmExternalError> {
+    let value_atom = trig_plock_get_machine_value(trig, sound, action)?;
+
+    send_plock_get_output(action, trig.index(), value_atom, out);
+    Ok(())
+}
+
+/// Returns `None` for a parameter which is not locked.
+pub fn trig_plock_get_machine_value(
+    trig: &Trig,
+    sound: &Sound,
+    action: &str,
+) -> Result<Option<Atom>, RytmExternalError> {
+    let machine_type = sound.machine_type();
+    let trig_index = trig.index();
+
+    Ok(match sound.machine_parameters() {
+        MachineParameters::BdHard(mp) => match action {
+            LEV => mp
+                .plock_get_lev(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            TUN => mp
+                .plock_get_tun(trig_index)?
+                .map(|val| Atom::from(f64::from(val))),
+            DEC => mp
+                .plock_get_dec(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            HLD => mp
+                .plock_get_hld(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            SWT => mp
+                .plock_get_swt(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            SNP => mp
+                .plock_get_snp(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            WAV => mp
+                .plock_get_wav(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            TIC => mp
+                .plock_get_tic(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::BdClassic(mp) => match action {
+            LEV => mp
+                .plock_get_lev(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            TUN => mp
+                .plock_get_tun(trig_index)?
+                .map(|val| Atom::from(f64::from(val))),
+            DEC => mp
+                .plock_get_dec(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            HLD => mp
+                .plock_get_hld(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            SWT => mp
+                .plock_get_swt(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            SWD => mp
+                .plock_get_swd(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            WAV => mp
+                .plock_get_wav(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            TRA => mp
+                .plock_get_tra(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::SdHard(mp) => match action {
+            LEV => mp
+                .plock_get_lev(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            TUN => mp
+                .plock_get_tun(trig_index)?
+                .map(|val| Atom::from(f64::from(val))),
+            DEC => mp
+                .plock_get_dec(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            SWD => mp
+                .plock_get_swd(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            TIC => mp
+                .plock_get_tic(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            NOD => mp
+                .plock_get_nod(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            NOL => mp
+                .plock_get_nol(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            SWT => mp
+                .plock_get_swt(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::SdClassic(mp) => match action {
+            LEV => mp
+                .plock_get_lev(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            TUN => mp
+                .plock_get_tun(trig_index)?
+                .map(|val| Atom::from(f64::from(val))),
+            DEC => mp
+                .plock_get_dec(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            DET => mp
+                .plock_get_det(trig_index)?
+                .map(|val| Atom::from(f64::from(val))),
+            SNP => mp
+                .plock_get_snp(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            NOD => mp
+                .plock_get_nod(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            NOL => mp
+                .plock_get_nol(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            BAL => mp.plock_get_bal(trig_index)?.map(Atom::from),
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::RsHard(mp) => match action {
+            LEV => mp
+                .plock_get_lev(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            TUN => mp
+                .plock_get_tun(trig_index)?
+                .map(|val| Atom::from(f64::from(val))),
+            DEC => mp
+                .plock_get_dec(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            SWD => mp
+                .plock_get_swd(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            TIC => mp
+                .plock_get_tic(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            NOL => mp
+                .plock_get_nol(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            SYN => mp
+                .plock_get_syn(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            SWT => mp
+                .plock_get_swt(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::RsClassic(mp) => match action {
+            LEV => mp
+                .plock_get_lev(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            T1 => mp
+                .plock_get_t1(trig_index)?
+                .map(|val| Atom::from(f64::from(val))),
+            DEC => mp
+                .plock_get_dec(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            BAL => mp.plock_get_bal(trig_index)?.map(Atom::from),
+            T2 => mp
+                .plock_get_t2(trig_index)?
+                .map(|val| Atom::from(f64::from(val))),
+            SYM => mp.plock_get_sym(trig_index)?.map(Atom::from),
+            NOL => mp
+                .plock_get_nol(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            TIC => mp
+                .plock_get_tic(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::CpClassic(mp) => match action {
+            LEV => mp
+                .plock_get_lev(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            TON => mp
+                .plock_get_ton(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            NOD => mp
+                .plock_get_nod(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            NUM => mp
+                .plock_get_num(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            RAT => mp
+                .plock_get_rat(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            NOL => mp
+                .plock_get_nol(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            RND => mp
+                .plock_get_rnd(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            CPT => mp
+                .plock_get_cpt(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::BtClassic(mp) => match action {
+            LEV => mp
+                .plock_get_lev(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            TUN => mp.plock_get_tun(trig_index)?.map(Atom::from),
+            DEC => mp
+                .plock_get_dec(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            NOL => mp
+                .plock_get_nol(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            SNP => mp
+                .plock_get_snp(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            SWD => mp
+                .plock_get_swd(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::XtClassic(mp) => match action {
+            LEV => mp
+                .plock_get_lev(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            TUN => mp.plock_get_tun(trig_index)?.map(Atom::from),
+            DEC => mp
+                .plock_get_dec(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            SWD => mp
+                .plock_get_swd(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            SWT => mp
+                .plock_get_swt(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            NOD => mp
+                .plock_get_nod(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            NOL => mp
+                .plock_get_nol(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            TON => mp.plock_get_ton(trig_index)?.map(Atom::from),
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::ChClassic(mp) => match action {
+            LEV => mp
+                .plock_get_lev(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            TUN => mp.plock_get_tun(trig_index)?.map(Atom::from),
+            DEC => mp
+                .plock_get_dec(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            COL => mp.plock_get_col(trig_index)?.map(Atom::from),
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::OhClassic(mp) => match action {
+            LEV => mp
+                .plock_get_lev(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            TUN => mp.plock_get_tun(trig_index)?.map(Atom::from),
+            DEC => mp
+                .plock_get_dec(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            COL => mp.plock_get_col(trig_index)?.map(Atom::from),
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::CyClassic(mp) => match action {
+            LEV => mp
+                .plock_get_lev(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            TUN => mp.plock_get_tun(trig_index)?.map(Atom::from),
+            DEC => mp
+                .plock_get_dec(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            COL => mp.plock_get_col(trig_index)?.map(Atom::from),
+            TON => mp.plock_get_ton(trig_index)?.map(Atom::from),
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::CbClassic(mp) => match action {
+            LEV => mp
+                .plock_get_lev(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            TUN => mp.plock_get_tun(trig_index)?.map(Atom::from),
+            DEC => mp
+                .plock_get_dec(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            DET => mp
+                .plock_get_det(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            PW1 => mp.plock_get_pw1(trig_index)?.map(Atom::from),
+            PW2 => mp.plock_get_pw2(trig_index)?.map(Atom::from),
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::BdFm(mp) => match action {
+            LEV => mp
+                .plock_get_lev(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            TUN => mp
+                .plock_get_tun(trig_index)?
+                .map(|val| Atom::from(f64::from(val))),
+            DEC => mp
+                .plock_get_dec(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            FMA => mp
+                .plock_get_fma(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            SWT => mp
+                .plock_get_swt(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            FMS => mp
+                .plock_get_fms(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            FMD => mp
+                .plock_get_fmd(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            FMT => mp
+                .plock_get_fmt(trig_index)?
+                .map(|val| Atom::from(f64::from(val))),
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::SdFm(mp) => match action {
+            LEV => mp
+                .plock_get_lev(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            TUN => mp
+                .plock_get_tun(trig_index)?
+                .map(|val| Atom::from(f64::from(val))),
+            DEC => mp
+                .plock_get_dec(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            FMT => mp
+                .plock_get_fmt(trig_index)?
+                .map(|val| Atom::from(f64::from(val))),
+            FMD => mp
+                .plock_get_fmd(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            NOD => mp
+                .plock_get_nod(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            NOL => mp
+                .plock_get_nol(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            FMA => mp
+                .plock_get_fma(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::UtNoise(mp) => match action {
+            LEV => mp
+                .plock_get_lev(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            LPF => mp
+                .plock_get_lpf(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            DEC => mp
+                .plock_get_dec(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            HPF => mp
+                .plock_get_hpf(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            LPQ => mp
+                .plock_get_lpq(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            ATK => mp
+                .plock_get_atk(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            SWT => mp
+                .plock_get_swt(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            SWD => mp.plock_get_swd(trig_index)?.map(Atom::from),
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::UtImpulse(mp) => match action {
+            LEV => mp
+                .plock_get_lev(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            ATK => mp
+                .plock_get_atk(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            DEC => mp
+                .plock_get_dec(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            POL => mp
+                .plock_get_pol(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::ChMetallic(mp) => match action {
+            LEV => mp
+                .plock_get_lev(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            TUN => mp.plock_get_tun(trig_index)?.map(Atom::from),
+            DEC => mp
+                .plock_get_dec(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::OhMetallic(mp) => match action {
+            LEV => mp
+                .plock_get_lev(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            TUN => mp.plock_get_tun(trig_index)?.map(Atom::from),
+            DEC => mp
+                .plock_get_dec(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::CyMetallic(mp) => match action {
+            LEV => mp
+                .plock_get_lev(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            TUN => mp.plock_get_tun(trig_index)?.map(Atom::from),
+            DEC => mp
+                .plock_get_dec(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            TON => mp.plock_get_ton(trig_index)?.map(Atom::from),
+            TRD => mp
+                .plock_get_trd(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::CbMetallic(mp) => match action {
+            LEV => mp
+                .plock_get_lev(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            TUN => mp.plock_get_tun(trig_index)?.map(Atom::from),
+            DEC => mp
+                .plock_get_dec(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            DET => mp
+                .plock_get_det(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            PW1 => mp.plock_get_pw1(trig_index)?.map(Atom::from),
+            PW2 => mp.plock_get_pw2(trig_index)?.map(Atom::from),
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::BdPlastic(mp) => match action {
+            LEV => mp
+                .plock_get_lev(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            TUN => mp
+                .plock_get_tun(trig_index)?
+                .map(|val| Atom::from(f64::from(val))),
+            DEC => mp
+                .plock_get_dec(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            TYP => mp
+                .plock_get_typ(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            MOD => mp
+                .plock_get_mod_level(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            SWT => mp
+                .plock_get_swt(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            SWD => mp
+                .plock_get_swd(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            TIC => mp
+                .plock_get_tic(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::BdSilky(mp) => match action {
+            LEV => mp
+                .plock_get_lev(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            TUN => mp
+                .plock_get_tun(trig_index)?
+                .map(|val| Atom::from(f64::from(val))),
+            DEC => mp
+                .plock_get_dec(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            HLD => mp
+                .plock_get_hld(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            SWT => mp
+                .plock_get_swt(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            SWD => mp
+                .plock_get_swd(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            DUS => mp
+                .plock_get_dus(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            CLK => mp
+                .plock_get_clk(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::SdNatural(mp) => match action {
+            LEV => mp
+                .plock_get_lev(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            TUN => mp
+                .plock_get_tun(trig_index)?
+                .map(|val| Atom::from(f64::from(val))),
+            BDY => mp
+                .plock_get_bdy(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            DEC => mp
+                .plock_get_dec(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            BAL => mp
+                .plock_get_bal(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            LPF => mp
+                .plock_get_lpf(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            HPF => mp
+                .plock_get_hpf(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            RES => mp
+                .plock_get_res(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::HhBasic(mp) => match action {
+            LEV => mp
+                .plock_get_lev(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            TUN => mp.plock_get_tun(trig_index)?.map(Atom::from),
+            DEC => mp
+                .plock_get_dec(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            TON => mp.plock_get_ton(trig_index)?.map(Atom::from),
+            TRD => mp
+                .plock_get_trd(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            RST => mp
+                .plock_get_rst(trig_index)?
+                .map(|val| Atom::from(isize::from(val))),
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::CyRide(mp) => match action {
+            LEV => mp
+                .plock_get_lev(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            TUN => mp.plock_get_tun(trig_index)?.map(Atom::from),
+            DEC => mp
+                .plock_get_dec(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            TYP => mp
+                .plock_get_typ(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            HIT => mp
+                .plock_get_hit(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            C1 => mp
+                .plock_get_c1(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            C2 => mp
+                .plock_get_c2(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            C3 => mp
+                .plock_get_c3(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::BdSharp(mp) => match action {
+            LEV => mp
+                .plock_get_lev(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            TUN => mp
+                .plock_get_tun(trig_index)?
+                .map(|val| Atom::from(f64::from(val))),
+            DEC => mp
+                .plock_get_dec(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            HLD => mp
+                .plock_get_hld(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            SWT => mp
+                .plock_get_swt(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            SWD => mp
+                .plock_get_swd(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            TIC => mp
+                .plock_get_tic(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::SyDualVco(mp) => match action {
+            LEV => mp
+                .plock_get_lev(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            TUN => mp
+                .plock_get_tun(trig_index)?
+                .map(|val| Atom::from(f64::from(val))),
+            DEC1 => mp
+                .plock_get_dec1(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            DET => mp
+                .plock_get_det(trig_index)?
+                .map(|val| Atom::from(f64::from(val))),
+            DEC2 => mp
+                .plock_get_dec2(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            BAL => mp.plock_get_bal(trig_index)?.map(Atom::from),
+            BND => mp.plock_get_bnd(trig_index)?.map(Atom::from),
+            CFG => mp
+                .plock_get_cfg(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::SyChip(mp) => match action {
+            LEV => mp
+                .plock_get_lev(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            TUN => mp
+                .plock_get_tun(trig_index)?
+                .map(|val| Atom::from(f64::from(val))),
+            DEC => mp
+                .plock_get_dec(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            OF2 => mp.plock_get_of2(trig_index)?.map(Atom::from),
+            OF3 => mp.plock_get_of3(trig_index)?.map(Atom::from),
+            OF4 => mp.plock_get_of4(trig_index)?.map(Atom::from),
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::BdAcoustic(mp) => match action {
+            LEV => mp
+                .plock_get_lev(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            TUN => mp
+                .plock_get_tun(trig_index)?
+                .map(|val| Atom::from(f64::from(val))),
+            DEC => mp
+                .plock_get_dec(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            HLD => mp
+                .plock_get_hld(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            SWT => mp
+                .plock_get_swt(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            SWD => mp
+                .plock_get_swd(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            IMP => mp
+                .plock_get_imp(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::SdAcoustic(mp) => match action {
+            LEV => mp
+                .plock_get_lev(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            TUN => mp
+                .plock_get_tun(trig_index)?
+                .map(|val| Atom::from(f64::from(val))),
+            BDY => mp
+                .plock_get_bdy(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            NOD => mp
+                .plock_get_nod(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            NOL => mp
+                .plock_get_nol(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            HLD => mp
+                .plock_get_hld(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            SWD => mp
+                .plock_get_swd(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            IMP => mp
+                .plock_get_imp(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::SyRaw(mp) => match action {
+            LEV => mp
+                .plock_get_lev(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            TUN => mp
+                .plock_get_tun(trig_index)?
+                .map(|val| Atom::from(f64::from(val))),
+            DEC2 => mp
+                .plock_get_dec2(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            DET => mp
+                .plock_get_det(trig_index)?
+                .map(|val| Atom::from(f64::from(val))),
+            NLEV => mp
+                .plock_get_nlev(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            BAL => mp.plock_get_bal(trig_index)?.map(Atom::from),
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::HhLab(mp) => match action {
+            LEV => mp
+                .plock_get_lev(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            OSC1 => mp
+                .plock_get_osc1(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            DEC => mp
+                .plock_get_dec(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            OSC2 => mp
+                .plock_get_osc2(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            OSC3 => mp
+                .plock_get_osc3(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            OSC4 => mp
+                .plock_get_osc4(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            OSC5 => mp
+                .plock_get_osc5(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            OSC6 => mp
+                .plock_get_osc6(trig_index)?
+                .map(|val| Atom::from(val as isize)),
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::Disable | MachineParameters::Unset => {
+            return Err(invalid_machine_parameter(machine_type, action))
+        }
+    })
+}
+
+pub fn handle_trig_plock_get_machine_enum_value(
+    trig: &Trig,
+    sound: &Sound,
+    enum_type: &str,
+    out: &Outlet,
+) -> Result<(), RytmExternalError> {
+    let enum_value = trig_plock_get_machine_enum_value(trig, sound, enum_type)?;
+
+    let enum_value_atom = enum_value.map(|value| Atom::from(SymbolRef::from(value.as_str())));
+    send_plock_get_output(enum_type, trig.index(), enum_value_atom, out);
+    Ok(())
+}
+
+/// Returns `None` for a parameter which is not locked.
+pub fn trig_plock_get_machine_enum_value(
+    trig: &Trig,
+    sound: &Sound,
+    enum_type: &str,
+) -> Result<Option<String>, RytmExternalError> {
+    let machine_type = sound.machine_type();
+    let trig_index = trig.index();
+
+    Ok(match (sound.machine_parameters(), enum_type) {
+        (MachineParameters::BdAcoustic(mp), BD_ACOUSTIC_WAVEFORM) => mp
+            .plock_get_wav(trig_index)?
+            .map(bd_acoustic_waveform_to_string),
+        (MachineParameters::BdSharp(mp), BD_SHARP_WAVEFORM) => mp
+            .plock_get_wav(trig_index)?
+            .map(bd_sharp_waveform_to_string),
+        (MachineParameters::SyChip(mp), SY_CHIP_WAVEFORM) => mp
+            .plock_get_wav(trig_index)?
+            .map(sy_chip_waveform_to_string),
+        (MachineParameters::SyChip(mp), SY_CHIP_SPEED) => {
+            mp.plock_get_spd(trig_index)?.map(sy_chip_speed_to_string)
+        }
+        (MachineParameters::SyRaw(mp), SY_RAW_WAVEFORM_1) => mp
+            .plock_get_wav1(trig_index)?
+            .map(sy_raw_waveform_1_to_string),
+        (MachineParameters::SyRaw(mp), SY_RAW_WAVEFORM_2) => mp
+            .plock_get_wav2(trig_index)?
+            .map(sy_raw_waveform_2_to_string),
+        (_, other) => return Err(invalid_machine_parameter(machine_type, other)),
+    })
+}
+
+pub fn handle_trig_plock_set_machine_action(
+    trig: &Trig,
+    sound: &Sound,
+    action: &str,
+    parameter_atom: &Atom,
+) -> Result<(), RytmExternalError> {
+    let machine_type = sound.machine_type();
+    let trig_index = trig.index();
+
+    match sound.machine_parameters() {
+        MachineParameters::BdHard(mp) => match action {
+            LEV => mp.plock_set_lev(parameter_atom.get_int() as usize, trig_index)?,
+            TUN => mp.plock_set_tun(parameter_atom.get_float() as f32, trig_index)?,
+            DEC => mp.plock_set_dec(parameter_atom.get_int() as usize, trig_index)?,
+            HLD => mp.plock_set_hld(parameter_atom.get_int() as usize, trig_index)?,
+            SWT => mp.plock_set_swt(parameter_atom.get_int() as usize, trig_index)?,
+            SNP => mp.plock_set_snp(parameter_atom.get_int() as usize, trig_index)?,
+            WAV => mp.plock_set_wav(parameter_atom.get_int() as usize, trig_index)?,
+            TIC => mp.plock_set_tic(parameter_atom.get_int() as usize, trig_index)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::BdClassic(mp) => match action {
+            LEV => mp.plock_set_lev(parameter_atom.get_int() as usize, trig_index)?,
+            TUN => mp.plock_set_tun(parameter_atom.get_float() as f32, trig_index)?,
+            DEC => mp.plock_set_dec(parameter_atom.get_int() as usize, trig_index)?,
+            HLD => mp.plock_set_hld(parameter_atom.get_int() as usize, trig_index)?,
+            SWT => mp.plock_set_swt(parameter_atom.get_int() as usize, trig_index)?,
+            SWD => mp.plock_set_swd(parameter_atom.get_int() as usize, trig_index)?,
+            WAV => mp.plock_set_wav(parameter_atom.get_int() as usize, trig_index)?,
+            TRA => mp.plock_set_tra(parameter_atom.get_int() as usize, trig_index)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::SdHard(mp) => match action {
+            LEV => mp.plock_set_lev(parameter_atom.get_int() as usize, trig_index)?,
+            TUN => mp.plock_set_tun(parameter_atom.get_float() as f32, trig_index)?,
+            DEC => mp.plock_set_dec(parameter_atom.get_int() as usize, trig_index)?,
+            SWD => mp.plock_set_swd(parameter_atom.get_int() as usize, trig_index)?,
+            TIC => mp.plock_set_tic(parameter_atom.get_int() as usize, trig_index)?,
+            NOD => mp.plock_set_nod(parameter_atom.get_int() as usize, trig_index)?,
+            NOL => mp.plock_set_nol(parameter_atom.get_int() as usize, trig_index)?,
+            SWT => mp.plock_set_swt(parameter_atom.get_int() as usize, trig_index)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::SdClassic(mp) => match action {
+            LEV => mp.plock_set_lev(parameter_atom.get_int() as usize, trig_index)?,
+            TUN => mp.plock_set_tun(parameter_atom.get_float() as f32, trig_index)?,
+            DEC => mp.plock_set_dec(parameter_atom.get_int() as usize, trig_index)?,
+            DET => mp.plock_set_det(parameter_atom.get_float() as f32, trig_index)?,
+            SNP => mp.plock_set_snp(parameter_atom.get_int() as usize, trig_index)?,
+            NOD => mp.plock_set_nod(parameter_atom.get_int() as usize, trig_index)?,
+            NOL => mp.plock_set_nol(parameter_atom.get_int() as usize, trig_index)?,
+            BAL => mp.plock_set_bal(parameter_atom.get_int(), trig_index)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::RsHard(mp) => match action {
+            LEV => mp.plock_set_lev(parameter_atom.get_int() as usize, trig_index)?,
+            TUN => mp.plock_set_tun(parameter_atom.get_float() as f32, trig_index)?,
+            DEC => mp.plock_set_dec(parameter_atom.get_int() as usize, trig_index)?,
+            SWD => mp.plock_set_swd(parameter_atom.get_int() as usize, trig_index)?,
+            TIC => mp.plock_set_tic(parameter_atom.get_int() as usize, trig_index)?,
+            NOL => mp.plock_set_nol(parameter_atom.get_int() as usize, trig_index)?,
+            SYN => mp.plock_set_syn(parameter_atom.get_int() as usize, trig_index)?,
+            SWT => mp.plock_set_swt(parameter_atom.get_int() as usize, trig_index)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::RsClassic(mp) => match action {
+            LEV => mp.plock_set_lev(parameter_atom.get_int() as usize, trig_index)?,
+            T1 => mp.plock_set_t1(parameter_atom.get_float() as f32, trig_index)?,
+            DEC => mp.plock_set_dec(parameter_atom.get_int() as usize, trig_index)?,
+            BAL => mp.plock_set_bal(parameter_atom.get_int(), trig_index)?,
+            T2 => mp.plock_set_t2(parameter_atom.get_float() as f32, trig_index)?,
+            SYM => mp.plock_set_sym(parameter_atom.get_int(), trig_index)?,
+            NOL => mp.plock_set_nol(parameter_atom.get_int() as usize, trig_index)?,
+            TIC => mp.plock_set_tic(parameter_atom.get_int() as usize, trig_index)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::CpClassic(mp) => match action {
+            LEV => mp.plock_set_lev(parameter_atom.get_int() as usize, trig_index)?,
+            TON => mp.plock_set_ton(parameter_atom.get_int() as usize, trig_index)?,
+            NOD => mp.plock_set_nod(parameter_atom.get_int() as usize, trig_index)?,
+            NUM => mp.plock_set_num(parameter_atom.get_int() as usize, trig_index)?,
+            RAT => mp.plock_set_rat(parameter_atom.get_int() as usize, trig_index)?,
+            NOL => mp.plock_set_nol(parameter_atom.get_int() as usize, trig_index)?,
+            RND => mp.plock_set_rnd(parameter_atom.get_int() as usize, trig_index)?,
+            CPT => mp.plock_set_cpt(parameter_atom.get_int() as usize, trig_index)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::BtClassic(mp) => match action {
+            LEV => mp.plock_set_lev(parameter_atom.get_int() as usize, trig_index)?,
+            TUN => mp.plock_set_tun(parameter_atom.get_int(), trig_index)?,
+            DEC => mp.plock_set_dec(parameter_atom.get_int() as usize, trig_index)?,
+            NOL => mp.plock_set_nol(parameter_atom.get_int() as usize, trig_index)?,
+            SNP => mp.plock_set_snp(parameter_atom.get_int() as usize, trig_index)?,
+            SWD => mp.plock_set_swd(parameter_atom.get_int() as usize, trig_index)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::XtClassic(mp) => match action {
+            LEV => mp.plock_set_lev(parameter_atom.get_int() as usize, trig_index)?,
+            TUN => mp.plock_set_tun(parameter_atom.get_int(), trig_index)?,
+            DEC => mp.plock_set_dec(parameter_atom.get_int() as usize, trig_index)?,
+            SWD => mp.plock_set_swd(parameter_atom.get_int() as usize, trig_index)?,
+            SWT => mp.plock_set_swt(parameter_atom.get_int() as usize, trig_index)?,
+            NOD => mp.plock_set_nod(parameter_atom.get_int() as usize, trig_index)?,
+            NOL => mp.plock_set_nol(parameter_atom.get_int() as usize, trig_index)?,
+            TON => mp.plock_set_ton(parameter_atom.get_int(), trig_index)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::ChClassic(mp) => match action {
+            LEV => mp.plock_set_lev(parameter_atom.get_int() as usize, trig_index)?,
+            TUN => mp.plock_set_tun(parameter_atom.get_int(), trig_index)?,
+            DEC => mp.plock_set_dec(parameter_atom.get_int() as usize, trig_index)?,
+            COL => mp.plock_set_col(parameter_atom.get_int(), trig_index)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::OhClassic(mp) => match action {
+            LEV => mp.plock_set_lev(parameter_atom.get_int() as usize, trig_index)?,
+            TUN => mp.plock_set_tun(parameter_atom.get_int(), trig_index)?,
+            DEC => mp.plock_set_dec(parameter_atom.get_int() as usize, trig_index)?,
+            COL => mp.plock_set_col(parameter_atom.get_int(), trig_index)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::CyClassic(mp) => match action {
+            LEV => mp.plock_set_lev(parameter_atom.get_int() as usize, trig_index)?,
+            TUN => mp.plock_set_tun(parameter_atom.get_int(), trig_index)?,
+            DEC => mp.plock_set_dec(parameter_atom.get_int() as usize, trig_index)?,
+            COL => mp.plock_set_col(parameter_atom.get_int(), trig_index)?,
+            TON => mp.plock_set_ton(parameter_atom.get_int(), trig_index)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::CbClassic(mp) => match action {
+            LEV => mp.plock_set_lev(parameter_atom.get_int() as usize, trig_index)?,
+            TUN => mp.plock_set_tun(parameter_atom.get_int(), trig_index)?,
+            DEC => mp.plock_set_dec(parameter_atom.get_int() as usize, trig_index)?,
+            DET => mp.plock_set_det(parameter_atom.get_int() as usize, trig_index)?,
+            PW1 => mp.plock_set_pw1(parameter_atom.get_int(), trig_index)?,
+            PW2 => mp.plock_set_pw2(parameter_atom.get_int(), trig_index)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::BdFm(mp) => match action {
+            LEV => mp.plock_set_lev(parameter_atom.get_int() as usize, trig_index)?,
+            TUN => mp.plock_set_tun(parameter_atom.get_float() as f32, trig_index)?,
+            DEC => mp.plock_set_dec(parameter_atom.get_int() as usize, trig_index)?,
+            FMA => mp.plock_set_fma(parameter_atom.get_int() as usize, trig_index)?,
+            SWT => mp.plock_set_swt(parameter_atom.get_int() as usize, trig_index)?,
+            FMS => mp.plock_set_fms(parameter_atom.get_int() as usize, trig_index)?,
+            FMD => mp.plock_set_fmd(parameter_atom.get_int() as usize, trig_index)?,
+            FMT => mp.plock_set_fmt(parameter_atom.get_float() as f32, trig_index)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::SdFm(mp) => match action {
+            LEV => mp.plock_set_lev(parameter_atom.get_int() as usize, trig_index)?,
+            TUN => mp.plock_set_tun(parameter_atom.get_float() as f32, trig_index)?,
+            DEC => mp.plock_set_dec(parameter_atom.get_int() as usize, trig_index)?,
+            FMT => mp.plock_set_fmt(parameter_atom.get_float() as f32, trig_index)?,
+            FMD => mp.plock_set_fmd(parameter_atom.get_int() as usize, trig_index)?,
+            NOD => mp.plock_set_nod(parameter_atom.get_int() as usize, trig_index)?,
+            NOL => mp.plock_set_nol(parameter_atom.get_int() as usize, trig_index)?,
+            FMA => mp.plock_set_fma(parameter_atom.get_int() as usize, trig_index)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::UtNoise(mp) => match action {
+            LEV => mp.plock_set_lev(parameter_atom.get_int() as usize, trig_index)?,
+            LPF => mp.plock_set_lpf(parameter_atom.get_int() as usize, trig_index)?,
+            DEC => mp.plock_set_dec(parameter_atom.get_int() as usize, trig_index)?,
+            HPF => mp.plock_set_hpf(parameter_atom.get_int() as usize, trig_index)?,
+            LPQ => mp.plock_set_lpq(parameter_atom.get_int() as usize, trig_index)?,
+            ATK => mp.plock_set_atk(parameter_atom.get_int() as usize, trig_index)?,
+            SWT => mp.plock_set_swt(parameter_atom.get_int() as usize, trig_index)?,
+            SWD => mp.plock_set_swd(parameter_atom.get_int(), trig_index)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::UtImpulse(mp) => match action {
+            LEV => mp.plock_set_lev(parameter_atom.get_int() as usize, trig_index)?,
+            ATK => mp.plock_set_atk(parameter_atom.get_int() as usize, trig_index)?,
+            DEC => mp.plock_set_dec(parameter_atom.get_int() as usize, trig_index)?,
+            POL => mp.plock_set_pol(parameter_atom.get_int() as usize, trig_index)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::ChMetallic(mp) => match action {
+            LEV => mp.plock_set_lev(parameter_atom.get_int() as usize, trig_index)?,
+            TUN => mp.plock_set_tun(parameter_atom.get_int(), trig_index)?,
+            DEC => mp.plock_set_dec(parameter_atom.get_int() as usize, trig_index)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::OhMetallic(mp) => match action {
+            LEV => mp.plock_set_lev(parameter_atom.get_int() as usize, trig_index)?,
+            TUN => mp.plock_set_tun(parameter_atom.get_int(), trig_index)?,
+            DEC => mp.plock_set_dec(parameter_atom.get_int() as usize, trig_index)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::CyMetallic(mp) => match action {
+            LEV => mp.plock_set_lev(parameter_atom.get_int() as usize, trig_index)?,
+            TUN => mp.plock_set_tun(parameter_atom.get_int(), trig_index)?,
+            DEC => mp.plock_set_dec(parameter_atom.get_int() as usize, trig_index)?,
+            TON => mp.plock_set_ton(parameter_atom.get_int(), trig_index)?,
+            TRD => mp.plock_set_trd(parameter_atom.get_int() as usize, trig_index)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::CbMetallic(mp) => match action {
+            LEV => mp.plock_set_lev(parameter_atom.get_int() as usize, trig_index)?,
+            TUN => mp.plock_set_tun(parameter_atom.get_int(), trig_index)?,
+            DEC => mp.plock_set_dec(parameter_atom.get_int() as usize, trig_index)?,
+            DET => mp.plock_set_det(parameter_atom.get_int() as usize, trig_index)?,
+            PW1 => mp.plock_set_pw1(parameter_atom.get_int(), trig_index)?,
+            PW2 => mp.plock_set_pw2(parameter_atom.get_int(), trig_index)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::BdPlastic(mp) => match action {
+            LEV => mp.plock_set_lev(parameter_atom.get_int() as usize, trig_index)?,
+            TUN => mp.plock_set_tun(parameter_atom.get_float() as f32, trig_index)?,
+            DEC => mp.plock_set_dec(parameter_atom.get_int() as usize, trig_index)?,
+            TYP => mp.plock_set_typ(parameter_atom.get_int() as usize, trig_index)?,
+            MOD => mp.plock_set_mod_level(parameter_atom.get_int() as usize, trig_index)?,
+            SWT => mp.plock_set_swt(parameter_atom.get_int() as usize, trig_index)?,
+            SWD => mp.plock_set_swd(parameter_atom.get_int() as usize, trig_index)?,
+            TIC => mp.plock_set_tic(parameter_atom.get_int() as usize, trig_index)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::BdSilky(mp) => match action {
+            LEV => mp.plock_set_lev(parameter_atom.get_int() as usize, trig_index)?,
+            TUN => mp.plock_set_tun(parameter_atom.get_float() as f32, trig_index)?,
+            DEC => mp.plock_set_dec(parameter_atom.get_int() as usize, trig_index)?,
+            HLD => mp.plock_set_hld(parameter_atom.get_int() as usize, trig_index)?,
+            SWT => mp.plock_set_swt(parameter_atom.get_int() as usize, trig_index)?,
+            SWD => mp.plock_set_swd(parameter_atom.get_int() as usize, trig_index)?,
+            DUS => mp.plock_set_dus(parameter_atom.get_int() as usize, trig_index)?,
+            CLK => mp.plock_set_clk(parameter_atom.get_int() as usize, trig_index)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::SdNatural(mp) => match action {
+            LEV => mp.plock_set_lev(parameter_atom.get_int() as usize, trig_index)?,
+            TUN => mp.plock_set_tun(parameter_atom.get_float() as f32, trig_index)?,
+            BDY => mp.plock_set_bdy(parameter_atom.get_int() as usize, trig_index)?,
+            DEC => mp.plock_set_dec(parameter_atom.get_int() as usize, trig_index)?,
+            BAL => mp.plock_set_bal(parameter_atom.get_int() as usize, trig_index)?,
+            LPF => mp.plock_set_lpf(parameter_atom.get_int() as usize, trig_index)?,
+            HPF => mp.plock_set_hpf(parameter_atom.get_int() as usize, trig_index)?,
+            RES => mp.plock_set_res(parameter_atom.get_int() as usize, trig_index)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::HhBasic(mp) => match action {
+            LEV => mp.plock_set_lev(parameter_atom.get_int() as usize, trig_index)?,
+            TUN => mp.plock_set_tun(parameter_atom.get_int(), trig_index)?,
+            DEC => mp.plock_set_dec(parameter_atom.get_int() as usize, trig_index)?,
+            TON => mp.plock_set_ton(parameter_atom.get_int(), trig_index)?,
+            TRD => mp.plock_set_trd(parameter_atom.get_int() as usize, trig_index)?,
+            RST => mp.plock_set_rst(get_bool_from_0_or_1(parameter_atom, RST)?, trig_index)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::CyRide(mp) => match action {
+            LEV => mp.plock_set_lev(parameter_atom.get_int() as usize, trig_index)?,
+            TUN => mp.plock_set_tun(parameter_atom.get_int(), trig_index)?,
+            DEC => mp.plock_set_dec(parameter_atom.get_int() as usize, trig_index)?,
+            TYP => mp.plock_set_typ(parameter_atom.get_int() as usize, trig_index)?,
+            HIT => mp.plock_set_hit(parameter_atom.get_int() as usize, trig_index)?,
+            C1 => mp.plock_set_c1(parameter_atom.get_int() as usize, trig_index)?,
+            C2 => mp.plock_set_c2(parameter_atom.get_int() as usize, trig_index)?,
+            C3 => mp.plock_set_c3(parameter_atom.get_int() as usize, trig_index)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::BdSharp(mp) => match action {
+            LEV => mp.plock_set_lev(parameter_atom.get_int() as usize, trig_index)?,
+            TUN => mp.plock_set_tun(parameter_atom.get_float() as f32, trig_index)?,
+            DEC => mp.plock_set_dec(parameter_atom.get_int() as usize, trig_index)?,
+            HLD => mp.plock_set_hld(parameter_atom.get_int() as usize, trig_index)?,
+            SWT => mp.plock_set_swt(parameter_atom.get_int() as usize, trig_index)?,
+            SWD => mp.plock_set_swd(parameter_atom.get_int() as usize, trig_index)?,
+            TIC => mp.plock_set_tic(parameter_atom.get_int() as usize, trig_index)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::SyDualVco(mp) => match action {
+            LEV => mp.plock_set_lev(parameter_atom.get_int() as usize, trig_index)?,
+            TUN => mp.plock_set_tun(parameter_atom.get_float() as f32, trig_index)?,
+            DEC1 => mp.plock_set_dec1(parameter_atom.get_int() as usize, trig_index)?,
+            DET => mp.plock_set_det(parameter_atom.get_float() as f32, trig_index)?,
+            DEC2 => mp.plock_set_dec2(parameter_atom.get_int() as usize, trig_index)?,
+            BAL => mp.plock_set_bal(parameter_atom.get_int(), trig_index)?,
+            BND => mp.plock_set_bnd(parameter_atom.get_int(), trig_index)?,
+            CFG => mp.plock_set_cfg(parameter_atom.get_int() as usize, trig_index)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::SyChip(mp) => match action {
+            LEV => mp.plock_set_lev(parameter_atom.get_int() as usize, trig_index)?,
+            TUN => mp.plock_set_tun(parameter_atom.get_float() as f32, trig_index)?,
+            DEC => mp.plock_set_dec(parameter_atom.get_int() as usize, trig_index)?,
+            OF2 => mp.plock_set_of2(parameter_atom.get_int(), trig_index)?,
+            OF3 => mp.plock_set_of3(parameter_atom.get_int(), trig_index)?,
+            OF4 => mp.plock_set_of4(parameter_atom.get_int(), trig_index)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::BdAcoustic(mp) => match action {
+            LEV => mp.plock_set_lev(parameter_atom.get_int() as usize, trig_index)?,
+            TUN => mp.plock_set_tun(parameter_atom.get_float() as f32, trig_index)?,
+            DEC => mp.plock_set_dec(parameter_atom.get_int() as usize, trig_index)?,
+            HLD => mp.plock_set_hld(parameter_atom.get_int() as usize, trig_index)?,
+            SWT => mp.plock_set_swt(parameter_atom.get_int() as usize, trig_index)?,
+            SWD => mp.plock_set_swd(parameter_atom.get_int() as usize, trig_index)?,
+            IMP => mp.plock_set_imp(parameter_atom.get_int() as usize, trig_index)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::SdAcoustic(mp) => match action {
+            LEV => mp.plock_set_lev(parameter_atom.get_int() as usize, trig_index)?,
+            TUN => mp.plock_set_tun(parameter_atom.get_float() as f32, trig_index)?,
+            BDY => mp.plock_set_bdy(parameter_atom.get_int() as usize, trig_index)?,
+            NOD => mp.plock_set_nod(parameter_atom.get_int() as usize, trig_index)?,
+            NOL => mp.plock_set_nol(parameter_atom.get_int() as usize, trig_index)?,
+            HLD => mp.plock_set_hld(parameter_atom.get_int() as usize, trig_index)?,
+            SWD => mp.plock_set_swd(parameter_atom.get_int() as usize, trig_index)?,
+            IMP => mp.plock_set_imp(parameter_atom.get_int() as usize, trig_index)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::SyRaw(mp) => match action {
+            LEV => mp.plock_set_lev(parameter_atom.get_int() as usize, trig_index)?,
+            TUN => mp.plock_set_tun(parameter_atom.get_float() as f32, trig_index)?,
+            DEC2 => mp.plock_set_dec2(parameter_atom.get_int() as usize, trig_index)?,
+            DET => mp.plock_set_det(parameter_atom.get_float() as f32, trig_index)?,
+            NLEV => mp.plock_set_nlev(parameter_atom.get_int() as usize, trig_index)?,
+            BAL => mp.plock_set_bal(parameter_atom.get_int(), trig_index)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::HhLab(mp) => match action {
+            LEV => mp.plock_set_lev(parameter_atom.get_int() as usize, trig_index)?,
+            OSC1 => mp.plock_set_osc1(parameter_atom.get_int() as usize, trig_index)?,
+            DEC => mp.plock_set_dec(parameter_atom.get_int() as usize, trig_index)?,
+            OSC2 => mp.plock_set_osc2(parameter_atom.get_int() as usize, trig_index)?,
+            OSC3 => mp.plock_set_osc3(parameter_atom.get_int() as usize, trig_index)?,
+            OSC4 => mp.plock_set_osc4(parameter_atom.get_int() as usize, trig_index)?,
+            OSC5 => mp.plock_set_osc5(parameter_atom.get_int() as usize, trig_index)?,
+            OSC6 => mp.plock_set_osc6(parameter_atom.get_int() as usize, trig_index)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::Disable | MachineParameters::Unset => {
+            return Err(invalid_machine_parameter(machine_type, action))
+        }
+    }
+
+    Ok(())
+}
+
+pub fn handle_trig_plock_set_machine_enum_value(
+    trig: &Trig,
+    sound: &Sound,
+    enum_type: &str,
+    enum_value: &str,
+) -> Result<(), RytmExternalError> {
+    let machine_type = sound.machine_type();
+    let trig_index = trig.index();
+
+    match (sound.machine_parameters(), enum_type) {
+        (MachineParameters::BdAcoustic(mp), BD_ACOUSTIC_WAVEFORM) => {
+            mp.plock_set_wav(enum_value.try_into()?, trig_index)?;
+        }
+        (MachineParameters::BdSharp(mp), BD_SHARP_WAVEFORM) => {
+            mp.plock_set_wav(enum_value.try_into()?, trig_index)?;
+        }
+        (MachineParameters::SyChip(mp), SY_CHIP_WAVEFORM) => {
+            mp.plock_set_wav(enum_value.try_into()?, trig_index)?;
+        }
+        (MachineParameters::SyChip(mp), SY_CHIP_SPEED) => {
+            mp.plock_set_spd(enum_value.try_into()?, trig_index)?;
+        }
+        (MachineParameters::SyRaw(mp), SY_RAW_WAVEFORM_1) => {
+            mp.plock_set_wav1(enum_value.try_into()?, trig_index)?;
+        }
+        (MachineParameters::SyRaw(mp), SY_RAW_WAVEFORM_2) => {
+            mp.plock_set_wav2(enum_value.try_into()?, trig_index)?;
+        }
+        (_, other) => return Err(invalid_machine_parameter(machine_type, other)),
+    }
+
+    Ok(())
+}
+
+pub fn handle_trig_plock_clear_machine_action(
+    trig: &Trig,
+    sound: &Sound,
+    action: &str,
+) -> Result<(), RytmExternalError> {
+    let machine_type = sound.machine_type();
+    let trig_index = trig.index();
+
+    match sound.machine_parameters() {
+        MachineParameters::BdHard(mp) => match action {
+            LEV => mp.plock_clear_lev(trig_index)?,
+            TUN => mp.plock_clear_tun(trig_index)?,
+            DEC => mp.plock_clear_dec(trig_index)?,
+            HLD => mp.plock_clear_hld(trig_index)?,
+            SWT => mp.plock_clear_swt(trig_index)?,
+            SNP => mp.plock_clear_snp(trig_index)?,
+            WAV => mp.plock_clear_wav(trig_index)?,
+            TIC => mp.plock_clear_tic(trig_index)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::BdClassic(mp) => match action {
+            LEV => mp.plock_clear_lev(trig_index)?,
+            TUN => mp.plock_clear_tun(trig_index)?,
+            DEC => mp.plock_clear_dec(trig_index)?,
+            HLD => mp.plock_clear_hld(trig_index)?,
+            SWT => mp.plock_clear_swt(trig_index)?,
+            SWD => mp.plock_clear_swd(trig_index)?,
+            WAV => mp.plock_clear_wav(trig_index)?,
+            TRA => mp.plock_clear_tra(trig_index)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::SdHard(mp) => match action {
+            LEV => mp.plock_clear_lev(trig_index)?,
+            TUN => mp.plock_clear_tun(trig_index)?,
+            DEC => mp.plock_clear_dec(trig_index)?,
+            SWD => mp.plock_clear_swd(trig_index)?,
+            TIC => mp.plock_clear_tic(trig_index)?,
+            NOD => mp.plock_clear_nod(trig_index)?,
+            NOL => mp.plock_clear_nol(trig_index)?,
+            SWT => mp.plock_clear_swt(trig_index)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::SdClassic(mp) => match action {
+            LEV => mp.plock_clear_lev(trig_index)?,
+            TUN => mp.plock_clear_tun(trig_index)?,
+            DEC => mp.plock_clear_dec(trig_index)?,
+            DET => mp.plock_clear_det(trig_index)?,
+            SNP => mp.plock_clear_snp(trig_index)?,
+            NOD => mp.plock_clear_nod(trig_index)?,
+            NOL => mp.plock_clear_nol(trig_index)?,
+            BAL => mp.plock_clear_bal(trig_index)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::RsHard(mp) => match action {
+            LEV => mp.plock_clear_lev(trig_index)?,
+            TUN => mp.plock_clear_tun(trig_index)?,
+            DEC => mp.plock_clear_dec(trig_index)?,
+            SWD => mp.plock_clear_swd(trig_index)?,
+            TIC => mp.plock_clear_tic(trig_index)?,
+            NOL => mp.plock_clear_nol(trig_index)?,
+            SYN => mp.plock_clear_syn(trig_index)?,
+            SWT => mp.plock_clear_swt(trig_index)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::RsClassic(mp) => match action {
+            LEV => mp.plock_clear_lev(trig_index)?,
+            T1 => mp.plock_clear_t1(trig_index)?,
+            DEC => mp.plock_clear_dec(trig_index)?,
+            BAL => mp.plock_clear_bal(trig_index)?,
+            T2 => mp.plock_clear_t2(trig_index)?,
+            SYM => mp.plock_clear_sym(trig_index)?,
+            NOL => mp.plock_clear_nol(trig_index)?,
+            TIC => mp.plock_clear_tic(trig_index)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::CpClassic(mp) => match action {
+            LEV => mp.plock_clear_lev(trig_index)?,
+            TON => mp.plock_clear_ton(trig_index)?,
+            NOD => mp.plock_clear_nod(trig_index)?,
+            NUM => mp.plock_clear_num(trig_index)?,
+            RAT => mp.plock_clear_rat(trig_index)?,
+            NOL => mp.plock_clear_nol(trig_index)?,
+            RND => mp.plock_clear_rnd(trig_index)?,
+            CPT => mp.plock_clear_cpt(trig_index)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::BtClassic(mp) => match action {
+            LEV => mp.plock_clear_lev(trig_index)?,
+            TUN => mp.plock_clear_tun(trig_index)?,
+            DEC => mp.plock_clear_dec(trig_index)?,
+            NOL => mp.plock_clear_nol(trig_index)?,
+            SNP => mp.plock_clear_snp(trig_index)?,
+            SWD => mp.plock_clear_swd(trig_index)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::XtClassic(mp) => match action {
+            LEV => mp.plock_clear_lev(trig_index)?,
+            TUN => mp.plock_clear_tun(trig_index)?,
+            DEC => mp.plock_clear_dec(trig_index)?,
+            SWD => mp.plock_clear_swd(trig_index)?,
+            SWT => mp.plock_clear_swt(trig_index)?,
+            NOD => mp.plock_clear_nod(trig_index)?,
+            NOL => mp.plock_clear_nol(trig_index)?,
+            TON => mp.plock_clear_ton(trig_index)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::ChClassic(mp) => match action {
+            LEV => mp.plock_clear_lev(trig_index)?,
+            TUN => mp.plock_clear_tun(trig_index)?,
+            DEC => mp.plock_clear_dec(trig_index)?,
+            COL => mp.plock_clear_col(trig_index)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::OhClassic(mp) => match action {
+            LEV => mp.plock_clear_lev(trig_index)?,
+            TUN => mp.plock_clear_tun(trig_index)?,
+            DEC => mp.plock_clear_dec(trig_index)?,
+            COL => mp.plock_clear_col(trig_index)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::CyClassic(mp) => match action {
+            LEV => mp.plock_clear_lev(trig_index)?,
+            TUN => mp.plock_clear_tun(trig_index)?,
+            DEC => mp.plock_clear_dec(trig_index)?,
+            COL => mp.plock_clear_col(trig_index)?,
+            TON => mp.plock_clear_ton(trig_index)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::CbClassic(mp) => match action {
+            LEV => mp.plock_clear_lev(trig_index)?,
+            TUN => mp.plock_clear_tun(trig_index)?,
+            DEC => mp.plock_clear_dec(trig_index)?,
+            DET => mp.plock_clear_det(trig_index)?,
+            PW1 => mp.plock_clear_pw1(trig_index)?,
+            PW2 => mp.plock_clear_pw2(trig_index)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::BdFm(mp) => match action {
+            LEV => mp.plock_clear_lev(trig_index)?,
+            TUN => mp.plock_clear_tun(trig_index)?,
+            DEC => mp.plock_clear_dec(trig_index)?,
+            FMA => mp.plock_clear_fma(trig_index)?,
+            SWT => mp.plock_clear_swt(trig_index)?,
+            FMS => mp.plock_clear_fms(trig_index)?,
+            FMD => mp.plock_clear_fmd(trig_index)?,
+            FMT => mp.plock_clear_fmt(trig_index)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::SdFm(mp) => match action {
+            LEV => mp.plock_clear_lev(trig_index)?,
+            TUN => mp.plock_clear_tun(trig_index)?,
+            DEC => mp.plock_clear_dec(trig_index)?,
+            FMT => mp.plock_clear_fmt(trig_index)?,
+            FMD => mp.plock_clear_fmd(trig_index)?,
+            NOD => mp.plock_clear_nod(trig_index)?,
+            NOL => mp.plock_clear_nol(trig_index)?,
+            FMA => mp.plock_clear_fma(trig_index)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::UtNoise(mp) => match action {
+            LEV => mp.plock_clear_lev(trig_index)?,
+            LPF => mp.plock_clear_lpf(trig_index)?,
+            DEC => mp.plock_clear_dec(trig_index)?,
+            HPF => mp.plock_clear_hpf(trig_index)?,
+            LPQ => mp.plock_clear_lpq(trig_index)?,
+            ATK => mp.plock_clear_atk(trig_index)?,
+            SWT => mp.plock_clear_swt(trig_index)?,
+            SWD => mp.plock_clear_swd(trig_index)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::UtImpulse(mp) => match action {
+            LEV => mp.plock_clear_lev(trig_index)?,
+            ATK => mp.plock_clear_atk(trig_index)?,
+            DEC => mp.plock_clear_dec(trig_index)?,
+            POL => mp.plock_clear_pol(trig_index)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::ChMetallic(mp) => match action {
+            LEV => mp.plock_clear_lev(trig_index)?,
+            TUN => mp.plock_clear_tun(trig_index)?,
+            DEC => mp.plock_clear_dec(trig_index)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::OhMetallic(mp) => match action {
+            LEV => mp.plock_clear_lev(trig_index)?,
+            TUN => mp.plock_clear_tun(trig_index)?,
+            DEC => mp.plock_clear_dec(trig_index)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::CyMetallic(mp) => match action {
+            LEV => mp.plock_clear_lev(trig_index)?,
+            TUN => mp.plock_clear_tun(trig_index)?,
+            DEC => mp.plock_clear_dec(trig_index)?,
+            TON => mp.plock_clear_ton(trig_index)?,
+            TRD => mp.plock_clear_trd(trig_index)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::CbMetallic(mp) => match action {
+            LEV => mp.plock_clear_lev(trig_index)?,
+            TUN => mp.plock_clear_tun(trig_index)?,
+            DEC => mp.plock_clear_dec(trig_index)?,
+            DET => mp.plock_clear_det(trig_index)?,
+            PW1 => mp.plock_clear_pw1(trig_index)?,
+            PW2 => mp.plock_clear_pw2(trig_index)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::BdPlastic(mp) => match action {
+            LEV => mp.plock_clear_lev(trig_index)?,
+            TUN => mp.plock_clear_tun(trig_index)?,
+            DEC => mp.plock_clear_dec(trig_index)?,
+            TYP => mp.plock_clear_typ(trig_index)?,
+            MOD => mp.plock_clear_mod_level(trig_index)?,
+            SWT => mp.plock_clear_swt(trig_index)?,
+            SWD => mp.plock_clear_swd(trig_index)?,
+            TIC => mp.plock_clear_tic(trig_index)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::BdSilky(mp) => match action {
+            LEV => mp.plock_clear_lev(trig_index)?,
+            TUN => mp.plock_clear_tun(trig_index)?,
+            DEC => mp.plock_clear_dec(trig_index)?,
+            HLD => mp.plock_clear_hld(trig_index)?,
+            SWT => mp.plock_clear_swt(trig_index)?,
+            SWD => mp.plock_clear_swd(trig_index)?,
+            DUS => mp.plock_clear_dus(trig_index)?,
+            CLK => mp.plock_clear_clk(trig_index)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::SdNatural(mp) => match action {
+            LEV => mp.plock_clear_lev(trig_index)?,
+            TUN => mp.plock_clear_tun(trig_index)?,
+            BDY => mp.plock_clear_bdy(trig_index)?,
+            DEC => mp.plock_clear_dec(trig_index)?,
+            BAL => mp.plock_clear_bal(trig_index)?,
+            LPF => mp.plock_clear_lpf(trig_index)?,
+            HPF => mp.plock_clear_hpf(trig_index)?,
+            RES => mp.plock_clear_res(trig_index)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::HhBasic(mp) => match action {
+            LEV => mp.plock_clear_lev(trig_index)?,
+            TUN => mp.plock_clear_tun(trig_index)?,
+            DEC => mp.plock_clear_dec(trig_index)?,
+            TON => mp.plock_clear_ton(trig_index)?,
+            TRD => mp.plock_clear_trd(trig_index)?,
+            RST => mp.plock_clear_rst(trig_index)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::CyRide(mp) => match action {
+            LEV => mp.plock_clear_lev(trig_index)?,
+            TUN => mp.plock_clear_tun(trig_index)?,
+            DEC => mp.plock_clear_dec(trig_index)?,
+            TYP => mp.plock_clear_typ(trig_index)?,
+            HIT => mp.plock_clear_hit(trig_index)?,
+            C1 => mp.plock_clear_c1(trig_index)?,
+            C2 => mp.plock_clear_c2(trig_index)?,
+            C3 => mp.plock_clear_c3(trig_index)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::BdSharp(mp) => match action {
+            LEV => mp.plock_clear_lev(trig_index)?,
+            TUN => mp.plock_clear_tun(trig_index)?,
+            DEC => mp.plock_clear_dec(trig_index)?,
+            HLD => mp.plock_clear_hld(trig_index)?,
+            SWT => mp.plock_clear_swt(trig_index)?,
+            SWD => mp.plock_clear_swd(trig_index)?,
+            TIC => mp.plock_clear_tic(trig_index)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::SyDualVco(mp) => match action {
+            LEV => mp.plock_clear_lev(trig_index)?,
+            TUN => mp.plock_clear_tun(trig_index)?,
+            DEC1 => mp.plock_clear_dec1(trig_index)?,
+            DET => mp.plock_clear_det(trig_index)?,
+            DEC2 => mp.plock_clear_dec2(trig_index)?,
+            BAL => mp.plock_clear_bal(trig_index)?,
+            BND => mp.plock_clear_bnd(trig_index)?,
+            CFG => mp.plock_clear_cfg(trig_index)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::SyChip(mp) => match action {
+            LEV => mp.plock_clear_lev(trig_index)?,
+            TUN => mp.plock_clear_tun(trig_index)?,
+            DEC => mp.plock_clear_dec(trig_index)?,
+            OF2 => mp.plock_clear_of2(trig_index)?,
+            OF3 => mp.plock_clear_of3(trig_index)?,
+            OF4 => mp.plock_clear_of4(trig_index)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::BdAcoustic(mp) => match action {
+            LEV => mp.plock_clear_lev(trig_index)?,
+            TUN => mp.plock_clear_tun(trig_index)?,
+            DEC => mp.plock_clear_dec(trig_index)?,
+            HLD => mp.plock_clear_hld(trig_index)?,
+            SWT => mp.plock_clear_swt(trig_index)?,
+            SWD => mp.plock_clear_swd(trig_index)?,
+            IMP => mp.plock_clear_imp(trig_index)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::SdAcoustic(mp) => match action {
+            LEV => mp.plock_clear_lev(trig_index)?,
+            TUN => mp.plock_clear_tun(trig_index)?,
+            BDY => mp.plock_clear_bdy(trig_index)?,
+            NOD => mp.plock_clear_nod(trig_index)?,
+            NOL => mp.plock_clear_nol(trig_index)?,
+            HLD => mp.plock_clear_hld(trig_index)?,
+            SWD => mp.plock_clear_swd(trig_index)?,
+            IMP => mp.plock_clear_imp(trig_index)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::SyRaw(mp) => match action {
+            LEV => mp.plock_clear_lev(trig_index)?,
+            TUN => mp.plock_clear_tun(trig_index)?,
+            DEC2 => mp.plock_clear_dec2(trig_index)?,
+            DET => mp.plock_clear_det(trig_index)?,
+            NLEV => mp.plock_clear_nlev(trig_index)?,
+            BAL => mp.plock_clear_bal(trig_index)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::HhLab(mp) => match action {
+            LEV => mp.plock_clear_lev(trig_index)?,
+            OSC1 => mp.plock_clear_osc1(trig_index)?,
+            DEC => mp.plock_clear_dec(trig_index)?,
+            OSC2 => mp.plock_clear_osc2(trig_index)?,
+            OSC3 => mp.plock_clear_osc3(trig_index)?,
+            OSC4 => mp.plock_clear_osc4(trig_index)?,
+            OSC5 => mp.plock_clear_osc5(trig_index)?,
+            OSC6 => mp.plock_clear_osc6(trig_index)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::Disable | MachineParameters::Unset => {
+            return Err(invalid_machine_parameter(machine_type, action))
+        }
+    }
+
+    Ok(())
+}
+
+pub fn handle_trig_plock_clear_machine_enum_value(
+    trig: &Trig,
+    sound: &Sound,
+    enum_type: &str,
+) -> Result<(), RytmExternalError> {
+    let machine_type = sound.machine_type();
+    let trig_index = trig.index();
+
+    match (sound.machine_parameters(), enum_type) {
+        (MachineParameters::BdAcoustic(mp), BD_ACOUSTIC_WAVEFORM) => {
+            mp.plock_clear_wav(trig_index)?;
+        }
+        (MachineParameters::BdSharp(mp), BD_SHARP_WAVEFORM) => mp.plock_clear_wav(trig_index)?,
+        (MachineParameters::SyChip(mp), SY_CHIP_WAVEFORM) => mp.plock_clear_wav(trig_index)?,
+        (MachineParameters::SyChip(mp), SY_CHIP_SPEED) => mp.plock_clear_spd(trig_index)?,
+        (MachineParameters::SyRaw(mp), SY_RAW_WAVEFORM_1) => mp.plock_clear_wav1(trig_index)?,
+        (MachineParameters::SyRaw(mp), SY_RAW_WAVEFORM_2) => mp.plock_clear_wav2(trig_index)?,
+        (_, other) => return Err(invalid_machine_parameter(machine_type, other)),
+    }
+
+    Ok(())
+}
+
+fn send_plock_get_output(
+    identifier: &str,
+    trig_index: usize,
+    value_atom: Option<Atom>,
+    out: &Outlet,
+) {
+    let identifier_atom = Atom::from(SymbolRef::from(identifier));
+    let index_atom = Atom::from(AtomValue::Int(trig_index as isize));
+    // Send the value as "unset" for a plock which is not set.
+    let value_atom = value_atom.unwrap_or_else(|| Atom::from(SymbolRef::from("unset")));
+
+    out.send(&[identifier_atom, index_atom, value_atom][..]);
+}
@@ -1,9 +1,9 @@
 use crate::api::kit_action_type;
 use crate::api::sound_action_type;
-use crate::error::EnumError::InvalidEnumType;
+use crate::atom::{Atom, SymbolRef};
+use crate::error::EnumError::{InvalidEnumType, NotParameterLockable};
 use crate::error::IdentifierError;
 use crate::{error::RytmExternalError, util::get_bool_from_0_or_1};
-use median::{atom::Atom, symbol::SymbolRef};
 use rytm_rs::object::pattern::Trig;
 use std::convert::TryInto;
 
@@ -14,7 +14,7 @@ pub fn handle_trig_plock_set_action(
     slice_index: usize,
 ) -> Result<(), RytmExternalError> {
     if let Some(parameter_atom) = atoms.get(slice_index) {
-        let action_str = action.to_string()?;
+        let action_str = action.to_string();
 
         return match action_str.as_str() {
             kit_action_type::FX_DELAY_TIME => {
@@ -89,10 +89,28 @@ pub fn handle_trig_plock_set_action(
             kit_action_type::FX_LFO_DEPTH => {
                 Ok(trig.plock_set_fx_lfo_depth(parameter_atom.get_float() as f32)?)
             }
-            //
-            // TODO: Do the dist setters after fixing the dist in the SDK
-            // TODO: Do Machine plocks
-            //
+
+            kit_action_type::FX_DISTORTION_DELAY_OVERDRIVE => {
+                Ok(trig
+                    .plock_set_fx_distortion_delay_overdrive(parameter_atom.get_int() as usize)?)
+            }
+            kit_action_type::FX_DISTORTION_DELAY_POST => Ok(trig
+                .plock_set_fx_distortion_delay_post(get_bool_from_0_or_1(
+                    parameter_atom,
+                    kit_action_type::FX_DISTORTION_DELAY_POST,
+                )?)?),
+            kit_action_type::FX_DISTORTION_REVERB_POST => Ok(trig
+                .plock_set_fx_distortion_reverb_post(get_bool_from_0_or_1(
+                    parameter_atom,
+                    kit_action_type::FX_DISTORTION_REVERB_POST,
+                )?)?),
+            kit_action_type::FX_DISTORTION_AMOUNT => {
+                Ok(trig.plock_set_fx_distortion_amount(parameter_atom.get_int() as usize)?)
+            }
+            kit_action_type::FX_DISTORTION_SYMMETRY => {
+                Ok(trig.plock_set_fx_distortion_symmetry(parameter_atom.get_int())?)
+            }
+
             sound_action_type::AMP_ATTACK => {
                 Ok(trig.plock_set_amplitude_attack(parameter_atom.get_int() as usize)?)
             }
@@ -206,8 +224,7 @@ pub fn handle_trig_plock_set_enum_value(
             Ok(trig.plock_set_fx_lfo_destination(enum_value.try_into()?)?)
         }
 
-        // TODO:
-        // sound_enum_type::MACHINE_PARAMETERS => todo!(),
+        sound_enum_type::MACHINE_TYPE => Err(NotParameterLockable(enum_type.to_owned()).into()),
         sound_enum_type::LFO_DESTINATION => {
             Ok(trig.plock_set_lfo_destination(enum_value.try_into()?)?)
         }
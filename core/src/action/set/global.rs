@@ -1,14 +1,14 @@
 use crate::api::global_action_type::*;
 use crate::api::global_enum_type::*;
+use crate::atom::SymbolRef;
+use crate::atom::{Atom, AtomValue};
 use crate::error::EnumError::InvalidEnumType;
 use crate::error::IdentifierError;
 use crate::error::RytmExternalError;
 use crate::util::get_bool_from_0_or_1;
+use crate::util::midi_channel_from_str;
 use crate::util::only_allow_numbers_as_identifier_parameter;
-use median::atom::{Atom, AtomValue};
-use median::symbol::SymbolRef;
 use rytm_rs::object::Global;
-use std::convert::TryFrom;
 use std::convert::TryInto;
 
 pub fn handle_global_set_enum_value(
@@ -72,37 +72,40 @@ pub fn handle_global_set_enum_value(
         AUTO_CHANNEL => global
             .midi_config_mut()
             .channels_mut()
-            .set_auto_channel(enum_value.try_into()?)?,
+            .set_auto_channel(midi_channel_from_str(enum_value, AUTO_CHANNEL)?)?,
 
         TRACK_CHANNELS => {
-            if let Some(atom) = maybe_parameter_atom {
-                if let Some(AtomValue::Int(track_index)) = atom.get_value() {
-                    global
-                        .midi_config_mut()
-                        .channels_mut()
-                        .set_track_channel(track_index as usize, enum_value.try_into()?)?;
-                } else {
-                    return Err("Invalid setter format: trackchannels should be followed by an integer track index. Format: trackchannels:<channel> <track index>. Example: trackchannels:1 2".into());
-                }
-            }
-            return Err("Invalid setter format: trackchannels should be followed by an integer track index. Format: trackchannels:<channel> <track index>. Example: trackchannels:1 2".into());
+            let Some(AtomValue::Int(track_index)) = maybe_parameter_atom.and_then(Atom::get_value)
+            else {
+                return Err("Invalid setter format: trackchannels should be followed by an integer track index. Format: trackchannels:<channel> <track index>. Example: trackchannels:1 2".into());
+            };
+            global.midi_config_mut().channels_mut().set_track_channel(
+                track_index as usize,
+                midi_channel_from_str(enum_value, TRACK_CHANNELS)?,
+            )?;
         }
         TRACK_FX_CHANNEL => global
             .midi_config_mut()
             .channels_mut()
-            .set_track_fx_channel(enum_value.try_into()?)?,
+            .set_track_fx_channel(midi_channel_from_str(enum_value, TRACK_FX_CHANNEL)?)?,
         PROGRAM_CHANGE_IN_CHANNEL => global
             .midi_config_mut()
             .channels_mut()
-            .set_program_change_in_channel(enum_value.try_into()?)?,
+            .set_program_change_in_channel(midi_channel_from_str(
+                enum_value,
+                PROGRAM_CHANGE_IN_CHANNEL,
+            )?)?,
         PROGRAM_CHANGE_OUT_CHANNEL => global
             .midi_config_mut()
             .channels_mut()
-            .set_program_change_out_channel(enum_value.try_into()?)?,
+            .set_program_change_out_channel(midi_channel_from_str(
+                enum_value,
+                PROGRAM_CHANGE_OUT_CHANNEL,
+            )?)?,
         PERFORMANCE_CHANNEL => global
             .midi_config_mut()
             .channels_mut()
-            .set_performance_channel(enum_value.try_into()?)?,
+            .set_performance_channel(midi_channel_from_str(enum_value, PERFORMANCE_CHANNEL)?)?,
 
         other => return Err(InvalidEnumType(other.to_owned()).into()),
     }
@@ -114,9 +117,10 @@ pub fn handle_global_set_action(
     global: &mut Global,
     action_or_enum_value_str: &str,
     parameter_atom: &Atom,
+    maybe_next_atom: Option<&Atom>,
 ) -> Result<(), RytmExternalError> {
-    let action_or_enum_value = SymbolRef::try_from(action_or_enum_value_str)?;
-    let action_or_enum_value_str = action_or_enum_value.to_string()?;
+    let action_or_enum_value = SymbolRef::from(action_or_enum_value_str);
+    let action_or_enum_value_str = action_or_enum_value.to_string();
 
     only_allow_numbers_as_identifier_parameter(parameter_atom)?;
 
@@ -140,16 +144,35 @@ pub fn handle_global_set_action(
             Ok(())
         }
 
+        // The track index is followed by an optional 0 or 1 which turns the track off or on, on when it is left out.
         ROUTE_TO_MAIN => {
-            global
-                .routing_mut()
-                .route_track_to_main(parameter_atom.get_int() as usize)?;
+            let track_index = parameter_atom.get_int() as usize;
+            if maybe_next_atom
+                .map(|atom| get_bool_from_0_or_1(atom, ROUTE_TO_MAIN))
+                .transpose()?
+                .unwrap_or(true)
+            {
+                global.routing_mut().route_track_to_main(track_index)?;
+            } else {
+                global
+                    .routing_mut()
+                    .clear_routing_of_a_track_to_main(track_index)?;
+            }
             Ok(())
         }
         SEND_TO_FX => {
-            global
-                .routing_mut()
-                .send_track_to_fx(parameter_atom.get_int() as usize)?;
+            let track_index = parameter_atom.get_int() as usize;
+            if maybe_next_atom
+                .map(|atom| get_bool_from_0_or_1(atom, SEND_TO_FX))
+                .transpose()?
+                .unwrap_or(true)
+            {
+                global.routing_mut().send_track_to_fx(track_index)?;
+            } else {
+                global
+                    .routing_mut()
+                    .clear_sending_of_a_track_to_fx(track_index)?;
+            }
             Ok(())
         }
 
@@ -217,6 +240,19 @@ pub fn handle_global_set_action(
             Ok(())
         }
 
+        METRONOME_ACTIVE => {
+            global
+                .metronome_settings_mut()
+                .set_active(get_bool_from_0_or_1(parameter_atom, METRONOME_ACTIVE)?);
+            Ok(())
+        }
+        METRONOME_PRE_ROLL_BARS => Ok(global
+            .metronome_settings_mut()
+            .set_pre_roll_bars(parameter_atom.get_int() as usize)?),
+        METRONOME_VOLUME => Ok(global
+            .metronome_settings_mut()
+            .set_volume(parameter_atom.get_int() as usize)?),
+
         _ => Err(IdentifierError::InvalidType(action_or_enum_value_str).into()),
     }
 }
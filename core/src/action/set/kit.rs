@@ -2,14 +2,13 @@ use crate::api::kit_action_type::*;
 use crate::api::kit_element_type::*;
 use crate::api::kit_enum_type::*;
 use crate::api::sound_kit::handle_sound_kit_set;
+use crate::atom::SymbolRef;
+use crate::atom::{Atom, AtomValue};
 use crate::error::EnumError::InvalidEnumType;
 use crate::error::IdentifierError;
 use crate::error::RytmExternalError;
 use crate::util::get_bool_from_0_or_1;
 use crate::util::only_allow_numbers_as_identifier_parameter;
-use median::atom::AtomType;
-use median::atom::{Atom, AtomValue};
-use median::symbol::SymbolRef;
 use rytm_rs::object::Kit;
 use rytm_rs::object::Sound;
 use std::convert::TryInto;
@@ -20,10 +19,10 @@ pub fn handle_kit_set_action(
     parameter: &Atom,
     maybe_next_atom: Option<&Atom>,
 ) -> Result<(), RytmExternalError> {
-    let action_str = action.to_string()?;
+    let action_str = action.to_string();
     if action_str.as_str() == NAME {
-        match parameter.get_value().unwrap() {
-            AtomValue::Symbol(symbol) => return Ok(kit.set_name(symbol.to_string()?.as_str())?),
+        match parameter.get_value() {
+            Some(AtomValue::Symbol(symbol)) => return Ok(kit.set_name(symbol.to_string().as_str())?),
             _ => return Err("Invalid parameter: name must be a symbol with maximum 15 characters long and use only ascii characters.".into()),
         }
     }
@@ -202,7 +201,8 @@ pub fn handle_kit_set_enum_value(
             return Err("Invalid setter format: ctrlinmod2target should be followed by an integer ctrlinmod2 index. Format: ctrlinmod2target:<target> <ctrlinmod2 index>. Example: ctrlinmod2target:lfophase 2".into());
         }
         FX_LFO_DESTINATION => kit.fx_lfo_mut().set_destination(enum_value.try_into()?),
-        FX_DELAY_TIME_ON_THE_GRID => kit.fx_delay_mut().set_time_on_grid(enum_value.try_into()?),
+        // TODO:
+        // FX_DELAY_TIME_ON_THE_GRID => kit.fx_delay_mut().set_time_on_grid(enum_value.try_into()?),
         FX_COMP_ATTACK => kit.fx_compressor_mut().set_attack(enum_value.try_into()?),
         FX_COMP_RELEASE => kit.fx_compressor_mut().set_release(enum_value.try_into()?),
         FX_COMP_RATIO => kit.fx_compressor_mut().set_ratio(enum_value.try_into()?),
@@ -211,7 +211,7 @@ pub fn handle_kit_set_enum_value(
             .set_side_chain_eq(enum_value.try_into()?),
 
         other => return Err(InvalidEnumType(other.to_owned()).into()),
-    };
+    }
 
     Ok(())
 }
@@ -222,21 +222,18 @@ pub fn handle_kit_set_kit_element(
     element_index: usize,
     element_parameter: &Atom,
 ) -> Result<(), RytmExternalError> {
-    match element_parameter.get_type() {
-        Some(AtomType::Object) | None => return Err(RytmExternalError::from(
+    if element_parameter.get_type().is_none() {
+        return Err(RytmExternalError::from(
             "Invalid parameter: Kit element parameters can be only integers, floats or symbols.",
-        )),
-        _ => {
-            // Pass..
-        }
-    };
+        ));
+    }
 
     match element_type {
         TRACK_LEVEL => {
             Ok(kit.set_track_level(element_index, element_parameter.get_int() as usize)?)
         }
         TRACK_RETRIG_RATE => {
-            let param_str = element_parameter.get_symbol().to_string()?;
+            let param_str = element_parameter.get_symbol().to_string();
             let (_, enum_value) = param_str.as_str().split_once(':').ok_or_else(|| {
                 RytmExternalError::from(
                     "Invalid parameter: kit element should be followed by an enum value.",
@@ -247,7 +244,7 @@ pub fn handle_kit_set_kit_element(
             Ok(())
         }
         TRACK_RETRIG_LENGTH => {
-            let param_str = element_parameter.get_symbol().to_string()?;
+            let param_str = element_parameter.get_symbol().to_string();
             let (_, enum_value) = param_str.as_str().split_once(':').ok_or_else(|| {
                 RytmExternalError::from(
                     "Invalid parameter: kit element should be followed by an enum value.",
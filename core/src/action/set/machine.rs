@@ -0,0 +1,366 @@
+use crate::action::machine::invalid_machine_parameter;
+use crate::api::machine_parameter_type::*;
+use crate::api::sound_machine_enum_type::*;
+use crate::atom::Atom;
+use crate::error::RytmExternalError;
+use crate::util::get_bool_from_0_or_1;
+use rytm_rs::object::sound::machine::MachineParameters;
+use rytm_rs::object::Sound;
+use std::convert::TryInto;
+
+pub fn handle_machine_set_enum_value(
+    sound: &mut Sound,
+    enum_type: &str,
+    enum_value: &str,
+) -> Result<(), RytmExternalError> {
+    let machine_type = sound.machine_type();
+    match (sound.machine_parameters_mut(), enum_type) {
+        (MachineParameters::BdAcoustic(mp), BD_ACOUSTIC_WAVEFORM) => {
+            mp.set_wav(enum_value.try_into()?);
+        }
+        (MachineParameters::BdSharp(mp), BD_SHARP_WAVEFORM) => mp.set_wav(enum_value.try_into()?),
+        (MachineParameters::SyChip(mp), SY_CHIP_WAVEFORM) => mp.set_wav(enum_value.try_into()?),
+        (MachineParameters::SyChip(mp), SY_CHIP_SPEED) => mp.set_spd(enum_value.try_into()?)?,
+        (MachineParameters::SyRaw(mp), SY_RAW_WAVEFORM_1) => mp.set_wav1(enum_value.try_into()?),
+        (MachineParameters::SyRaw(mp), SY_RAW_WAVEFORM_2) => mp.set_wav2(enum_value.try_into()?),
+        (_, other) => return Err(invalid_machine_parameter(machine_type, other)),
+    }
+
+    Ok(())
+}
+
+pub fn handle_machine_set_action(
+    sound: &mut Sound,
+    action: &str,
+    parameter_atom: &Atom,
+) -> Result<(), RytmExternalError> {
+    let machine_type = sound.machine_type();
+    match sound.machine_parameters_mut() {
+        MachineParameters::BdHard(mp) => match action {
+            LEV => mp.set_lev(parameter_atom.get_int() as usize)?,
+            TUN => mp.set_tun(parameter_atom.get_float() as f32)?,
+            DEC => mp.set_dec(parameter_atom.get_int() as usize)?,
+            HLD => mp.set_hld(parameter_atom.get_int() as usize)?,
+            SWT => mp.set_swt(parameter_atom.get_int() as usize)?,
+            SNP => mp.set_snp(parameter_atom.get_int() as usize)?,
+            WAV => mp.set_wav(parameter_atom.get_int() as usize)?,
+            TIC => mp.set_tic(parameter_atom.get_int() as usize)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::BdClassic(mp) => match action {
+            LEV => mp.set_lev(parameter_atom.get_int() as usize)?,
+            TUN => mp.set_tun(parameter_atom.get_float() as f32)?,
+            DEC => mp.set_dec(parameter_atom.get_int() as usize)?,
+            HLD => mp.set_hld(parameter_atom.get_int() as usize)?,
+            SWT => mp.set_swt(parameter_atom.get_int() as usize)?,
+            SWD => mp.set_swd(parameter_atom.get_int() as usize)?,
+            WAV => mp.set_wav(parameter_atom.get_int() as usize)?,
+            TRA => mp.set_tra(parameter_atom.get_int() as usize)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::SdHard(mp) => match action {
+            LEV => mp.set_lev(parameter_atom.get_int() as usize)?,
+            TUN => mp.set_tun(parameter_atom.get_float() as f32)?,
+            DEC => mp.set_dec(parameter_atom.get_int() as usize)?,
+            SWD => mp.set_swd(parameter_atom.get_int() as usize)?,
+            TIC => mp.set_tic(parameter_atom.get_int() as usize)?,
+            NOD => mp.set_nod(parameter_atom.get_int() as usize)?,
+            NOL => mp.set_nol(parameter_atom.get_int() as usize)?,
+            SWT => mp.set_swt(parameter_atom.get_int() as usize)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::SdClassic(mp) => match action {
+            LEV => mp.set_lev(parameter_atom.get_int() as usize)?,
+            TUN => mp.set_tun(parameter_atom.get_float() as f32)?,
+            DEC => mp.set_dec(parameter_atom.get_int() as usize)?,
+            DET => mp.set_det(parameter_atom.get_float() as f32)?,
+            SNP => mp.set_snp(parameter_atom.get_int() as usize)?,
+            NOD => mp.set_nod(parameter_atom.get_int() as usize)?,
+            NOL => mp.set_nol(parameter_atom.get_int() as usize)?,
+            BAL => mp.set_bal(parameter_atom.get_int())?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::RsHard(mp) => match action {
+            LEV => mp.set_lev(parameter_atom.get_int() as usize)?,
+            TUN => mp.set_tun(parameter_atom.get_float() as f32)?,
+            DEC => mp.set_dec(parameter_atom.get_int() as usize)?,
+            SWD => mp.set_swd(parameter_atom.get_int() as usize)?,
+            TIC => mp.set_tic(parameter_atom.get_int() as usize)?,
+            NOL => mp.set_nol(parameter_atom.get_int() as usize)?,
+            SYN => mp.set_syn(parameter_atom.get_int() as usize)?,
+            SWT => mp.set_swt(parameter_atom.get_int() as usize)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::RsClassic(mp) => match action {
+            LEV => mp.set_lev(parameter_atom.get_int() as usize)?,
+            T1 => mp.set_t1(parameter_atom.get_float() as f32)?,
+            DEC => mp.set_dec(parameter_atom.get_int() as usize)?,
+            BAL => mp.set_bal(parameter_atom.get_int())?,
+            T2 => mp.set_t2(parameter_atom.get_float() as f32)?,
+            SYM => mp.set_sym(parameter_atom.get_int())?,
+            NOL => mp.set_nol(parameter_atom.get_int() as usize)?,
+            TIC => mp.set_tic(parameter_atom.get_int() as usize)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::CpClassic(mp) => match action {
+            LEV => mp.set_lev(parameter_atom.get_int() as usize)?,
+            TON => mp.set_ton(parameter_atom.get_int() as usize)?,
+            NOD => mp.set_nod(parameter_atom.get_int() as usize)?,
+            NUM => mp.set_num(parameter_atom.get_int() as usize)?,
+            RAT => mp.set_rat(parameter_atom.get_int() as usize)?,
+            NOL => mp.set_nol(parameter_atom.get_int() as usize)?,
+            RND => mp.set_rnd(parameter_atom.get_int() as usize)?,
+            CPT => mp.set_cpt(parameter_atom.get_int() as usize)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::BtClassic(mp) => match action {
+            LEV => mp.set_lev(parameter_atom.get_int() as usize)?,
+            TUN => mp.set_tun(parameter_atom.get_int())?,
+            DEC => mp.set_dec(parameter_atom.get_int() as usize)?,
+            NOL => mp.set_nol(parameter_atom.get_int() as usize)?,
+            SNP => mp.set_snp(parameter_atom.get_int() as usize)?,
+            SWD => mp.set_swd(parameter_atom.get_int() as usize)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::XtClassic(mp) => match action {
+            LEV => mp.set_lev(parameter_atom.get_int() as usize)?,
+            TUN => mp.set_tun(parameter_atom.get_int())?,
+            DEC => mp.set_dec(parameter_atom.get_int() as usize)?,
+            SWD => mp.set_swd(parameter_atom.get_int() as usize)?,
+            SWT => mp.set_swt(parameter_atom.get_int() as usize)?,
+            NOD => mp.set_nod(parameter_atom.get_int() as usize)?,
+            NOL => mp.set_nol(parameter_atom.get_int() as usize)?,
+            TON => mp.set_ton(parameter_atom.get_int())?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::ChClassic(mp) => match action {
+            LEV => mp.set_lev(parameter_atom.get_int() as usize)?,
+            TUN => mp.set_tun(parameter_atom.get_int())?,
+            DEC => mp.set_dec(parameter_atom.get_int() as usize)?,
+            COL => mp.set_col(parameter_atom.get_int())?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::OhClassic(mp) => match action {
+            LEV => mp.set_lev(parameter_atom.get_int() as usize)?,
+            TUN => mp.set_tun(parameter_atom.get_int())?,
+            DEC => mp.set_dec(parameter_atom.get_int() as usize)?,
+            COL => mp.set_col(parameter_atom.get_int())?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::CyClassic(mp) => match action {
+            LEV => mp.set_lev(parameter_atom.get_int() as usize)?,
+            TUN => mp.set_tun(parameter_atom.get_int())?,
+            DEC => mp.set_dec(parameter_atom.get_int() as usize)?,
+            COL => mp.set_col(parameter_atom.get_int())?,
+            TON => mp.set_ton(parameter_atom.get_int())?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::CbClassic(mp) => match action {
+            LEV => mp.set_lev(parameter_atom.get_int() as usize)?,
+            TUN => mp.set_tun(parameter_atom.get_int())?,
+            DEC => mp.set_dec(parameter_atom.get_int() as usize)?,
+            DET => mp.set_det(parameter_atom.get_int() as usize)?,
+            PW1 => mp.set_pw1(parameter_atom.get_int())?,
+            PW2 => mp.set_pw2(parameter_atom.get_int())?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::BdFm(mp) => match action {
+            LEV => mp.set_lev(parameter_atom.get_int() as usize)?,
+            TUN => mp.set_tun(parameter_atom.get_float() as f32)?,
+            DEC => mp.set_dec(parameter_atom.get_int() as usize)?,
+            FMA => mp.set_fma(parameter_atom.get_int() as usize)?,
+            SWT => mp.set_swt(parameter_atom.get_int() as usize)?,
+            FMS => mp.set_fms(parameter_atom.get_int() as usize)?,
+            FMD => mp.set_fmd(parameter_atom.get_int() as usize)?,
+            FMT => mp.set_fmt(parameter_atom.get_float() as f32)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::SdFm(mp) => match action {
+            LEV => mp.set_lev(parameter_atom.get_int() as usize)?,
+            TUN => mp.set_tun(parameter_atom.get_float() as f32)?,
+            DEC => mp.set_dec(parameter_atom.get_int() as usize)?,
+            FMT => mp.set_fmt(parameter_atom.get_float() as f32)?,
+            FMD => mp.set_fmd(parameter_atom.get_int() as usize)?,
+            NOD => mp.set_nod(parameter_atom.get_int() as usize)?,
+            NOL => mp.set_nol(parameter_atom.get_int() as usize)?,
+            FMA => mp.set_fma(parameter_atom.get_int() as usize)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::UtNoise(mp) => match action {
+            LEV => mp.set_lev(parameter_atom.get_int() as usize)?,
+            LPF => mp.set_lpf(parameter_atom.get_int() as usize)?,
+            DEC => mp.set_dec(parameter_atom.get_int() as usize)?,
+            HPF => mp.set_hpf(parameter_atom.get_int() as usize)?,
+            LPQ => mp.set_lpq(parameter_atom.get_int() as usize)?,
+            ATK => mp.set_atk(parameter_atom.get_int() as usize)?,
+            SWT => mp.set_swt(parameter_atom.get_int() as usize)?,
+            SWD => mp.set_swd(parameter_atom.get_int())?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::UtImpulse(mp) => match action {
+            LEV => mp.set_lev(parameter_atom.get_int() as usize)?,
+            ATK => mp.set_atk(parameter_atom.get_int() as usize)?,
+            DEC => mp.set_dec(parameter_atom.get_int() as usize)?,
+            POL => mp.set_pol(parameter_atom.get_int() as usize)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::ChMetallic(mp) => match action {
+            LEV => mp.set_lev(parameter_atom.get_int() as usize)?,
+            TUN => mp.set_tun(parameter_atom.get_int())?,
+            DEC => mp.set_dec(parameter_atom.get_int() as usize)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::OhMetallic(mp) => match action {
+            LEV => mp.set_lev(parameter_atom.get_int() as usize)?,
+            TUN => mp.set_tun(parameter_atom.get_int())?,
+            DEC => mp.set_dec(parameter_atom.get_int() as usize)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::CyMetallic(mp) => match action {
+            LEV => mp.set_lev(parameter_atom.get_int() as usize)?,
+            TUN => mp.set_tun(parameter_atom.get_int())?,
+            DEC => mp.set_dec(parameter_atom.get_int() as usize)?,
+            TON => mp.set_ton(parameter_atom.get_int())?,
+            TRD => mp.set_trd(parameter_atom.get_int() as usize)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::CbMetallic(mp) => match action {
+            LEV => mp.set_lev(parameter_atom.get_int() as usize)?,
+            TUN => mp.set_tun(parameter_atom.get_int())?,
+            DEC => mp.set_dec(parameter_atom.get_int() as usize)?,
+            DET => mp.set_det(parameter_atom.get_int() as usize)?,
+            PW1 => mp.set_pw1(parameter_atom.get_int())?,
+            PW2 => mp.set_pw2(parameter_atom.get_int())?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::BdPlastic(mp) => match action {
+            LEV => mp.set_lev(parameter_atom.get_int() as usize)?,
+            TUN => mp.set_tun(parameter_atom.get_float() as f32)?,
+            DEC => mp.set_dec(parameter_atom.get_int() as usize)?,
+            TYP => mp.set_typ(parameter_atom.get_int() as usize)?,
+            MOD => mp.set_mod_level(parameter_atom.get_int() as usize)?,
+            SWT => mp.set_swt(parameter_atom.get_int() as usize)?,
+            SWD => mp.set_swd(parameter_atom.get_int() as usize)?,
+            TIC => mp.set_tic(parameter_atom.get_int() as usize)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::BdSilky(mp) => match action {
+            LEV => mp.set_lev(parameter_atom.get_int() as usize)?,
+            TUN => mp.set_tun(parameter_atom.get_float() as f32)?,
+            DEC => mp.set_dec(parameter_atom.get_int() as usize)?,
+            HLD => mp.set_hld(parameter_atom.get_int() as usize)?,
+            SWT => mp.set_swt(parameter_atom.get_int() as usize)?,
+            SWD => mp.set_swd(parameter_atom.get_int() as usize)?,
+            DUS => mp.set_dus(parameter_atom.get_int() as usize)?,
+            CLK => mp.set_clk(parameter_atom.get_int() as usize)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::SdNatural(mp) => match action {
+            LEV => mp.set_lev(parameter_atom.get_int() as usize)?,
+            TUN => mp.set_tun(parameter_atom.get_float() as f32)?,
+            BDY => mp.set_bdy(parameter_atom.get_int() as usize)?,
+            DEC => mp.set_dec(parameter_atom.get_int() as usize)?,
+            BAL => mp.set_bal(parameter_atom.get_int() as usize)?,
+            LPF => mp.set_lpf(parameter_atom.get_int() as usize)?,
+            HPF => mp.set_hpf(parameter_atom.get_int() as usize)?,
+            RES => mp.set_res(parameter_atom.get_int() as usize)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::HhBasic(mp) => match action {
+            LEV => mp.set_lev(parameter_atom.get_int() as usize)?,
+            TUN => mp.set_tun(parameter_atom.get_int())?,
+            DEC => mp.set_dec(parameter_atom.get_int() as usize)?,
+            TON => mp.set_ton(parameter_atom.get_int())?,
+            TRD => mp.set_trd(parameter_atom.get_int() as usize)?,
+            RST => mp.set_rst(get_bool_from_0_or_1(parameter_atom, RST)?),
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::CyRide(mp) => match action {
+            LEV => mp.set_lev(parameter_atom.get_int() as usize)?,
+            TUN => mp.set_tun(parameter_atom.get_int())?,
+            DEC => mp.set_dec(parameter_atom.get_int() as usize)?,
+            TYP => mp.set_typ(parameter_atom.get_int() as usize)?,
+            HIT => mp.set_hit(parameter_atom.get_int() as usize)?,
+            C1 => mp.set_c1(parameter_atom.get_int() as usize)?,
+            C2 => mp.set_c2(parameter_atom.get_int() as usize)?,
+            C3 => mp.set_c3(parameter_atom.get_int() as usize)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::BdSharp(mp) => match action {
+            LEV => mp.set_lev(parameter_atom.get_int() as usize)?,
+            TUN => mp.set_tun(parameter_atom.get_float() as f32)?,
+            DEC => mp.set_dec(parameter_atom.get_int() as usize)?,
+            HLD => mp.set_hld(parameter_atom.get_int() as usize)?,
+            SWT => mp.set_swt(parameter_atom.get_int() as usize)?,
+            SWD => mp.set_swd(parameter_atom.get_int() as usize)?,
+            TIC => mp.set_tic(parameter_atom.get_int() as usize)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::SyDualVco(mp) => match action {
+            LEV => mp.set_lev(parameter_atom.get_int() as usize)?,
+            TUN => mp.set_tun(parameter_atom.get_float() as f32)?,
+            DEC1 => mp.set_dec1(parameter_atom.get_int() as usize)?,
+            DET => mp.set_det(parameter_atom.get_float() as f32)?,
+            DEC2 => mp.set_dec2(parameter_atom.get_int() as usize)?,
+            BAL => mp.set_bal(parameter_atom.get_int())?,
+            BND => mp.set_bnd(parameter_atom.get_int())?,
+            CFG => mp.set_cfg(parameter_atom.get_int() as usize)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::SyChip(mp) => match action {
+            LEV => mp.set_lev(parameter_atom.get_int() as usize)?,
+            TUN => mp.set_tun(parameter_atom.get_float() as f32)?,
+            DEC => mp.set_dec(parameter_atom.get_int() as usize)?,
+            OF2 => mp.set_of2(parameter_atom.get_int())?,
+            OF3 => mp.set_of3(parameter_atom.get_int())?,
+            OF4 => mp.set_of4(parameter_atom.get_int())?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::BdAcoustic(mp) => match action {
+            LEV => mp.set_lev(parameter_atom.get_int() as usize)?,
+            TUN => mp.set_tun(parameter_atom.get_float() as f32)?,
+            DEC => mp.set_dec(parameter_atom.get_int() as usize)?,
+            HLD => mp.set_hld(parameter_atom.get_int() as usize)?,
+            SWT => mp.set_swt(parameter_atom.get_int() as usize)?,
+            SWD => mp.set_swd(parameter_atom.get_int() as usize)?,
+            IMP => mp.set_imp(parameter_atom.get_int() as usize)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::SdAcoustic(mp) => match action {
+            LEV => mp.set_lev(parameter_atom.get_int() as usize)?,
+            TUN => mp.set_tun(parameter_atom.get_float() as f32)?,
+            BDY => mp.set_bdy(parameter_atom.get_int() as usize)?,
+            NOD => mp.set_nod(parameter_atom.get_int() as usize)?,
+            NOL => mp.set_nol(parameter_atom.get_int() as usize)?,
+            HLD => mp.set_hld(parameter_atom.get_int() as usize)?,
+            SWD => mp.set_swd(parameter_atom.get_int() as usize)?,
+            IMP => mp.set_imp(parameter_atom.get_int() as usize)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::SyRaw(mp) => match action {
+            LEV => mp.set_lev(parameter_atom.get_int() as usize)?,
+            TUN => mp.set_tun(parameter_atom.get_float() as f32)?,
+            DEC2 => mp.set_dec2(parameter_atom.get_int() as usize)?,
+            DET => mp.set_det(parameter_atom.get_float() as f32)?,
+            NLEV => mp.set_nlev(parameter_atom.get_int() as usize)?,
+            BAL => mp.set_bal(parameter_atom.get_int())?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::HhLab(mp) => match action {
+            LEV => mp.set_lev(parameter_atom.get_int() as usize)?,
+            OSC1 => mp.set_osc1(parameter_atom.get_int() as usize)?,
+            DEC => mp.set_dec(parameter_atom.get_int() as usize)?,
+            OSC2 => mp.set_osc2(parameter_atom.get_int() as usize)?,
+            OSC3 => mp.set_osc3(parameter_atom.get_int() as usize)?,
+            OSC4 => mp.set_osc4(parameter_atom.get_int() as usize)?,
+            OSC5 => mp.set_osc5(parameter_atom.get_int() as usize)?,
+            OSC6 => mp.set_osc6(parameter_atom.get_int() as usize)?,
+            other => return Err(invalid_machine_parameter(machine_type, other)),
+        },
+        MachineParameters::Disable | MachineParameters::Unset => {
+            return Err(invalid_machine_parameter(machine_type, action))
+        }
+    }
+
+    Ok(())
+}
@@ -1,11 +1,11 @@
+use crate::atom::Atom;
+use crate::atom::SymbolRef;
 use crate::error::EnumError::InvalidEnumType;
 use crate::error::IdentifierError;
 use crate::util::{
     only_allow_numbers_as_identifier_parameter, try_get_identifier_value_from_atom_slice,
 };
 use crate::{api::pattern_action_type::*, api::pattern_enum_type::*, error::RytmExternalError};
-use median::atom::Atom;
-use median::symbol::SymbolRef;
 use rytm_rs::object::Pattern;
 use std::convert::TryInto;
 
@@ -23,7 +23,7 @@ pub fn pattern_set(
     atoms: &[Atom],
     select: usize,
 ) -> Result<(), RytmExternalError> {
-    if let Some((enum_type, enum_variant)) = action_or_enum_value.to_string()?.split_once(':') {
+    if let Some((enum_type, enum_variant)) = action_or_enum_value.to_string().split_once(':') {
         handle_pattern_enum_set_action(pattern, enum_type, enum_variant)
     } else {
         handle_set_action(SetAction::Pattern(PatternSetAction {
@@ -43,7 +43,7 @@ pub fn handle_pattern_set_action(action: PatternSetAction) -> Result<(), RytmExt
 
     only_allow_numbers_as_identifier_parameter(parameter)?;
 
-    match action.to_string()?.as_str() {
+    match action.to_string().as_str() {
         MASTER_LENGTH => Ok(pattern.set_master_length(parameter.get_int() as usize)?),
         MASTER_CHANGE => Ok(pattern.set_master_change(parameter.get_int() as usize)?),
         KIT_NUMBER => Ok(pattern.set_kit_number(parameter.get_int() as usize)?),
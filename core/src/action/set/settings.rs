@@ -1,14 +1,13 @@
 use crate::api::settings_action_type::*;
 use crate::api::settings_enum_type::*;
+use crate::atom::Atom;
+use crate::atom::SymbolRef;
 use crate::error::EnumError::InvalidEnumType;
 use crate::error::IdentifierError;
 use crate::error::RytmExternalError;
 use crate::util::get_bool_from_0_or_1;
 use crate::util::only_allow_numbers_as_identifier_parameter;
-use median::atom::Atom;
-use median::symbol::SymbolRef;
 use rytm_rs::object::Settings;
-use std::convert::TryFrom;
 use std::convert::TryInto;
 
 pub fn handle_settings_set_enum_value(
@@ -50,8 +49,8 @@ pub fn handle_settings_set_action(
     action_or_enum_value_str: &str,
     parameter_atom: &Atom,
 ) -> Result<(), RytmExternalError> {
-    let action_or_enum_value = SymbolRef::try_from(action_or_enum_value_str)?;
-    let action_or_enum_value_str = action_or_enum_value.to_string()?;
+    let action_or_enum_value = SymbolRef::from(action_or_enum_value_str);
+    let action_or_enum_value_str = action_or_enum_value.to_string();
 
     only_allow_numbers_as_identifier_parameter(parameter_atom)?;
 
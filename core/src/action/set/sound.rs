@@ -1,15 +1,17 @@
+use crate::action::set::machine::{handle_machine_set_action, handle_machine_set_enum_value};
+use crate::api::machine_parameter_type::MACHINE_PARAMETER_TYPES;
 use crate::api::sound_action_type::*;
 use crate::api::sound_enum_type::*;
+use crate::api::sound_machine_enum_type::SOUND_MACHINE_ENUM_TYPES;
+use crate::atom::Atom;
+use crate::atom::AtomValue;
+use crate::atom::SymbolRef;
 use crate::error::EnumError::InvalidEnumType;
 use crate::error::IdentifierError;
 use crate::error::RytmExternalError;
 use crate::util::get_bool_from_0_or_1;
 use crate::util::only_allow_numbers_as_identifier_parameter;
-use median::atom::Atom;
-use median::atom::AtomValue;
-use median::symbol::SymbolRef;
 use rytm_rs::object::Sound;
-use std::convert::TryFrom;
 use std::convert::TryInto;
 
 pub fn handle_sound_set_enum_value(
@@ -19,11 +21,6 @@ pub fn handle_sound_set_enum_value(
     maybe_parameter_atom: Option<&Atom>,
 ) -> Result<(), RytmExternalError> {
     match enum_type {
-        // TODO:
-        // MACHINE_PARAMETERS => {
-        //     sound.set_selected_machine_parameter(enum_value.try_into()?);
-        //     Ok(())
-        // }
         MACHINE_TYPE => {
             sound.set_machine_type(enum_value.try_into()?)?;
             Ok(())
@@ -113,6 +110,9 @@ pub fn handle_sound_set_enum_value(
                 .set_chromatic_mode(enum_value.try_into()?);
             Ok(())
         }
+        machine_enum_type if SOUND_MACHINE_ENUM_TYPES.contains(&machine_enum_type) => {
+            handle_machine_set_enum_value(sound, machine_enum_type, enum_value)
+        }
         other => Err(InvalidEnumType(other.to_owned()).into()),
     }
 }
@@ -123,12 +123,12 @@ pub fn handle_sound_set_action(
     parameter_atom: &Atom,
     maybe_next_atom: Option<&Atom>,
 ) -> Result<(), RytmExternalError> {
-    let action_or_enum_value = SymbolRef::try_from(action_or_enum_value_str)?;
-    let action_or_enum_value_str = action_or_enum_value.to_string()?;
+    let action_or_enum_value = SymbolRef::from(action_or_enum_value_str);
+    let action_or_enum_value_str = action_or_enum_value.to_string();
 
     if action_or_enum_value_str.as_str() == NAME {
-        match parameter_atom.get_value().unwrap() {
-            AtomValue::Symbol(symbol) => return Ok(sound.set_name(symbol.to_string()?.as_str())?),
+        match parameter_atom.get_value() {
+            Some(AtomValue::Symbol(symbol)) => return Ok(sound.set_name(symbol.to_string().as_str())?),
             _ => return Err("Invalid parameter: name must be a symbol with maximum 15 characters long and use only ascii characters.".into()),
         }
     }
@@ -137,8 +137,6 @@ pub fn handle_sound_set_action(
 
     match action_or_enum_value_str.as_str() {
         ACCENT_LEVEL => Ok(sound.set_accent_level(parameter_atom.get_int() as usize)?),
-        // TODO:
-        // MACHINE => Ok(sound.set_machine(parameter_atom.get_int() as usize)?),
         AMP_ATTACK => Ok(sound
             .amplitude_mut()
             .set_attack(parameter_atom.get_int() as usize)?),
@@ -268,6 +266,29 @@ pub fn handle_sound_set_action(
             Err("Invalid setter format: atmodamt should be followed by an integer atmod index. Format: atmodamt <atmod index> <amount>. Example: atmodamt 2 100".into())
         }
 
+        ENV_RESET_FILTER => {
+            sound
+                .settings_mut()
+                .set_env_reset_filter(get_bool_from_0_or_1(parameter_atom, ENV_RESET_FILTER)?);
+            Ok(())
+        }
+        VELOCITY_TO_VOLUME => {
+            sound
+                .settings_mut()
+                .set_velocity_to_volume(get_bool_from_0_or_1(parameter_atom, VELOCITY_TO_VOLUME)?);
+            Ok(())
+        }
+        LEGACY_FX_SEND => {
+            sound
+                .settings_mut()
+                .set_legacy_fx_send(get_bool_from_0_or_1(parameter_atom, LEGACY_FX_SEND)?);
+            Ok(())
+        }
+
+        machine_parameter if MACHINE_PARAMETER_TYPES.contains(&machine_parameter) => {
+            handle_machine_set_action(sound, machine_parameter, parameter_atom)
+        }
+
         other => Err(IdentifierError::InvalidType(other.to_owned()).into()),
     }
 }
@@ -1,14 +1,15 @@
 use std::convert::TryInto;
 
+use crate::atom::Atom;
+use crate::atom::SymbolRef;
 use crate::error::EnumError::InvalidEnumType;
 use crate::error::IdentifierError;
+use crate::euclid::handle_track_euclid;
 use crate::util::{
     get_bool_from_0_or_1, only_allow_numbers_as_identifier_parameter,
     try_get_identifier_value_from_atom_slice,
 };
 use crate::{api::track_action_type::*, api::track_enum_type::*, error::RytmExternalError};
-use median::atom::Atom;
-use median::symbol::SymbolRef;
 use rytm_rs::object::pattern::track::Track;
 
 use super::{handle_set_action, SetAction};
@@ -25,7 +26,11 @@ pub fn track_set(
     atoms: &[Atom],
     select: usize,
 ) -> Result<(), RytmExternalError> {
-    if let Some((enum_type, enum_variant)) = action_or_enum_value.to_string()?.split_once(':') {
+    if action_or_enum_value.to_string() == EUCLID {
+        return handle_track_euclid(track, atoms, select);
+    }
+
+    if let Some((enum_type, enum_variant)) = action_or_enum_value.to_string().split_once(':') {
         handle_track_enum_set_action(track, enum_type, enum_variant)
     } else {
         handle_set_action(SetAction::Track(TrackSetAction {
@@ -45,7 +50,7 @@ pub fn handle_track_set_action(action: TrackSetAction) -> Result<(), RytmExterna
 
     only_allow_numbers_as_identifier_parameter(parameter)?;
 
-    match action.to_string()?.as_str() {
+    match action.to_string().as_str() {
         DEF_TRIG_NOTE => Ok(track.set_default_trig_note(parameter.get_int() as usize)?),
         DEF_TRIG_VELOCITY => Ok(track.set_default_trig_velocity(parameter.get_int() as usize)?),
         DEF_TRIG_PROB => Ok(track.set_default_trig_probability(parameter.get_int() as usize)?),
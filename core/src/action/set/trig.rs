@@ -1,12 +1,12 @@
 use crate::api::trig_action_type::*;
 use crate::api::trig_enum_type::*;
+use crate::atom::Atom;
+use crate::atom::SymbolRef;
 use crate::error::EnumError::InvalidEnumType;
 use crate::error::IdentifierError;
 use crate::util::only_allow_numbers_as_identifier_parameter;
 use crate::util::try_get_identifier_value_from_atom_slice;
 use crate::{error::RytmExternalError, util::get_bool_from_0_or_1};
-use median::atom::Atom;
-use median::symbol::SymbolRef;
 use rytm_rs::object::pattern::{track::trig::HoldsTrigFlags, Trig};
 use std::convert::TryInto;
 
@@ -25,7 +25,7 @@ pub fn trig_set(
     atoms: &[Atom],
     select: usize,
 ) -> Result<(), RytmExternalError> {
-    if let Some((enum_type, enum_variant)) = action_or_enum_value.to_string()?.split_once(':') {
+    if let Some((enum_type, enum_variant)) = action_or_enum_value.to_string().split_once(':') {
         handle_trig_enum_set_action(trig, enum_type, enum_variant)
     } else {
         handle_set_action(SetAction::Trig(TrigSetAction {
@@ -45,14 +45,40 @@ pub fn handle_trig_set_action(action: TrigSetAction) -> Result<(), RytmExternalE
 
     only_allow_numbers_as_identifier_parameter(parameter)?;
 
-    match action.to_string()?.as_str() {
+    match action.to_string().as_str() {
         ENABLE => trig.set_trig_enable(get_bool_from_0_or_1(parameter, ENABLE)?),
         RETRIG => trig.set_retrig(get_bool_from_0_or_1(parameter, RETRIG)?),
         MUTE => trig.set_mute(get_bool_from_0_or_1(parameter, MUTE)?),
         ACCENT => trig.set_accent(get_bool_from_0_or_1(parameter, ACCENT)?),
         SWING => trig.set_swing(get_bool_from_0_or_1(parameter, SWING)?),
         SLIDE => trig.set_slide(get_bool_from_0_or_1(parameter, SLIDE)?),
-        // TODO: Do the rest of the flags
+        PARAMETER_LOCK_LFO_SWITCH => trig.set_parameter_lock_lfo_switch(get_bool_from_0_or_1(
+            parameter,
+            PARAMETER_LOCK_LFO_SWITCH,
+        )?),
+        PARAMETER_LOCK_LFO => {
+            trig.set_parameter_lock_lfo(get_bool_from_0_or_1(parameter, PARAMETER_LOCK_LFO)?);
+        }
+        PARAMETER_LOCK_SYNTH_SWITCH => trig.set_parameter_lock_synth_switch(get_bool_from_0_or_1(
+            parameter,
+            PARAMETER_LOCK_SYNTH_SWITCH,
+        )?),
+        PARAMETER_LOCK_SYNTH => {
+            trig.set_parameter_lock_synth(get_bool_from_0_or_1(parameter, PARAMETER_LOCK_SYNTH)?);
+        }
+        PARAMETER_LOCK_SAMPLE_SWITCH => trig.set_parameter_lock_sample_switch(
+            get_bool_from_0_or_1(parameter, PARAMETER_LOCK_SAMPLE_SWITCH)?,
+        ),
+        PARAMETER_LOCK_SAMPLE => {
+            trig.set_parameter_lock_sample(get_bool_from_0_or_1(parameter, PARAMETER_LOCK_SAMPLE)?);
+        }
+        PARAMETER_LOCK_ENV_SWITCH => trig.set_parameter_lock_env_switch(get_bool_from_0_or_1(
+            parameter,
+            PARAMETER_LOCK_ENV_SWITCH,
+        )?),
+        PARAMETER_LOCK_ENV => {
+            trig.set_parameter_lock_env(get_bool_from_0_or_1(parameter, PARAMETER_LOCK_ENV)?);
+        }
         NOTE => trig.set_note(parameter.get_int() as usize)?,
         VELOCITY => trig.set_velocity(parameter.get_int() as usize)?,
         RETRIG_VELOCITY_OFFSET => trig.set_retrig_velocity_offset(parameter.get_int())?,
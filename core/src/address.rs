@@ -0,0 +1,146 @@
+//! Validated addresses of a pattern, a track in it or a trig in a track.
+//!
+//! Getters and setters of `pattern` and `pattern_wb` resolve their indices through these so an index which is out
+//! of range is an error instead of a panic.
+
+use crate::atom::{Atom, AtomValue};
+use crate::error::{GetError, RytmExternalError, SetError};
+use crate::selection::{TRACK_COUNT, TRIG_COUNT};
+use crate::util::{
+    string_from_atom_slice, try_get_atom_value_assuming_identifier_or_index_or_enum_value,
+};
+use rytm_rs::object::Pattern;
+use rytm_rs::prelude::*;
+
+/// The pattern which is addressed, a stored pattern or the pattern of the work buffer.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PatternSlot {
+    Stored(usize),
+    WorkBuffer,
+}
+
+impl PatternSlot {
+    /// The index of a stored pattern, the plock sound of a track is found through it.
+    pub const fn index(self) -> Option<usize> {
+        match self {
+            Self::Stored(index) => Some(index),
+            Self::WorkBuffer => None,
+        }
+    }
+
+    /// The index of the atom which follows the object selector, `pattern <index>` is an atom longer than `pattern_wb`.
+    pub const fn first_atom(self) -> usize {
+        match self {
+            Self::Stored(_) => 2,
+            Self::WorkBuffer => 1,
+        }
+    }
+
+    pub fn pattern(self, project: &RytmProject) -> &Pattern {
+        match self {
+            Self::Stored(index) => &project.patterns()[index],
+            Self::WorkBuffer => project.work_buffer().pattern(),
+        }
+    }
+
+    pub fn pattern_mut(self, project: &mut RytmProject) -> &mut Pattern {
+        match self {
+            Self::Stored(index) => &mut project.patterns_mut()[index],
+            Self::WorkBuffer => project.work_buffer_mut().pattern_mut(),
+        }
+    }
+
+    pub fn invalid_getter_format(self, atoms: &[Atom]) -> RytmExternalError {
+        let atoms = string_from_atom_slice(atoms);
+        match self {
+            Self::Stored(_) => GetError::InvalidPatternGetterFormat(atoms).into(),
+            Self::WorkBuffer => GetError::InvalidPatternWbGetterFormat(atoms).into(),
+        }
+    }
+
+    pub fn invalid_setter_format(self, atoms: &[Atom]) -> RytmExternalError {
+        let atoms = string_from_atom_slice(atoms);
+        match self {
+            Self::Stored(_) => SetError::InvalidPatternSetterFormat(atoms).into(),
+            Self::WorkBuffer => SetError::InvalidPatternWbSetterFormat(atoms).into(),
+        }
+    }
+
+    pub fn invalid_format(self, atoms: &[Atom], operation: PatternOperation) -> RytmExternalError {
+        match operation {
+            PatternOperation::Get => self.invalid_getter_format(atoms),
+            PatternOperation::Set => self.invalid_setter_format(atoms),
+        }
+    }
+}
+
+/// Whether an address is read for a getter or a setter, errors show the formats of the operation.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PatternOperation {
+    Get,
+    Set,
+}
+
+/// What is addressed in a pattern.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PatternElement {
+    Pattern,
+    Track(usize),
+    Trig(usize, usize),
+}
+
+/// A pattern element with the index of the atom which follows its address.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct PatternAddress {
+    pub slot: PatternSlot,
+    pub element: PatternElement,
+    pub next: usize,
+}
+
+impl PatternAddress {
+    /// Reads the track and the trig index which may follow the object selector, e.g. `pattern 1 <track> <trig>`.
+    pub fn try_parse(
+        slot: PatternSlot,
+        atoms: &[Atom],
+        operation: PatternOperation,
+    ) -> Result<Self, RytmExternalError> {
+        let first = slot.first_atom();
+        let address = |element, next| Self {
+            slot,
+            element,
+            next,
+        };
+
+        let track_index =
+            match try_get_atom_value_assuming_identifier_or_index_or_enum_value(first, atoms)? {
+                AtomValue::Symbol(_) => return Ok(address(PatternElement::Pattern, first)),
+                AtomValue::Int(index) => validate_index(index, TRACK_COUNT, "Track")?,
+                AtomValue::Float(_) => return Err(slot.invalid_format(atoms, operation)),
+            };
+
+        match try_get_atom_value_assuming_identifier_or_index_or_enum_value(first + 1, atoms)? {
+            AtomValue::Symbol(_) => Ok(address(PatternElement::Track(track_index), first + 1)),
+            AtomValue::Int(index) => {
+                let trig_index = validate_index(index, TRIG_COUNT, "Trig")?;
+                Ok(address(
+                    PatternElement::Trig(track_index, trig_index),
+                    first + 2,
+                ))
+            }
+            AtomValue::Float(_) => Err(slot.invalid_format(atoms, operation)),
+        }
+    }
+}
+
+/// Checks that an index is in `0..count`, `name` is the capitalized name of what is indexed.
+pub fn validate_index(value: isize, count: usize, name: &str) -> Result<usize, RytmExternalError> {
+    if (0..count as isize).contains(&value) {
+        Ok(value as usize)
+    } else {
+        Err(format!(
+            "Invalid index: {value}. {name} index must be an integer between 0 and {}.",
+            count - 1
+        )
+        .into())
+    }
+}
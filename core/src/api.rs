@@ -1,17 +1,24 @@
+pub mod diff;
+pub mod edit;
 pub mod global;
 pub mod global_wb;
+pub mod history;
 pub mod kit;
 pub mod kit_wb;
+pub mod load;
+pub mod morph;
 pub mod pattern;
 pub mod pattern_wb;
+pub mod project;
+pub mod random;
 pub mod settings;
 pub mod sound;
 pub mod sound_kit;
 pub mod sound_wb;
+pub mod syx;
 
+use crate::atom::SymbolRef;
 use lazy_static::lazy_static;
-use median::symbol::SymbolRef;
-use std::convert::TryFrom;
 
 // TODO: Sub module error types insert
 
@@ -21,18 +28,70 @@ pub mod object_type {
     use super::*;
 
     lazy_static! {
-        pub static ref PATTERN: SymbolRef = SymbolRef::try_from("pattern").unwrap();
-        pub static ref PATTERN_WORK_BUFFER: SymbolRef = SymbolRef::try_from("pattern_wb").unwrap();
-        pub static ref KIT: SymbolRef = SymbolRef::try_from("kit").unwrap();
-        pub static ref KIT_WORK_BUFFER: SymbolRef = SymbolRef::try_from("kit_wb").unwrap();
-        pub static ref SOUND: SymbolRef = SymbolRef::try_from("sound").unwrap();
-        pub static ref SOUND_WORK_BUFFER: SymbolRef = SymbolRef::try_from("sound_wb").unwrap();
-        pub static ref GLOBAL: SymbolRef = SymbolRef::try_from("global").unwrap();
-        pub static ref GLOBAL_WORK_BUFFER: SymbolRef = SymbolRef::try_from("global_wb").unwrap();
-        pub static ref SETTINGS: SymbolRef = SymbolRef::try_from("settings").unwrap();
+        pub static ref PATTERN: SymbolRef = SymbolRef::from("pattern");
+        pub static ref PATTERN_WORK_BUFFER: SymbolRef = SymbolRef::from("pattern_wb");
+        pub static ref KIT: SymbolRef = SymbolRef::from("kit");
+        pub static ref KIT_WORK_BUFFER: SymbolRef = SymbolRef::from("kit_wb");
+        pub static ref SOUND: SymbolRef = SymbolRef::from("sound");
+        pub static ref SOUND_WORK_BUFFER: SymbolRef = SymbolRef::from("sound_wb");
+        pub static ref GLOBAL: SymbolRef = SymbolRef::from("global");
+        pub static ref GLOBAL_WORK_BUFFER: SymbolRef = SymbolRef::from("global_wb");
+        pub static ref SETTINGS: SymbolRef = SymbolRef::from("settings");
     }
 }
 
+/*** Bulk Targets ***/
+
+pub mod bulk_target {
+    pub const ALL: &str = "all";
+    pub const PATTERNS: &str = "patterns";
+    pub const KITS: &str = "kits";
+    pub const SOUNDS: &str = "sounds";
+    pub const GLOBALS: &str = "globals";
+    pub const WORK_BUFFER: &str = "workbuffer";
+    pub const STOP: &str = "stop";
+
+    pub const BULK_TARGETS: &[&str] = &[ALL, PATTERNS, KITS, SOUNDS, GLOBALS, WORK_BUFFER, STOP];
+}
+
+/*** Randomization ***/
+
+pub mod random_type {
+    pub const MUTATE: &str = "mutate";
+    pub const SEED: &str = "seed";
+
+    pub const AMP: &str = "amp";
+    pub const FILTER: &str = "filter";
+    pub const LFO: &str = "lfo";
+    pub const SAMP: &str = "samp";
+    pub const FX: &str = "fx";
+    pub const TRACK: &str = "track";
+}
+
+/*** History ***/
+
+pub mod history_type {
+    pub const HISTORY: &str = "history";
+    pub const UNDO: &str = "undo";
+    pub const REDO: &str = "redo";
+    pub const CLEAR: &str = "clear";
+}
+
+/*** Diff ***/
+
+pub mod diff_type {
+    pub const DIFF: &str = "diff";
+    pub const PROJECT: &str = "project";
+    pub const NONE: &str = "none";
+}
+
+/*** Morphing ***/
+
+pub mod morph_output_type {
+    pub const SYSEX: &str = "sysex";
+    pub const CC: &str = "cc";
+}
+
 /*** Object Element Types ***/
 
 pub mod kit_element_type {
@@ -61,13 +120,83 @@ pub mod plock_type {
     pub const ALL_PLOCK_TYPES: &[&str] = &[PLOCK_GET, PLOCK_SET, PLOCK_CLEAR];
 }
 
-// TODO: MACHINE
+// Machine parameters are shared between machines and resolved against the machine assigned to the sound.
 pub mod machine_parameter_type {
-    // TODO: For the first version we'll omit machine parameters.
+    pub const LEV: &str = "lev";
+    pub const TUN: &str = "tun";
+    pub const DEC: &str = "dec";
+    pub const HLD: &str = "hld";
+    pub const SWT: &str = "swt";
+    pub const SNP: &str = "snp";
+    pub const WAV: &str = "wav";
+    pub const TIC: &str = "tic";
+    pub const SWD: &str = "swd";
+    pub const TRA: &str = "tra";
+    pub const NOD: &str = "nod";
+    pub const NOL: &str = "nol";
+    pub const DET: &str = "det";
+    pub const BAL: &str = "bal";
+    pub const SYN: &str = "syn";
+    pub const T1: &str = "t1";
+    pub const T2: &str = "t2";
+    pub const SYM: &str = "sym";
+    pub const TON: &str = "ton";
+    pub const NUM: &str = "num";
+    pub const RAT: &str = "rat";
+    pub const RND: &str = "rnd";
+    pub const CPT: &str = "cpt";
+    pub const COL: &str = "col";
+    pub const PW1: &str = "pw1";
+    pub const PW2: &str = "pw2";
+    pub const FMA: &str = "fma";
+    pub const FMS: &str = "fms";
+    pub const FMD: &str = "fmd";
+    pub const FMT: &str = "fmt";
+    pub const LPF: &str = "lpf";
+    pub const HPF: &str = "hpf";
+    pub const LPQ: &str = "lpq";
+    pub const ATK: &str = "atk";
+    pub const POL: &str = "pol";
+    pub const TRD: &str = "trd";
+    pub const TYP: &str = "typ";
+    pub const MOD: &str = "mod";
+    pub const DUS: &str = "dus";
+    pub const CLK: &str = "clk";
+    pub const BDY: &str = "bdy";
+    pub const RES: &str = "res";
+    pub const RST: &str = "rst";
+    pub const HIT: &str = "hit";
+    pub const C1: &str = "c1";
+    pub const C2: &str = "c2";
+    pub const C3: &str = "c3";
+    pub const DEC1: &str = "dec1";
+    pub const DEC2: &str = "dec2";
+    pub const BND: &str = "bnd";
+    pub const CFG: &str = "cfg";
+    pub const OF2: &str = "of2";
+    pub const OF3: &str = "of3";
+    pub const OF4: &str = "of4";
+    pub const IMP: &str = "imp";
+    pub const NLEV: &str = "nlev";
+    pub const OSC1: &str = "osc1";
+    pub const OSC2: &str = "osc2";
+    pub const OSC3: &str = "osc3";
+    pub const OSC4: &str = "osc4";
+    pub const OSC5: &str = "osc5";
+    pub const OSC6: &str = "osc6";
+
+    pub const MACHINE_PARAMETER_TYPES: &[&str] = &[
+        LEV, TUN, DEC, HLD, SWT, SNP, WAV, TIC, SWD, TRA, NOD, NOL, DET, BAL, SYN, T1, T2, SYM,
+        TON, NUM, RAT, RND, CPT, COL, PW1, PW2, FMA, FMS, FMD, FMT, LPF, HPF, LPQ, ATK, POL, TRD,
+        TYP, MOD, DUS, CLK, BDY, RES, RST, HIT, C1, C2, C3, DEC1, DEC2, BND, CFG, OF2, OF3, OF4,
+        IMP, NLEV, OSC1, OSC2, OSC3, OSC4, OSC5, OSC6,
+    ];
 }
 
 /*** Action Types ***/
 
+// The read only action types of an object are only accepted by `get`, they tell where the object is kept.
+
 pub mod settings_action_type {
     pub const VERSION: &str = "version";
     pub const BPM_PROJECT: &str = "projectbpm";
@@ -79,6 +208,20 @@ pub mod settings_action_type {
     pub const FIXED_VELOCITY_AMOUNT: &str = "fixedvelocityamt";
     pub const SAMPLE_RECORDER_THR: &str = "samplerecorderthr";
     pub const SAMPLE_RECORDER_MONITOR_ENABLE: &str = "samplerecordermon";
+
+    pub const SETTINGS_READ_ONLY_ACTION_TYPES: &[&str] = &[VERSION];
+
+    pub const SETTINGS_ACTION_TYPES: &[&str] = &[
+        BPM_PROJECT,
+        SELECTED_TRACK,
+        SELECTED_PAGE,
+        MUTE,
+        UNMUTE,
+        FIXED_VELOCITY_ENABLE,
+        FIXED_VELOCITY_AMOUNT,
+        SAMPLE_RECORDER_THR,
+        SAMPLE_RECORDER_MONITOR_ENABLE,
+    ];
 }
 
 pub mod global_action_type {
@@ -102,12 +245,34 @@ pub mod global_action_type {
 
     pub const RECEIVE_NOTES: &str = "receivenotes";
     pub const RECEIVE_CC_NRPN: &str = "receiveccnrpn";
-    // Only get will be implemented for this one
+    // The device sets this one itself, it is read only.
     pub const TURBO_SPEED: &str = "turbospeed";
 
     pub const METRONOME_ACTIVE: &str = "metronomeactive";
     pub const METRONOME_PRE_ROLL_BARS: &str = "metronomeprerollbars";
     pub const METRONOME_VOLUME: &str = "metronomelev";
+
+    pub const GLOBAL_READ_ONLY_ACTION_TYPES: &[&str] =
+        &[VERSION, INDEX, IS_WORK_BUFFER, TURBO_SPEED];
+
+    pub const GLOBAL_ACTION_TYPES: &[&str] = &[
+        KIT_RELOAD_ON_CHANGE,
+        QUANTIZE_LIVE_REC,
+        AUTO_TRACK_SWITCH,
+        ROUTE_TO_MAIN,
+        SEND_TO_FX,
+        CLOCK_RECEIVE,
+        CLOCK_SEND,
+        TRANSPORT_RECEIVE,
+        TRANSPORT_SEND,
+        PROGRAM_CHANGE_RECEIVE,
+        PROGRAM_CHANGE_SEND,
+        RECEIVE_NOTES,
+        RECEIVE_CC_NRPN,
+        METRONOME_ACTIVE,
+        METRONOME_PRE_ROLL_BARS,
+        METRONOME_VOLUME,
+    ];
 }
 
 pub mod kit_action_type {
@@ -151,10 +316,12 @@ pub mod kit_action_type {
     pub const FX_DISTORTION_AMOUNT: &str = "fxdistamt";
     pub const FX_DISTORTION_SYMMETRY: &str = "fxdistsym";
 
+    pub const KIT_READ_ONLY_ACTION_TYPES: &[&str] = &[VERSION, INDEX];
+
     pub const KIT_ACTION_TYPES: &[&str] = &[
-        VERSION,
-        INDEX,
         NAME,
+        CONTROL_IN_1_MOD_AMT,
+        CONTROL_IN_2_MOD_AMT,
         FX_DELAY_TIME,
         FX_DELAY_PING_PONG,
         FX_DELAY_STEREO_WIDTH,
@@ -178,6 +345,11 @@ pub mod kit_action_type {
         FX_LFO_FADE,
         FX_LFO_START_PHASE_OR_SLEW,
         FX_LFO_DEPTH,
+        FX_DISTORTION_DELAY_OVERDRIVE,
+        FX_DISTORTION_DELAY_POST,
+        FX_DISTORTION_REVERB_POST,
+        FX_DISTORTION_AMOUNT,
+        FX_DISTORTION_SYMMETRY,
     ];
 }
 
@@ -190,25 +362,45 @@ pub mod trig_action_type {
     pub const SWING: &str = "swing";
     pub const SLIDE: &str = "slide";
 
-    // TODO: I need to understand how these behave first.
-    // Also maybe we expose them in the parameter lock set action?
-    // pub const PARAMETER_LOCK_LFO_SWITCH: &str = "parameterlocklfoswitch";
-    // pub const PARAMETER_LOCK_LFO: &str = "parameterlocklfo";
-    // pub const PARAMETER_LOCK_SYNTH_SWITCH: &str = "parameterlocksynthswitch";
-    // pub const PARAMETER_LOCK_SYNTH: &str = "parameterlocksynth";
-    // pub const PARAMETER_LOCK_SAMPLE_SWITCH: &str = "parameterlocksampleswitch";
-    // pub const PARAMETER_LOCK_SAMPLE: &str = "parameterlocksample";
-    // pub const PARAMETER_LOCK_ENV_SWITCH: &str = "parameterlockenvswitch";
-    // pub const PARAMETER_LOCK_ENV: &str = "parameterlockenv";
+    // The switch flags lock the trig menu switches of a trig instead of following the track defaults.
+    // The non switch flags are the locked values of these switches (SYN, SMP, ENV and LFO in the trig menu).
+    pub const PARAMETER_LOCK_LFO_SWITCH: &str = "parameterlocklfoswitch";
+    pub const PARAMETER_LOCK_LFO: &str = "parameterlocklfo";
+    pub const PARAMETER_LOCK_SYNTH_SWITCH: &str = "parameterlocksynthswitch";
+    pub const PARAMETER_LOCK_SYNTH: &str = "parameterlocksynth";
+    pub const PARAMETER_LOCK_SAMPLE_SWITCH: &str = "parameterlocksampleswitch";
+    pub const PARAMETER_LOCK_SAMPLE: &str = "parameterlocksample";
+    pub const PARAMETER_LOCK_ENV_SWITCH: &str = "parameterlockenvswitch";
+    pub const PARAMETER_LOCK_ENV: &str = "parameterlockenv";
 
     pub const NOTE: &str = "note";
     pub const VELOCITY: &str = "vel";
     pub const RETRIG_VELOCITY_OFFSET: &str = "retrigveloffset";
     pub const SOUND_LOCK: &str = "soundlock";
+
+    pub const TRIG_ACTION_TYPES: &[&str] = &[
+        ENABLE,
+        RETRIG,
+        MUTE,
+        ACCENT,
+        SWING,
+        SLIDE,
+        PARAMETER_LOCK_LFO_SWITCH,
+        PARAMETER_LOCK_LFO,
+        PARAMETER_LOCK_SYNTH_SWITCH,
+        PARAMETER_LOCK_SYNTH,
+        PARAMETER_LOCK_SAMPLE_SWITCH,
+        PARAMETER_LOCK_SAMPLE,
+        PARAMETER_LOCK_ENV_SWITCH,
+        PARAMETER_LOCK_ENV,
+        NOTE,
+        VELOCITY,
+        RETRIG_VELOCITY_OFFSET,
+        SOUND_LOCK,
+    ];
 }
 
 pub mod track_action_type {
-    pub const IS_WORK_BUFFER: &str = "iswb";
     pub const OWNER_INDEX: &str = "parentindex";
     pub const INDEX: &str = "index";
     pub const DEF_TRIG_NOTE: &str = "deftrignote";
@@ -224,6 +416,25 @@ pub mod track_action_type {
     pub const EUCLIDEAN_RO1: &str = "ro1";
     pub const EUCLIDEAN_RO2: &str = "ro2";
     pub const EUCLIDEAN_TRO: &str = "tro";
+    // Renders a euclidean rhythm into the trigs, it is not a property of the track.
+    pub const EUCLID: &str = "euclid";
+
+    pub const TRACK_READ_ONLY_ACTION_TYPES: &[&str] = &[OWNER_INDEX, INDEX];
+
+    pub const TRACK_ACTION_TYPES: &[&str] = &[
+        DEF_TRIG_NOTE,
+        DEF_TRIG_VELOCITY,
+        DEF_TRIG_PROB,
+        NUMBER_OF_STEPS,
+        QUANTIZE_AMOUNT,
+        SENDS_MIDI,
+        EUCLIDEAN_MODE,
+        EUCLIDEAN_PL1,
+        EUCLIDEAN_PL2,
+        EUCLIDEAN_RO1,
+        EUCLIDEAN_RO2,
+        EUCLIDEAN_TRO,
+    ];
 }
 
 pub mod pattern_action_type {
@@ -239,6 +450,17 @@ pub mod pattern_action_type {
 
     // TODO: Newly found settings
     // pub const PAD_SCALE_PER_TRACK: &str = "padscalepertrack";
+
+    pub const PATTERN_READ_ONLY_ACTION_TYPES: &[&str] = &[IS_WORK_BUFFER, INDEX, VERSION];
+
+    pub const PATTERN_ACTION_TYPES: &[&str] = &[
+        MASTER_LENGTH,
+        MASTER_CHANGE,
+        KIT_NUMBER,
+        SWING_AMOUNT,
+        GLOBAL_QUANTIZE,
+        BPM,
+    ];
 }
 
 pub mod sound_action_type {
@@ -292,15 +514,18 @@ pub mod sound_action_type {
     pub const VELOCITY_TO_VOLUME: &str = "veltovol";
     pub const LEGACY_FX_SEND: &str = "legacyfxsend";
 
-    pub const SOUND_ACTION_TYPES: &[&str] = &[
+    pub const SOUND_READ_ONLY_ACTION_TYPES: &[&str] = &[
         VERSION,
         INDEX,
-        NAME,
         IS_POOL,
         IS_KIT,
         IS_WORK_BUFFER,
         KIT_NUMBER,
         SOUND_TYPE,
+    ];
+
+    pub const SOUND_ACTION_TYPES: &[&str] = &[
+        NAME,
         ACCENT_LEVEL,
         AMP_ATTACK,
         AMP_HOLD,
@@ -342,12 +567,16 @@ pub mod sound_action_type {
 pub mod pattern_enum_type {
     pub const SPEED: &str = "speed";
     pub const TIME_MODE: &str = "timemode";
+
+    pub const PATTERN_ENUM_TYPES: &[&str] = &[SPEED, TIME_MODE];
 }
 
 pub mod track_enum_type {
     pub const ROOT_NOTE: &str = "rootnote";
     pub const PAD_SCALE: &str = "padscale";
     pub const DEFAULT_NOTE_LENGTH: &str = "defaultnotelen";
+
+    pub const TRACK_ENUM_TYPES: &[&str] = &[ROOT_NOTE, PAD_SCALE, DEFAULT_NOTE_LENGTH];
 }
 
 pub mod trig_enum_type {
@@ -356,6 +585,14 @@ pub mod trig_enum_type {
     pub const RETRIG_LENGTH: &str = "retriglen";
     pub const RETRIG_RATE: &str = "retrigrate";
     pub const TRIG_CONDITION: &str = "trigcondition";
+
+    pub const TRIG_ENUM_TYPES: &[&str] = &[
+        MICRO_TIME,
+        NOTE_LENGTH,
+        RETRIG_LENGTH,
+        RETRIG_RATE,
+        TRIG_CONDITION,
+    ];
 }
 
 pub mod kit_enum_type {
@@ -390,10 +627,18 @@ pub mod settings_enum_type {
     pub const PATTERN_MODE: &str = "patternmode";
     pub const SAMPLE_RECORDER_SOURCE: &str = "samplerecordersrc";
     pub const SAMPLE_RECORDER_RECORDING_LENGTH: &str = "samplerecorderrecordinglen";
+
+    pub const SETTINGS_ENUM_TYPES: &[&str] = &[
+        PARAMETER_MENU_ITEM,
+        FX_PARAMETER_MENU_ITEM,
+        SEQUENCER_MODE,
+        PATTERN_MODE,
+        SAMPLE_RECORDER_SOURCE,
+        SAMPLE_RECORDER_RECORDING_LENGTH,
+    ];
 }
 
 pub mod sound_enum_type {
-    pub const MACHINE_PARAMETERS: &str = "machineparameters";
     pub const MACHINE_TYPE: &str = "machinetype";
     pub const LFO_DESTINATION: &str = "lfodest";
     pub const VELOCITY_MOD_TARGET: &str = "velmodtarget";
@@ -405,7 +650,6 @@ pub mod sound_enum_type {
     pub const SOUND_SETTINGS_CHROMATIC_MODE: &str = "chromaticmode";
 
     pub const SOUND_ENUM_TYPES: &[&str] = &[
-        MACHINE_PARAMETERS,
         MACHINE_TYPE,
         LFO_DESTINATION,
         VELOCITY_MOD_TARGET,
@@ -425,6 +669,15 @@ pub mod sound_machine_enum_type {
     pub const SY_CHIP_SPEED: &str = "sychipspeed";
     pub const SY_RAW_WAVEFORM_1: &str = "syrawwaveform1";
     pub const SY_RAW_WAVEFORM_2: &str = "syrawwaveform2";
+
+    pub const SOUND_MACHINE_ENUM_TYPES: &[&str] = &[
+        BD_ACOUSTIC_WAVEFORM,
+        BD_SHARP_WAVEFORM,
+        SY_CHIP_WAVEFORM,
+        SY_CHIP_SPEED,
+        SY_RAW_WAVEFORM_1,
+        SY_RAW_WAVEFORM_2,
+    ];
 }
 
 pub mod global_enum_type {
@@ -451,4 +704,27 @@ pub mod global_enum_type {
     pub const PROGRAM_CHANGE_IN_CHANNEL: &str = "pgmchangeinchannel";
     pub const PROGRAM_CHANGE_OUT_CHANNEL: &str = "pgmchangeoutchannel";
     pub const PERFORMANCE_CHANNEL: &str = "performancechannel";
+
+    pub const GLOBAL_ENUM_TYPES: &[&str] = &[
+        METRONOME_TIME_SIGNATURE,
+        ROUTING_USB_IN_OPTIONS,
+        ROUTING_USB_OUT_OPTIONS,
+        ROUTING_USB_TO_MAIN_DB,
+        OUT_PORT_FUNCTION,
+        THRU_PORT_FUNCTION,
+        INPUT_FROM,
+        OUTPUT_TO,
+        PARAM_OUTPUT,
+        PAD_DEST,
+        PRESSURE_DEST,
+        ENCODER_DEST,
+        MUTE_DEST,
+        PORTS_OUTPUT_CHANNEL,
+        AUTO_CHANNEL,
+        TRACK_CHANNELS,
+        TRACK_FX_CHANNEL,
+        PROGRAM_CHANGE_IN_CHANNEL,
+        PROGRAM_CHANGE_OUT_CHANNEL,
+        PERFORMANCE_CHANNEL,
+    ];
 }
@@ -0,0 +1,128 @@
+use crate::atom::SymbolRef;
+use crate::atom::{Atom, AtomValue};
+use crate::util::LockOrRecover;
+use crate::{
+    api::{diff_type::*, project::read_project},
+    diff::{diff_objects, diff_projects, Difference, PathSegment},
+    error::RytmExternalError,
+    rytm::Rytm,
+    types::ObjectTypeSelector,
+    util::{string_from_atom_slice, try_get_path_from_atom_slice},
+};
+use serde_json::Value;
+use std::convert::TryFrom;
+
+const FORMAT: &str = "diff <object-type> [<index>] <object-type> [<index>] or diff project <path> where both objects are of the same type. Example: diff kit 3 kit_wb";
+
+/// Outputs the parameters which differ between two objects or between the project and a project file.
+///
+/// Format: `diff <identifier> .. <from> <to>` for two objects and `diff <object-type> <index> <identifier> .. <current> <saved>` for a project.
+pub fn handle_diff(rytm: &Rytm, atoms: &[Atom]) -> Result<(), RytmExternalError> {
+    let is_project = matches!(
+        atoms.first().and_then(Atom::get_value),
+        Some(AtomValue::Symbol(symbol)) if symbol.to_string() == PROJECT
+    );
+
+    if is_project {
+        if atoms.len() != 2 {
+            return Err(invalid_format(atoms));
+        }
+        let path = try_get_path_from_atom_slice(1, atoms)?;
+        let (mut saved, unlinked) = read_project(&path)?;
+        if unlinked > 0 {
+            return Err(format!(
+                "Invalid diff: Parameter locks of {unlinked} objects in {} could not be restored so the project can not be compared.",
+                path.display()
+            )
+            .into());
+        }
+
+        let differences = diff_projects(&mut rytm.project.lock_or_recover(), &mut saved)?;
+        for (object, difference) in differences {
+            let prefix = [
+                Atom::from(object.object_type()),
+                Atom::from(AtomValue::Int(object.index() as isize)),
+            ];
+            send_difference(rytm, &prefix, &difference);
+        }
+
+        return Ok(());
+    }
+
+    let (from, next) = diff_object(atoms, 0)?;
+    let (to, next) = diff_object(atoms, next)?;
+    if next != atoms.len() {
+        return Err(invalid_format(atoms));
+    }
+
+    let differences = {
+        let mut project = rytm.project.lock_or_recover();
+        diff_objects(&mut project, from, to)?
+    };
+    for difference in differences {
+        send_difference(rytm, &[], &difference);
+    }
+
+    Ok(())
+}
+
+/// Parses an object and returns it with the index of the atom which follows it.
+fn diff_object(
+    atoms: &[Atom],
+    at: usize,
+) -> Result<(ObjectTypeSelector, usize), RytmExternalError> {
+    let object = ObjectTypeSelector::try_from((
+        atoms.get(at).ok_or_else(|| invalid_format(atoms))?,
+        atoms.get(at + 1),
+    ))?;
+    let next = if object.indexable() { at + 2 } else { at + 1 };
+
+    Ok((object, next))
+}
+
+fn send_difference(rytm: &Rytm, prefix: &[Atom], difference: &Difference) {
+    let mut message = vec![Atom::from(SymbolRef::from(DIFF))];
+    message.extend_from_slice(prefix);
+    message.extend(difference_atoms(difference));
+
+    rytm.query_out.send(&message[..]);
+}
+
+/// Converts a difference to `<identifier> .. <from> <to>`.
+pub(crate) fn difference_atoms(difference: &Difference) -> Vec<Atom> {
+    let mut atoms = difference
+        .path
+        .iter()
+        .map(|segment| match segment {
+            PathSegment::Identifier(identifier) => Atom::from(SymbolRef::from(identifier.as_str())),
+            PathSegment::Index(index) => Atom::from(AtomValue::Int(*index as isize)),
+        })
+        .collect::<Vec<_>>();
+    atoms.push(value_atom(difference.from.clone()));
+    atoms.push(value_atom(difference.to.clone()));
+    atoms
+}
+
+/// Converts a value of a dump to an atom, a missing value is `none` and lists or objects are sent as json.
+fn value_atom(value: Option<Value>) -> Atom {
+    match value {
+        None | Some(Value::Null) => Atom::from(SymbolRef::from(NONE)),
+        Some(Value::Bool(value)) => Atom::from(AtomValue::Int(isize::from(value))),
+        Some(Value::Number(number)) if number.is_i64() => {
+            Atom::from(AtomValue::Int(number.as_i64().unwrap_or_default() as isize))
+        }
+        Some(Value::Number(number)) => {
+            Atom::from(AtomValue::Float(number.as_f64().unwrap_or_default()))
+        }
+        Some(Value::String(value)) => Atom::from(SymbolRef::from(value.as_str())),
+        Some(value) => Atom::from(SymbolRef::from(value.to_string())),
+    }
+}
+
+fn invalid_format(atoms: &[Atom]) -> RytmExternalError {
+    format!(
+        "Invalid format: {}. The right format should be, {FORMAT}",
+        string_from_atom_slice(atoms)
+    )
+    .into()
+}
@@ -0,0 +1,253 @@
+use crate::atom::{Atom, AtomValue};
+use crate::util::LockOrRecover;
+use crate::{
+    api::kit_element_type,
+    edit::{Clipboard, SoundSlot},
+    error::RytmExternalError,
+    rytm::Rytm,
+    selection::{IndexSelection, TRACK_COUNT, TRIG_COUNT},
+    types::ObjectTypeSelector,
+    util::string_from_atom_slice,
+};
+use std::convert::TryFrom;
+
+pub fn handle_pattern_copy(rytm: &Rytm, atoms: &[Atom]) -> Result<(), RytmExternalError> {
+    let (object, next) = pattern_object(
+        atoms,
+        "copy <pattern|pattern_wb> [<index>] [<tracks> [<trigs>]]. Example: copy pattern 0 0..3",
+    )?;
+
+    let tracks = IndexSelection::try_parse(atoms, next, TRACK_COUNT, "Track")?;
+    let trigs = tracks
+        .as_ref()
+        .map(|tracks| IndexSelection::try_parse(atoms, tracks.next, TRIG_COUNT, "Trig"))
+        .transpose()?
+        .flatten();
+    only_allow_atoms_until(
+        atoms,
+        trigs
+            .as_ref()
+            .or(tracks.as_ref())
+            .map_or(next, |selection| selection.next),
+    )?;
+
+    let clipboard = {
+        let project = rytm.project.lock_or_recover();
+        Clipboard::copy(
+            &project,
+            object,
+            tracks.as_ref().map(|tracks| {
+                (
+                    tracks.indices.as_slice(),
+                    trigs.as_ref().map(|trigs| trigs.indices.as_slice()),
+                )
+            }),
+        )?
+    };
+    *rytm.clipboard.lock_or_recover() = Some(clipboard);
+
+    Ok(())
+}
+
+pub fn handle_pattern_paste(rytm: &Rytm, atoms: &[Atom]) -> Result<(), RytmExternalError> {
+    let (object, next) = pattern_object(
+        atoms,
+        "paste <pattern|pattern_wb> [<index>] [<track> [<trig>]]. Example: paste pattern_wb 4 0",
+    )?;
+
+    let index = |at: usize, count: usize, name: &str| match atoms.get(at).and_then(Atom::get_value)
+    {
+        Some(AtomValue::Int(index)) if (0..count as isize).contains(&index) => {
+            Ok(Some(index as usize))
+        }
+        None => Ok(None),
+        _ => Err(RytmExternalError::from(format!(
+            "Invalid format: {}. {name} index to paste to must be an integer between 0 and {}.",
+            string_from_atom_slice(atoms),
+            count - 1
+        ))),
+    };
+    let start = index(next, TRACK_COUNT, "Track")?
+        .map(|track| Ok::<_, RytmExternalError>((track, index(next + 1, TRIG_COUNT, "Trig")?)))
+        .transpose()?;
+    only_allow_atoms_until(atoms, next + 2)?;
+
+    let guard = rytm.clipboard.lock_or_recover();
+    let clipboard = guard
+        .as_ref()
+        .ok_or("Invalid paste: The clipboard is empty. Copy a pattern, tracks or trigs first.")?;
+    rytm.record_change(Rytm::SELECTOR_PASTE, atoms, &[object], || {
+        clipboard.paste(&mut rytm.project.lock_or_recover(), object, start)
+    })
+}
+
+pub fn handle_pattern_clear(rytm: &Rytm, atoms: &[Atom]) -> Result<(), RytmExternalError> {
+    let format =
+        "clear <pattern|pattern_wb> [<index>] <tracks> [<trigs>]. Example: clear pattern_wb 0";
+    let (object, next) = pattern_object(atoms, format)?;
+    let tracks = required_tracks(atoms, next, format)?;
+    let trigs = IndexSelection::try_parse(atoms, tracks.next, TRIG_COUNT, "Trig")?;
+    only_allow_atoms_until(
+        atoms,
+        trigs.as_ref().map_or(tracks.next, |trigs| trigs.next),
+    )?;
+
+    rytm.record_change(Rytm::SELECTOR_CLEAR, atoms, &[object], || {
+        crate::edit::clear(
+            &mut rytm.project.lock_or_recover(),
+            object,
+            &tracks.indices,
+            trigs.as_ref().map(|trigs| trigs.indices.as_slice()),
+        )
+    })
+}
+
+pub fn handle_pattern_shift(rytm: &Rytm, atoms: &[Atom]) -> Result<(), RytmExternalError> {
+    let format =
+        "shift <pattern|pattern_wb> [<index>] <tracks> <steps>. Example: shift pattern_wb all -1";
+    let (object, next) = pattern_object(atoms, format)?;
+    let tracks = required_tracks(atoms, next, format)?;
+    let Some(AtomValue::Int(amount)) = atoms.get(tracks.next).and_then(Atom::get_value) else {
+        return Err(format!(
+            "Invalid format: {}. The tracks should be followed by an integer amount of steps to shift. The right format should be, {format}",
+            string_from_atom_slice(atoms)
+        )
+        .into());
+    };
+    only_allow_atoms_until(atoms, tracks.next + 1)?;
+
+    rytm.record_change(Rytm::SELECTOR_SHIFT, atoms, &[object], || {
+        crate::edit::shift(
+            &mut rytm.project.lock_or_recover(),
+            object,
+            &tracks.indices,
+            amount,
+        )
+    })
+}
+
+pub fn handle_pattern_reverse(rytm: &Rytm, atoms: &[Atom]) -> Result<(), RytmExternalError> {
+    let format = "reverse <pattern|pattern_wb> [<index>] <tracks>. Example: reverse pattern 3 0..3";
+    let (object, next) = pattern_object(atoms, format)?;
+    let tracks = required_tracks(atoms, next, format)?;
+    only_allow_atoms_until(atoms, tracks.next)?;
+
+    rytm.record_change(Rytm::SELECTOR_REVERSE, atoms, &[object], || {
+        crate::edit::reverse(&mut rytm.project.lock_or_recover(), object, &tracks.indices)
+    })
+}
+
+pub fn handle_sound_copy(rytm: &Rytm, atoms: &[Atom]) -> Result<(), RytmExternalError> {
+    let format = "copysound <from> <to> where both are one of sound <index>, sound_wb <index>, kit <index> sound <sound-index> or kit_wb sound <sound-index>. Example: copysound kit 12 sound 3 sound 0";
+    let (from, next) = sound_slot(atoms, 0, format)?;
+    let (to, next) = sound_slot(atoms, next, format)?;
+    only_allow_atoms_until(atoms, next)?;
+
+    rytm.record_change(Rytm::SELECTOR_COPY_SOUND, atoms, &[to.object()], || {
+        from.copy(&mut rytm.project.lock_or_recover(), to)
+    })
+}
+
+pub fn handle_sound_swap(rytm: &Rytm, atoms: &[Atom]) -> Result<(), RytmExternalError> {
+    let format = "swapsound <sound> <sound> where both are one of sound <index>, sound_wb <index>, kit <index> sound <sound-index> or kit_wb sound <sound-index>. Example: swapsound kit_wb sound 0 kit_wb sound 1";
+    let (first, next) = sound_slot(atoms, 0, format)?;
+    let (second, next) = sound_slot(atoms, next, format)?;
+    only_allow_atoms_until(atoms, next)?;
+
+    rytm.record_change(
+        Rytm::SELECTOR_SWAP_SOUND,
+        atoms,
+        &[first.object(), second.object()],
+        || first.swap(&mut rytm.project.lock_or_recover(), second),
+    )
+}
+
+/// Parses `<pattern|pattern_wb> [<index>]` and returns the pattern with the index of the atom which follows it.
+fn pattern_object(
+    atoms: &[Atom],
+    format: &str,
+) -> Result<(ObjectTypeSelector, usize), RytmExternalError> {
+    let invalid_format = || -> RytmExternalError {
+        format!(
+            "Invalid format: {}. The right format should be, {format}",
+            string_from_atom_slice(atoms)
+        )
+        .into()
+    };
+
+    let object =
+        ObjectTypeSelector::try_from((atoms.first().ok_or_else(invalid_format)?, atoms.get(1)))?;
+    match object {
+        ObjectTypeSelector::Pattern(_) => Ok((object, 2)),
+        ObjectTypeSelector::PatternWorkBuffer => Ok((object, 1)),
+        _ => Err(invalid_format()),
+    }
+}
+
+/// Parses `<sound|sound_wb> <index>`, `kit <index> sound <sound-index>` or `kit_wb sound <sound-index>` starting from
+/// the atom at `at` and returns the sound with the index of the atom which follows it.
+pub(crate) fn sound_slot(
+    atoms: &[Atom],
+    at: usize,
+    format: &str,
+) -> Result<(SoundSlot, usize), RytmExternalError> {
+    let invalid_format = || -> RytmExternalError {
+        format!(
+            "Invalid format: {}. The right format should be, {format}",
+            string_from_atom_slice(atoms)
+        )
+        .into()
+    };
+
+    let object = ObjectTypeSelector::try_from((
+        atoms.get(at).ok_or_else(invalid_format)?,
+        atoms.get(at + 1),
+    ))?;
+    let next = if object.indexable() { at + 2 } else { at + 1 };
+    let kit_sound = || {
+        let is_sound = matches!(
+            atoms.get(next).and_then(Atom::get_value),
+            Some(AtomValue::Symbol(symbol)) if symbol.as_str() == kit_element_type::SOUND
+        );
+        match atoms.get(next + 1).and_then(Atom::get_value) {
+            Some(AtomValue::Int(track)) if is_sound && (0..12).contains(&track) => {
+                Ok(track as usize)
+            }
+            _ => Err(invalid_format()),
+        }
+    };
+
+    match object {
+        ObjectTypeSelector::Sound(index) => Ok((SoundSlot::Pool(index), next)),
+        ObjectTypeSelector::SoundWorkBuffer(index) => Ok((SoundSlot::WorkBuffer(index), next)),
+        ObjectTypeSelector::Kit(index) => Ok((SoundSlot::Kit(index, kit_sound()?), next + 2)),
+        ObjectTypeSelector::KitWorkBuffer => Ok((SoundSlot::KitWorkBuffer(kit_sound()?), next + 2)),
+        _ => Err(invalid_format()),
+    }
+}
+
+fn required_tracks(
+    atoms: &[Atom],
+    at: usize,
+    format: &str,
+) -> Result<IndexSelection, RytmExternalError> {
+    IndexSelection::try_parse(atoms, at, TRACK_COUNT, "Track")?.ok_or_else(|| {
+        format!(
+            "Invalid format: {}. The pattern should be followed by the tracks to edit. The right format should be, {format}",
+            string_from_atom_slice(atoms)
+        )
+        .into()
+    })
+}
+
+fn only_allow_atoms_until(atoms: &[Atom], end: usize) -> Result<(), RytmExternalError> {
+    if atoms.len() > end {
+        return Err(format!(
+            "Invalid format: {}. Unexpected {} at the end of the message.",
+            string_from_atom_slice(atoms),
+            string_from_atom_slice(&atoms[end..])
+        )
+        .into());
+    }
+    Ok(())
+}
@@ -1,4 +1,6 @@
+use crate::atom::{Atom, AtomValue};
 use crate::error::{GetError, SetError};
+use crate::util::LockOrRecover;
 use crate::util::{
     string_from_atom_slice, try_get_atom_value_assuming_identifier_or_index_or_enum_value,
 };
@@ -11,18 +13,17 @@ use crate::{
     rytm::Rytm,
     util::try_get_identifier_value_from_atom_slice,
 };
-use median::atom::{Atom, AtomValue};
 
 pub fn handle_global_set(
     rytm: &Rytm,
     atoms: &[Atom],
     global_index: usize,
 ) -> Result<(), RytmExternalError> {
-    let mut guard = rytm.project.lock().unwrap();
+    let mut guard = rytm.project.lock_or_recover();
 
     match try_get_atom_value_assuming_identifier_or_index_or_enum_value(2, atoms)? {
         AtomValue::Symbol(action_or_enum_value) => {
-            let action_or_enum_value_str = action_or_enum_value.to_string()?;
+            let action_or_enum_value_str = action_or_enum_value.to_string();
             let enum_pair = action_or_enum_value_str.split_once(':');
 
             let global_mut = &mut guard.globals_mut()[global_index];
@@ -33,7 +34,12 @@ pub fn handle_global_set(
                 handle_global_set_enum_value(global_mut, enum_type, enum_value, maybe_next_atom)
             } else {
                 let parameter_atom = try_get_identifier_value_from_atom_slice(3, atoms)?;
-                handle_global_set_action(global_mut, &action_or_enum_value_str, parameter_atom)
+                handle_global_set_action(
+                    global_mut,
+                    &action_or_enum_value_str,
+                    parameter_atom,
+                    atoms.get(4),
+                )
             }
         }
         _ => Err(SetError::InvalidGlobalSetterFormat(string_from_atom_slice(atoms)).into()),
@@ -45,11 +51,11 @@ pub fn handle_global_get(
     atoms: &[Atom],
     global_index: usize,
 ) -> Result<(), RytmExternalError> {
-    let guard = rytm.project.lock().unwrap();
+    let guard = rytm.project.lock_or_recover();
     let out = &rytm.query_out;
     match try_get_atom_value_assuming_identifier_or_index_or_enum_value(2, atoms)? {
         AtomValue::Symbol(action_or_enum_value) => {
-            let action_or_enum_value_str = action_or_enum_value.to_string()?;
+            let action_or_enum_value_str = action_or_enum_value.to_string();
             let enum_pair = action_or_enum_value_str.split_once(':');
 
             let global = &guard.globals()[global_index];
@@ -1,3 +1,5 @@
+use crate::atom::{Atom, AtomValue};
+use crate::util::LockOrRecover;
 use crate::{
     action::{
         get::global::{handle_global_get_action, handle_global_get_enum_value},
@@ -7,16 +9,15 @@ use crate::{
     rytm::Rytm,
     util::{string_from_atom_slice, try_get_identifier_value_from_atom_slice},
 };
-use median::atom::{Atom, AtomValue};
 
 use crate::util::try_get_atom_value_assuming_identifier_or_index_or_enum_value;
 
 pub fn handle_global_wb_set(rytm: &Rytm, atoms: &[Atom]) -> Result<(), RytmExternalError> {
-    let mut guard = rytm.project.lock().unwrap();
+    let mut guard = rytm.project.lock_or_recover();
 
     match try_get_atom_value_assuming_identifier_or_index_or_enum_value(1, atoms)? {
         AtomValue::Symbol(action_or_enum_value) => {
-            let action_or_enum_value_str = action_or_enum_value.to_string()?;
+            let action_or_enum_value_str = action_or_enum_value.to_string();
             let enum_pair = action_or_enum_value_str.split_once(':');
 
             let global_mut = guard.work_buffer_mut().global_mut();
@@ -27,7 +28,12 @@ pub fn handle_global_wb_set(rytm: &Rytm, atoms: &[Atom]) -> Result<(), RytmExter
                 handle_global_set_enum_value(global_mut, enum_type, enum_value, maybe_next_atom)
             } else {
                 let parameter_atom = try_get_identifier_value_from_atom_slice(2, atoms)?;
-                handle_global_set_action(global_mut, &action_or_enum_value_str, parameter_atom)
+                handle_global_set_action(
+                    global_mut,
+                    &action_or_enum_value_str,
+                    parameter_atom,
+                    atoms.get(3),
+                )
             }
         }
         _ => Err(SetError::InvalidGlobalWbSetterFormat(string_from_atom_slice(atoms)).into()),
@@ -35,11 +41,11 @@ pub fn handle_global_wb_set(rytm: &Rytm, atoms: &[Atom]) -> Result<(), RytmExter
 }
 
 pub fn handle_global_wb_get(rytm: &Rytm, atoms: &[Atom]) -> Result<(), RytmExternalError> {
-    let guard = rytm.project.lock().unwrap();
+    let guard = rytm.project.lock_or_recover();
     let out = &rytm.query_out;
     match try_get_atom_value_assuming_identifier_or_index_or_enum_value(1, atoms)? {
         AtomValue::Symbol(action_or_enum_value) => {
-            let action_or_enum_value_str = action_or_enum_value.to_string()?;
+            let action_or_enum_value_str = action_or_enum_value.to_string();
             let enum_pair = action_or_enum_value_str.split_once(':');
 
             let global = guard.work_buffer().global();
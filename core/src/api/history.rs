@@ -0,0 +1,97 @@
+use crate::api::diff::difference_atoms;
+use crate::atom::SymbolRef;
+use crate::atom::{Atom, AtomValue};
+use crate::util::LockOrRecover;
+use crate::{
+    api::history_type::*, error::RytmExternalError, rytm::Rytm, util::string_from_atom_slice,
+};
+
+pub fn handle_undo(rytm: &Rytm, atoms: &[Atom]) -> Result<(), RytmExternalError> {
+    if !atoms.is_empty() {
+        return Err(format!(
+            "Invalid format: undo {}. undo does not take any arguments.",
+            string_from_atom_slice(atoms)
+        )
+        .into());
+    }
+
+    let mut history = rytm.history.lock_or_recover();
+    history.undo(&mut rytm.project.lock_or_recover())?;
+
+    Ok(())
+}
+
+pub fn handle_redo(rytm: &Rytm, atoms: &[Atom]) -> Result<(), RytmExternalError> {
+    if !atoms.is_empty() {
+        return Err(format!(
+            "Invalid format: redo {}. redo does not take any arguments.",
+            string_from_atom_slice(atoms)
+        )
+        .into());
+    }
+
+    let mut history = rytm.history.lock_or_recover();
+    history.redo(&mut rytm.project.lock_or_recover())?;
+
+    Ok(())
+}
+
+/// Outputs the changes which can be undone and redone or clears them.
+///
+/// Format: `history <undo|redo> <position> <change> <object-type> <index> <identifier> .. <old> <new>` for every parameter
+/// a change touched and `history <undo|redo> <position> <change>` for a change which touched none, the ones which are
+/// next to undo or redo first.
+pub fn handle_history(rytm: &Rytm, atoms: &[Atom]) -> Result<(), RytmExternalError> {
+    match atoms {
+        [] => {}
+        [atom] if matches!(atom.get_value(), Some(AtomValue::Symbol(symbol)) if symbol.to_string() == CLEAR) =>
+        {
+            rytm.history.lock_or_recover().clear();
+            return Ok(());
+        }
+        _ => {
+            return Err(format!(
+                "Invalid format: history {}. The right format should be, history [clear].",
+                string_from_atom_slice(atoms)
+            )
+            .into())
+        }
+    }
+
+    let mut history = rytm.history.lock_or_recover();
+    // Dumping is slow for patterns so the parameters of changes are only listed when the history is asked for.
+    history.list_differences();
+    let changes = history
+        .undoable()
+        .enumerate()
+        .map(|(position, change)| (UNDO, position, change))
+        .chain(
+            history
+                .redoable()
+                .enumerate()
+                .map(|(position, change)| (REDO, position, change)),
+        );
+
+    for (kind, position, change) in changes {
+        let prefix = [
+            Atom::from(SymbolRef::from(HISTORY)),
+            Atom::from(SymbolRef::from(kind)),
+            Atom::from(AtomValue::Int(position as isize)),
+            Atom::from(SymbolRef::from(change.description())),
+        ];
+        if change.differences().is_empty() {
+            rytm.query_out.send(&prefix[..]);
+            continue;
+        }
+
+        for (object, difference) in change.differences() {
+            let mut message = prefix.to_vec();
+            message.push(Atom::from(object.object_type()));
+            message.push(Atom::from(AtomValue::Int(object.index() as isize)));
+            message.extend(difference_atoms(difference));
+            rytm.query_out.send(&message[..]);
+        }
+    }
+
+    Ok(())
+}
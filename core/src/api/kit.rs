@@ -1,3 +1,5 @@
+use crate::atom::Atom;
+use crate::util::LockOrRecover;
 use crate::{
     action::{
         get::kit::{
@@ -16,7 +18,6 @@ use crate::{
         KitElementOrActionOrEnumTypeAndValue,
     },
 };
-use median::atom::Atom;
 
 use crate::util::try_get_identifier_value_from_atom_slice;
 
@@ -25,7 +26,7 @@ pub fn handle_kit_set(
     atoms: &[Atom],
     kit_index: usize,
 ) -> Result<(), RytmExternalError> {
-    let mut guard = rytm.project.lock().unwrap();
+    let mut guard = rytm.project.lock_or_recover();
 
     match try_get_atom_value_as_kit_element_or_identifier_or_enum_value(2, atoms)? {
         KitElementOrActionOrEnumTypeAndValue::Action(action) => {
@@ -78,7 +79,7 @@ pub fn handle_kit_get(
     atoms: &[Atom],
     kit_index: usize,
 ) -> Result<(), RytmExternalError> {
-    let guard = rytm.project.lock().unwrap();
+    let guard = rytm.project.lock_or_recover();
     let out = &rytm.query_out;
     match try_get_atom_value_as_kit_element_or_identifier_or_enum_value(2, atoms)? {
         KitElementOrActionOrEnumTypeAndValue::Action(action) => {
@@ -1,3 +1,5 @@
+use crate::atom::Atom;
+use crate::util::LockOrRecover;
 use crate::{
     action::{
         get::kit::{
@@ -16,7 +18,6 @@ use crate::{
         KitElementOrActionOrEnumTypeAndValue,
     },
 };
-use median::atom::Atom;
 
 use crate::util::try_get_identifier_value_from_atom_slice;
 
@@ -39,7 +40,7 @@ use crate::util::try_get_identifier_value_from_atom_slice;
 // 3 (only after kit elem) treat it as the param for the chosen element
 
 pub fn handle_kit_wb_set(rytm: &Rytm, atoms: &[Atom]) -> Result<(), RytmExternalError> {
-    let mut guard = rytm.project.lock().unwrap();
+    let mut guard = rytm.project.lock_or_recover();
 
     match try_get_atom_value_as_kit_element_or_identifier_or_enum_value(1, atoms)? {
         KitElementOrActionOrEnumTypeAndValue::Action(action) => {
@@ -88,7 +89,7 @@ pub fn handle_kit_wb_set(rytm: &Rytm, atoms: &[Atom]) -> Result<(), RytmExternal
 }
 
 pub fn handle_kit_wb_get(rytm: &Rytm, atoms: &[Atom]) -> Result<(), RytmExternalError> {
-    let guard = rytm.project.lock().unwrap();
+    let guard = rytm.project.lock_or_recover();
     let out = &rytm.query_out;
     match try_get_atom_value_as_kit_element_or_identifier_or_enum_value(1, atoms)? {
         KitElementOrActionOrEnumTypeAndValue::Action(action) => {
@@ -0,0 +1,27 @@
+use crate::util::LockOrRecover;
+use crate::{
+    atom::{Atom, AtomValue},
+    error::RytmExternalError,
+    rytm::Rytm,
+    types::ObjectTypeSelector,
+};
+use std::convert::TryFrom;
+
+pub fn handle_object_load(rytm: &Rytm, atoms: &[Atom]) -> Result<(), RytmExternalError> {
+    let object = ObjectTypeSelector::try_from((
+        atoms.first().ok_or(
+            "Invalid format: The right format should be, load <object-type> [<index>] <dict-name>. Example: load kit 0 mykit",
+        )?,
+        atoms.get(1),
+    ))?;
+
+    let name_index = if object.indexable() { 2 } else { 1 };
+    let Some(AtomValue::Symbol(name)) = atoms.get(name_index).and_then(Atom::get_value) else {
+        return Err("Invalid format: load should be followed by the name of a dictionary. Example: load kit 0 mykit".into());
+    };
+
+    let value = (rytm.dictionary_reader)(name.as_str())?;
+    rytm.record_change(Rytm::SELECTOR_LOAD, atoms, &[object], || {
+        crate::load::load_object(&mut rytm.project.lock_or_recover(), object, &value)
+    })
+}
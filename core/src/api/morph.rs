@@ -0,0 +1,130 @@
+use crate::atom::{Atom, AtomValue};
+use crate::util::LockOrRecover;
+use crate::{
+    api::{edit::sound_slot, kit_element_type, morph_output_type::*},
+    edit::SoundSlot,
+    error::RytmExternalError,
+    live::LiveSnapshot,
+    morph::{morph, MorphObject},
+    rytm::Rytm,
+    types::ObjectTypeSelector,
+    util::string_from_atom_slice,
+};
+use std::convert::TryFrom;
+
+const FORMAT: &str = "morph <from> <to> <position 0.0..=1.0> <target> [sysex|cc] where from, to and target are either all sounds, one of sound <index>, sound_wb <index>, kit <index> sound <sound-index> or kit_wb sound <sound-index>, or all kits, one of kit <index> or kit_wb. Example: morph sound 0 sound 1 0.25 sound_wb 0 cc";
+
+pub fn handle_morph(rytm: &Rytm, atoms: &[Atom]) -> Result<(), RytmExternalError> {
+    let (from, next) = morph_object(atoms, 0)?;
+    let (to, next) = morph_object(atoms, next)?;
+    let position = match atoms.get(next).and_then(Atom::get_value) {
+        Some(AtomValue::Int(position @ 0..=1)) => f64::from(position as i32),
+        Some(AtomValue::Float(position)) => position,
+        _ => -1.0,
+    };
+    if !(0.0..=1.0).contains(&position) {
+        return Err(format!(
+            "Invalid format: {}. The position should be a number between 0.0 and 1.0.",
+            string_from_atom_slice(atoms)
+        )
+        .into());
+    }
+    let (target, next) = morph_object(atoms, next + 1)?;
+
+    let all_sounds = [from, to, target]
+        .iter()
+        .all(|object| matches!(object, MorphObject::Sound(_)));
+    let all_kits = [from, to, target]
+        .iter()
+        .all(|object| matches!(object, MorphObject::Kit(_)));
+    if !all_sounds && !all_kits {
+        return Err(format!(
+            "Invalid format: {}. Sounds can only be morphed into sounds and kits into kits.",
+            string_from_atom_slice(atoms)
+        )
+        .into());
+    }
+
+    let output = match atoms.get(next).and_then(Atom::get_value) {
+        None => None,
+        Some(AtomValue::Symbol(symbol)) if next + 1 == atoms.len() => {
+            match symbol.to_string().as_str() {
+                SYSEX => Some(SYSEX),
+                CC => Some(CC),
+                _ => return Err(invalid_format(atoms)),
+            }
+        }
+        _ => return Err(invalid_format(atoms)),
+    };
+
+    let run = || {
+        rytm.record_change(Rytm::SELECTOR_MORPH, atoms, &[target.object()], || {
+            morph(
+                &mut rytm.project.lock_or_recover(),
+                from,
+                to,
+                position,
+                target,
+            )
+        })
+    };
+    match (target, output) {
+        (_, Some(SYSEX)) => {
+            run()?;
+            rytm.send_object(target.object())
+        }
+        (MorphObject::Sound(SoundSlot::WorkBuffer(index)), Some(_)) => {
+            rytm.set_and_send_live_messages(|project| LiveSnapshot::sound(project, index), run)
+        }
+        (MorphObject::Kit(ObjectTypeSelector::KitWorkBuffer), Some(_)) => {
+            rytm.set_and_send_live_messages(LiveSnapshot::kit, run)
+        }
+        (_, Some(_)) => Err(format!(
+            "Invalid format: {}. cc output is only possible when the target is sound_wb <index> or kit_wb.",
+            string_from_atom_slice(atoms)
+        )
+        .into()),
+        (MorphObject::Sound(SoundSlot::WorkBuffer(index)), None) => {
+            rytm.set_with_live_output(|project| LiveSnapshot::sound(project, index), run)
+        }
+        (MorphObject::Kit(ObjectTypeSelector::KitWorkBuffer), None) => {
+            rytm.set_with_live_output(LiveSnapshot::kit, run)
+        }
+        _ => run(),
+    }
+}
+
+/// Parses a sound or a kit and returns it with the index of the atom which follows it.
+fn morph_object(atoms: &[Atom], at: usize) -> Result<(MorphObject, usize), RytmExternalError> {
+    let object = ObjectTypeSelector::try_from((
+        atoms.get(at).ok_or_else(|| invalid_format(atoms))?,
+        atoms.get(at + 1),
+    ))?;
+    let next = if object.indexable() { at + 2 } else { at + 1 };
+    let followed_by_sound = matches!(
+        atoms.get(next).and_then(Atom::get_value),
+        Some(AtomValue::Symbol(symbol)) if symbol.as_str() == kit_element_type::SOUND
+    );
+
+    match object {
+        ObjectTypeSelector::Kit(_) | ObjectTypeSelector::KitWorkBuffer if !followed_by_sound => {
+            Ok((MorphObject::Kit(object), next))
+        }
+        ObjectTypeSelector::Sound(_)
+        | ObjectTypeSelector::SoundWorkBuffer(_)
+        | ObjectTypeSelector::Kit(_)
+        | ObjectTypeSelector::KitWorkBuffer => {
+            let (slot, next) = sound_slot(atoms, at, FORMAT)?;
+            Ok((MorphObject::Sound(slot), next))
+        }
+        _ => Err(invalid_format(atoms)),
+    }
+}
+
+fn invalid_format(atoms: &[Atom]) -> RytmExternalError {
+    format!(
+        "Invalid format: {}. The right format should be, {FORMAT}",
+        string_from_atom_slice(atoms)
+    )
+    .into()
+}
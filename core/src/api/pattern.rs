@@ -0,0 +1,135 @@
+use crate::atom::{Atom, AtomValue};
+use crate::util::LockOrRecover;
+use crate::{
+    action::{
+        get::{pattern::pattern_get, track::track_get, trig::trig_get},
+        plock::handle_trig_plock_getter_action,
+        set::pattern::pattern_set,
+    },
+    address::{PatternAddress, PatternElement, PatternOperation, PatternSlot},
+    error::RytmExternalError,
+    rytm::Rytm,
+    selection::{set_pattern_elements, set_pattern_selection, IndexSelection, TRACK_COUNT},
+    util::{get_plock_sound_for_track, link_plock_sounds},
+};
+
+use super::plock_type::ALL_PLOCK_TYPES;
+use crate::util::try_get_atom_value_assuming_identifier_or_index_or_enum_value;
+
+pub fn handle_pattern_set(
+    rytm: &Rytm,
+    atoms: &[Atom],
+    pattern_index: usize,
+) -> Result<(), RytmExternalError> {
+    handle_pattern_slot_set(rytm, atoms, PatternSlot::Stored(pattern_index))
+}
+
+pub fn handle_pattern_get(
+    rytm: &Rytm,
+    atoms: &[Atom],
+    pattern_index: usize,
+) -> Result<(), RytmExternalError> {
+    handle_pattern_slot_get(rytm, atoms, PatternSlot::Stored(pattern_index))
+}
+
+/// Sets a parameter of a stored pattern or the work buffer pattern, or of the tracks and trigs which are selected in it.
+pub fn handle_pattern_slot_set(
+    rytm: &Rytm,
+    atoms: &[Atom],
+    slot: PatternSlot,
+) -> Result<(), RytmExternalError> {
+    let mut guard = rytm.project.lock_or_recover();
+    let first = slot.first_atom();
+
+    // Selectors of many tracks or trigs, a single track or trig is addressed like in the getters.
+    let track_is_index = matches!(
+        atoms.get(first).and_then(Atom::get_value),
+        Some(AtomValue::Int(_))
+    );
+    if IndexSelection::selects_many(atoms, first)
+        || (track_is_index && IndexSelection::selects_many(atoms, first + 1))
+    {
+        let tracks = IndexSelection::try_parse(atoms, first, TRACK_COUNT, "Track")?
+            .ok_or_else(|| slot.invalid_setter_format(atoms))?;
+        return set_pattern_selection(&mut guard, slot, &tracks, atoms);
+    }
+
+    let address = PatternAddress::try_parse(slot, atoms, PatternOperation::Set)?;
+    match address.element {
+        PatternElement::Pattern => {
+            let AtomValue::Symbol(action_or_enum_value) =
+                try_get_atom_value_assuming_identifier_or_index_or_enum_value(address.next, atoms)?
+            else {
+                return Err(slot.invalid_setter_format(atoms));
+            };
+            pattern_set(
+                action_or_enum_value,
+                slot.pattern_mut(&mut guard),
+                atoms,
+                address.next + 1,
+            )
+        }
+        PatternElement::Track(track_index) => set_pattern_elements(
+            &mut guard,
+            slot,
+            (&[track_index], None),
+            atoms,
+            address.next,
+        ),
+        PatternElement::Trig(track_index, trig_index) => set_pattern_elements(
+            &mut guard,
+            slot,
+            (&[track_index], Some(&[trig_index])),
+            atoms,
+            address.next,
+        ),
+    }
+}
+
+/// Gets a parameter of a stored pattern or the work buffer pattern, or of a track or a trig in it.
+pub fn handle_pattern_slot_get(
+    rytm: &Rytm,
+    atoms: &[Atom],
+    slot: PatternSlot,
+) -> Result<(), RytmExternalError> {
+    let mut guard = rytm.project.lock_or_recover();
+    let out = &rytm.query_out;
+
+    let address = PatternAddress::try_parse(slot, atoms, PatternOperation::Get)?;
+    let AtomValue::Symbol(action_or_enum_type) =
+        try_get_atom_value_assuming_identifier_or_index_or_enum_value(address.next, atoms)?
+    else {
+        return Err(slot.invalid_getter_format(atoms));
+    };
+    let action_or_enum_type_str = action_or_enum_type.to_string();
+    let is_plock = ALL_PLOCK_TYPES.contains(&action_or_enum_type_str.as_str());
+    if is_plock {
+        link_plock_sounds(&mut guard, slot.index())?;
+    }
+    let pattern = slot.pattern(&guard);
+
+    match address.element {
+        PatternElement::Pattern => pattern_get(action_or_enum_type, pattern, out),
+        PatternElement::Track(track_index) => {
+            track_get(action_or_enum_type, &pattern.tracks()[track_index], out)
+        }
+        PatternElement::Trig(track_index, trig_index) => {
+            let trig = &pattern.tracks()[track_index].trigs()[trig_index];
+
+            // Check if it is a plock action first
+            if is_plock {
+                let maybe_sound = get_plock_sound_for_track(&guard, slot.index(), track_index);
+                return handle_trig_plock_getter_action(
+                    trig,
+                    maybe_sound,
+                    &action_or_enum_type_str,
+                    atoms,
+                    address.next + 1,
+                    out,
+                );
+            }
+
+            trig_get(action_or_enum_type, trig, out)
+        }
+    }
+}
@@ -0,0 +1,12 @@
+use crate::atom::Atom;
+use crate::{address::PatternSlot, error::RytmExternalError, rytm::Rytm};
+
+use super::pattern::{handle_pattern_slot_get, handle_pattern_slot_set};
+
+pub fn handle_pattern_wb_set(rytm: &Rytm, atoms: &[Atom]) -> Result<(), RytmExternalError> {
+    handle_pattern_slot_set(rytm, atoms, PatternSlot::WorkBuffer)
+}
+
+pub fn handle_pattern_wb_get(rytm: &Rytm, atoms: &[Atom]) -> Result<(), RytmExternalError> {
+    handle_pattern_slot_get(rytm, atoms, PatternSlot::WorkBuffer)
+}
@@ -0,0 +1,70 @@
+use crate::atom::Atom;
+use crate::edit::encode_pattern_for_redecode;
+use crate::types::ObjectTypeSelector;
+use crate::util::LockOrRecover;
+use crate::{error::RytmExternalError, rytm::Rytm, util::try_get_path_from_atom_slice};
+use rytm_rs::prelude::*;
+
+pub fn handle_project_write(rytm: &Rytm, atoms: &[Atom]) -> Result<(), RytmExternalError> {
+    let path = try_get_path_from_atom_slice(0, atoms)?;
+    let json = serde_json::to_string(&*rytm.project.lock_or_recover())?;
+    std::fs::write(path, json)?;
+
+    Ok(())
+}
+
+pub fn handle_project_read(rytm: &Rytm, atoms: &[Atom]) -> Result<(), RytmExternalError> {
+    let path = try_get_path_from_atom_slice(0, atoms)?;
+    let (project, unlinked) = read_project(&path)?;
+
+    // Changes of the previous project can not be undone in the one which replaces it.
+    rytm.history.lock_or_recover().clear();
+    *rytm.project.lock_or_recover() = *project;
+
+    if unlinked > 0 {
+        return Err(format!(
+            "Project is read but parameter locks of {unlinked} objects could not be restored. Query them from the device to restore their parameter locks."
+        )
+        .into());
+    }
+
+    Ok(())
+}
+
+/// Reads a project written with `write` and returns it with the number of objects which could not be linked.
+pub(crate) fn read_project(
+    path: &std::path::Path,
+) -> Result<(Box<RytmProject>, usize), RytmExternalError> {
+    // Boxed since the project is too large for the stack.
+    let mut project: Box<RytmProject> = serde_json::from_str(&std::fs::read_to_string(path)?)?;
+
+    // A deserialized project does not have its trigs and kits linked to the parameter lock pools of the patterns.
+    // Updating the project with the patterns in the same way they're received from the device links them.
+    // The work buffer pattern comes first since patterns without a kit assigned use the kit of the work buffer pattern.
+    let mut responses = vec![encode_pattern_for_redecode(project.work_buffer().pattern())];
+    responses.extend(project.patterns().iter().map(encode_pattern_for_redecode));
+    responses.extend(
+        project
+            .work_buffer()
+            .sounds()
+            .iter()
+            .enumerate()
+            .map(|(index, sound)| {
+                // Encoded sounds carry the id of the sound pool so they're retargeted to the work buffer.
+                let mut sysex = sound.as_sysex()?;
+                ObjectTypeSelector::SoundWorkBuffer(index).retarget_sysex_response(&mut sysex)?;
+                Ok(sysex)
+            }),
+    );
+
+    let unlinked = responses
+        .into_iter()
+        .filter(|response| {
+            response.as_ref().map_or(true, |response| {
+                project.update_from_sysex_response(response).is_err()
+            })
+        })
+        .count();
+
+    Ok((project, unlinked))
+}
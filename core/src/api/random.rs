@@ -0,0 +1,140 @@
+use crate::atom::{Atom, AtomValue};
+use crate::util::LockOrRecover;
+use crate::{
+    api::{edit::sound_slot, kit_element_type, random_type::*},
+    edit::SoundSlot,
+    error::RytmExternalError,
+    live::LiveSnapshot,
+    random::{randomize, Random, RandomMode, RandomTarget},
+    rytm::Rytm,
+    selection::{IndexSelection, TRACK_COUNT},
+    types::ObjectTypeSelector,
+    util::string_from_atom_slice,
+};
+use std::convert::TryFrom;
+
+const FORMAT: &str = "randomize <target> [mutate <amount 0.0..=1.0>] <groups or identifiers> [seed <seed>] where the target is one of sound <index>, sound_wb <index>, kit <index> sound <sound-index>, kit_wb sound <sound-index>, kit <index>, kit_wb, pattern <index> <tracks> or pattern_wb <tracks>. Example: randomize sound_wb 0 amp filter seed 7";
+
+pub fn handle_randomize(rytm: &Rytm, atoms: &[Atom]) -> Result<(), RytmExternalError> {
+    let (target, next) = random_target(atoms)?;
+    let (mode, next) = random_mode(atoms, next)?;
+
+    let mut selectors = Vec::new();
+    let mut seed = None;
+    let mut index = next;
+    while let Some(atom) = atoms.get(index) {
+        let Some(AtomValue::Symbol(symbol)) = atom.get_value() else {
+            return Err(invalid_format(atoms));
+        };
+        let selector = symbol.to_string();
+        if selector == SEED {
+            let Some(AtomValue::Int(value)) = atoms.get(index + 1).and_then(Atom::get_value) else {
+                return Err(format!(
+                    "Invalid format: {}. seed should be followed by an integer.",
+                    string_from_atom_slice(atoms)
+                )
+                .into());
+            };
+            seed = Some(value as u64);
+            index += 2;
+            break;
+        }
+        selectors.push(selector);
+        index += 1;
+    }
+    if selectors.is_empty() || index < atoms.len() {
+        return Err(invalid_format(atoms));
+    }
+
+    let object = match &target {
+        RandomTarget::Sound(slot) => slot.object(),
+        RandomTarget::Kit(object) | RandomTarget::Tracks(object, _) => *object,
+    };
+    let run = || {
+        rytm.record_change(Rytm::SELECTOR_RANDOMIZE, atoms, &[object], || {
+            let mut random = rytm.random.lock_or_recover();
+            if let Some(seed) = seed {
+                *random = Random::new(seed);
+            }
+            randomize(
+                &mut rytm.project.lock_or_recover(),
+                &target,
+                &selectors,
+                mode,
+                &mut random,
+            )
+        })
+    };
+
+    match target {
+        RandomTarget::Sound(SoundSlot::WorkBuffer(index)) => {
+            rytm.set_with_live_output(|project| LiveSnapshot::sound(project, index), run)
+        }
+        RandomTarget::Kit(ObjectTypeSelector::KitWorkBuffer) => {
+            rytm.set_with_live_output(LiveSnapshot::kit, run)
+        }
+        _ => run(),
+    }
+}
+
+/// Parses the target of randomize and returns it with the index of the atom which follows it.
+fn random_target(atoms: &[Atom]) -> Result<(RandomTarget, usize), RytmExternalError> {
+    let object = ObjectTypeSelector::try_from((
+        atoms.first().ok_or_else(|| invalid_format(atoms))?,
+        atoms.get(1),
+    ))?;
+    let next = if object.indexable() { 2 } else { 1 };
+    let followed_by_sound = matches!(
+        atoms.get(next).and_then(Atom::get_value),
+        Some(AtomValue::Symbol(symbol)) if symbol.as_str() == kit_element_type::SOUND
+    );
+
+    match object {
+        ObjectTypeSelector::Kit(_) | ObjectTypeSelector::KitWorkBuffer if !followed_by_sound => {
+            Ok((RandomTarget::Kit(object), next))
+        }
+        ObjectTypeSelector::Sound(_)
+        | ObjectTypeSelector::SoundWorkBuffer(_)
+        | ObjectTypeSelector::Kit(_)
+        | ObjectTypeSelector::KitWorkBuffer => {
+            let (slot, next) = sound_slot(atoms, 0, FORMAT)?;
+            Ok((RandomTarget::Sound(slot), next))
+        }
+        ObjectTypeSelector::Pattern(_) | ObjectTypeSelector::PatternWorkBuffer => {
+            let tracks = IndexSelection::try_parse(atoms, next, TRACK_COUNT, "Track")?
+                .ok_or_else(|| invalid_format(atoms))?;
+            Ok((RandomTarget::Tracks(object, tracks.indices), tracks.next))
+        }
+        _ => Err(invalid_format(atoms)),
+    }
+}
+
+/// Parses the optional `mutate <amount>` and returns the mode with the index of the atom which follows it.
+fn random_mode(atoms: &[Atom], at: usize) -> Result<(RandomMode, usize), RytmExternalError> {
+    match atoms.get(at).and_then(Atom::get_value) {
+        Some(AtomValue::Symbol(symbol)) if symbol.to_string() == MUTATE => {
+            let amount = match atoms.get(at + 1).and_then(Atom::get_value) {
+                Some(AtomValue::Int(amount @ 0..=1)) => f64::from(amount as i32),
+                Some(AtomValue::Float(amount)) => amount,
+                _ => -1.0,
+            };
+            if !(0.0..=1.0).contains(&amount) {
+                return Err(format!(
+                    "Invalid format: {}. mutate should be followed by an amount between 0.0 and 1.0.",
+                    string_from_atom_slice(atoms)
+                )
+                .into());
+            }
+            Ok((RandomMode::Mutate(amount), at + 2))
+        }
+        _ => Ok((RandomMode::Randomize, at)),
+    }
+}
+
+fn invalid_format(atoms: &[Atom]) -> RytmExternalError {
+    format!(
+        "Invalid format: {}. The right format should be, {FORMAT}",
+        string_from_atom_slice(atoms)
+    )
+    .into()
+}
@@ -1,3 +1,5 @@
+use crate::atom::{Atom, AtomValue};
+use crate::util::LockOrRecover;
 use crate::{
     action::{
         get::settings::{handle_settings_get_action, handle_settings_get_enum_value},
@@ -7,16 +9,15 @@ use crate::{
     rytm::Rytm,
     util::{string_from_atom_slice, try_get_identifier_value_from_atom_slice},
 };
-use median::atom::{Atom, AtomValue};
 
 use crate::util::try_get_atom_value_assuming_identifier_or_index_or_enum_value;
 
 pub fn handle_settings_set(rytm: &Rytm, atoms: &[Atom]) -> Result<(), RytmExternalError> {
-    let mut guard = rytm.project.lock().unwrap();
+    let mut guard = rytm.project.lock_or_recover();
 
     match try_get_atom_value_assuming_identifier_or_index_or_enum_value(1, atoms)? {
         AtomValue::Symbol(action_or_enum_value) => {
-            let action_or_enum_value_str = action_or_enum_value.to_string()?;
+            let action_or_enum_value_str = action_or_enum_value.to_string();
             let enum_pair = action_or_enum_value_str.split_once(':');
 
             let settings_mut = guard.settings_mut();
@@ -33,11 +34,11 @@ pub fn handle_settings_set(rytm: &Rytm, atoms: &[Atom]) -> Result<(), RytmExtern
 }
 
 pub fn handle_settings_get(rytm: &Rytm, atoms: &[Atom]) -> Result<(), RytmExternalError> {
-    let guard = rytm.project.lock().unwrap();
+    let guard = rytm.project.lock_or_recover();
     let out = &rytm.query_out;
     match try_get_atom_value_assuming_identifier_or_index_or_enum_value(1, atoms)? {
         AtomValue::Symbol(action_or_enum_value) => {
-            let action_or_enum_value_str = action_or_enum_value.to_string()?;
+            let action_or_enum_value_str = action_or_enum_value.to_string();
             let enum_pair = action_or_enum_value_str.split_once(':');
 
             let settings = guard.settings();
@@ -1,6 +1,6 @@
-use median::{
+use crate::{
     atom::{Atom, AtomValue},
-    outlet::OutAnything,
+    output::Outlet,
 };
 use rytm_rs::object::Sound;
 
@@ -22,7 +22,7 @@ pub fn handle_sound_kit_set(
 ) -> Result<(), RytmExternalError> {
     match try_get_atom_value_assuming_identifier_or_index_or_enum_value(2, atoms)? {
         AtomValue::Symbol(action_or_enum_value) => {
-            let action_or_enum_value_str = action_or_enum_value.to_string()?;
+            let action_or_enum_value_str = action_or_enum_value.to_string();
             let enum_pair = action_or_enum_value_str.split_once(':');
 
             if let Some((enum_type, enum_value)) = enum_pair {
@@ -46,11 +46,11 @@ pub fn handle_sound_kit_set(
 pub fn handle_sound_kit_get(
     sound: &Sound,
     atoms: &[Atom],
-    out: &OutAnything,
+    out: &Outlet,
 ) -> Result<(), RytmExternalError> {
     match try_get_atom_value_assuming_identifier_or_index_or_enum_value(2, atoms)? {
         AtomValue::Symbol(action_or_enum_value) => {
-            let action_or_enum_value_str = action_or_enum_value.to_string()?;
+            let action_or_enum_value_str = action_or_enum_value.to_string();
             let enum_pair = action_or_enum_value_str.split_once(':');
 
             if let Some((enum_type, enum_value)) = enum_pair {
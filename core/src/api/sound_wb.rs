@@ -1,3 +1,5 @@
+use crate::atom::{Atom, AtomValue};
+use crate::util::LockOrRecover;
 use crate::{
     action::{
         get::sound::{handle_sound_get_action, handle_sound_get_enum_value},
@@ -7,7 +9,6 @@ use crate::{
     rytm::Rytm,
     util::{string_from_atom_slice, try_get_atom_value_assuming_identifier_or_index_or_enum_value},
 };
-use median::atom::{Atom, AtomValue};
 
 use crate::util::try_get_identifier_value_from_atom_slice;
 
@@ -16,14 +17,14 @@ pub fn handle_sound_wb_set(
     atoms: &[Atom],
     sound_index: usize,
 ) -> Result<(), RytmExternalError> {
-    let mut guard = rytm.project.lock().unwrap();
+    let mut guard = rytm.project.lock_or_recover();
 
     match try_get_atom_value_assuming_identifier_or_index_or_enum_value(2, atoms)? {
         AtomValue::Symbol(action_or_enum_value) => {
-            let action_or_enum_value_str = action_or_enum_value.to_string()?;
+            let action_or_enum_value_str = action_or_enum_value.to_string();
             let enum_pair = action_or_enum_value_str.split_once(':');
 
-            let sound_mut = &mut guard.pool_sounds_mut()[sound_index];
+            let sound_mut = &mut guard.work_buffer_mut().sounds_mut()[sound_index];
 
             if let Some((enum_type, enum_value)) = enum_pair {
                 let maybe_next_atom = atoms.get(3);
@@ -48,14 +49,14 @@ pub fn handle_sound_wb_get(
     atoms: &[Atom],
     sound_index: usize,
 ) -> Result<(), RytmExternalError> {
-    let guard = rytm.project.lock().unwrap();
+    let guard = rytm.project.lock_or_recover();
     let out = &rytm.query_out;
     match try_get_atom_value_assuming_identifier_or_index_or_enum_value(2, atoms)? {
         AtomValue::Symbol(action_or_enum_value) => {
-            let action_or_enum_value_str = action_or_enum_value.to_string()?;
+            let action_or_enum_value_str = action_or_enum_value.to_string();
             let enum_pair = action_or_enum_value_str.split_once(':');
 
-            let sound = &guard.pool_sounds()[sound_index];
+            let sound = &guard.work_buffer().sounds()[sound_index];
 
             if let Some((enum_type, enum_value)) = enum_pair {
                 handle_sound_get_enum_value(sound, enum_type, enum_value, out)
@@ -0,0 +1,73 @@
+use crate::atom::Atom;
+use crate::{
+    error::RytmExternalError, rytm::Rytm, types::ObjectTypeSelector,
+    util::try_get_path_from_atom_slice,
+};
+use std::convert::TryFrom;
+
+const SYSEX_START: u8 = 0xF0;
+const SYSEX_END: u8 = 0xF7;
+
+pub fn handle_object_export(rytm: &Rytm, atoms: &[Atom]) -> Result<(), RytmExternalError> {
+    let (object, path_index) = object_and_path_index(atoms)?;
+    let path = try_get_path_from_atom_slice(path_index, atoms)?;
+
+    std::fs::write(path, rytm.object_sysex(object)?)?;
+
+    Ok(())
+}
+
+pub fn handle_object_import(rytm: &Rytm, atoms: &[Atom]) -> Result<(), RytmExternalError> {
+    let (object, path_index) = object_and_path_index(atoms)?;
+    let path = try_get_path_from_atom_slice(path_index, atoms)?;
+    let bytes = std::fs::read(path)?;
+
+    // Messages following the first one are loaded to the following objects, e.g. a file of 3 sounds imported to sound 5 updates sounds 5, 6 and 7.
+    let mut responses = split_sysex_messages(&bytes)?;
+    for (offset, response) in responses.iter_mut().enumerate() {
+        object
+            .offset_by(offset)?
+            .retarget_sysex_response(response)?;
+    }
+
+    let objects = (0..responses.len())
+        .map(|offset| object.offset_by(offset))
+        .collect::<Result<Vec<_>, _>>()?;
+    // Responses are decoded to a copy of the project so an invalid file doesn't leave the project half updated.
+    rytm.record_change(Rytm::SELECTOR_IMPORT, atoms, &objects, || {
+        rytm.apply_sysex_responses(&responses)
+    })
+}
+
+/// Parses `<object-type> [<index>]` and returns the object with the index of the atom which follows it.
+fn object_and_path_index(atoms: &[Atom]) -> Result<(ObjectTypeSelector, usize), RytmExternalError> {
+    let object = ObjectTypeSelector::try_from((
+        atoms.first().ok_or(
+            "Invalid format: The right format should be, <object-type> [<index>] <path>. Example: export kit 0 /path/to/kit.syx",
+        )?,
+        atoms.get(1),
+    ))?;
+
+    Ok((object, if object.indexable() { 2 } else { 1 }))
+}
+
+/// Splits the contents of a `.syx` file to the sysex messages in it.
+fn split_sysex_messages(bytes: &[u8]) -> Result<Vec<Vec<u8>>, RytmExternalError> {
+    let mut messages = Vec::new();
+    let mut rest = bytes;
+
+    while let Some(start) = rest.iter().position(|&byte| byte == SYSEX_START) {
+        let end = rest[start..]
+            .iter()
+            .position(|&byte| byte == SYSEX_END)
+            .ok_or("Invalid sysex file: The last message in the file is incomplete.")?;
+        messages.push(rest[start..=start + end].to_vec());
+        rest = &rest[start + end + 1..];
+    }
+
+    if messages.is_empty() {
+        return Err("Invalid sysex file: The file does not contain any sysex messages.".into());
+    }
+
+    Ok(messages)
+}
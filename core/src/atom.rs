@@ -0,0 +1,168 @@
+//! Atoms and symbols which messages are made of.
+//!
+//! They follow the atoms of Max so the parsers read the same as they would against Max,
+//! the host converts its own atoms to these and back at its edges.
+
+use std::fmt;
+use std::sync::Arc;
+
+/// A cheaply cloned piece of text, e.g. a selector, an identifier or a name.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct SymbolRef(Arc<str>);
+
+impl SymbolRef {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<&str> for SymbolRef {
+    fn from(symbol: &str) -> Self {
+        Self(symbol.into())
+    }
+}
+
+impl From<String> for SymbolRef {
+    fn from(symbol: String) -> Self {
+        Self(symbol.into())
+    }
+}
+
+impl fmt::Display for SymbolRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AtomType {
+    Int,
+    Float,
+    Symbol,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum AtomValue {
+    Int(isize),
+    Float(f64),
+    Symbol(SymbolRef),
+}
+
+/// A single element of a message.
+///
+/// An atom without a value stands for an atom of the host which rytm does not understand, like an object.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Atom {
+    value: Option<AtomValue>,
+}
+
+impl Atom {
+    /// Reads a token of a plain text message, numbers become ints or floats and anything else a symbol.
+    pub fn parse(token: &str) -> Self {
+        if let Ok(value) = token.parse::<isize>() {
+            return Self::from(value);
+        }
+        // Words like `inf` or `nan` are symbols in a message.
+        match token.parse::<f64>() {
+            Ok(value) if token.chars().any(|c| c.is_ascii_digit()) => Self::from(value),
+            _ => Self::from(SymbolRef::from(token)),
+        }
+    }
+
+    /// Reads a plain text message like `get kit 3 name` to atoms.
+    pub fn parse_list(message: &str) -> Vec<Self> {
+        message.split_whitespace().map(Self::parse).collect()
+    }
+
+    pub fn get_type(&self) -> Option<AtomType> {
+        self.value.as_ref().map(|value| match value {
+            AtomValue::Int(_) => AtomType::Int,
+            AtomValue::Float(_) => AtomType::Float,
+            AtomValue::Symbol(_) => AtomType::Symbol,
+        })
+    }
+
+    pub fn get_value(&self) -> Option<AtomValue> {
+        self.value.clone()
+    }
+
+    /// The value as an int, floats are truncated and anything else is 0.
+    pub const fn get_int(&self) -> isize {
+        match self.value {
+            Some(AtomValue::Int(value)) => value,
+            Some(AtomValue::Float(value)) => value as isize,
+            _ => 0,
+        }
+    }
+
+    /// The value as a float, anything other than a number is 0.0.
+    #[allow(clippy::cast_precision_loss)]
+    pub const fn get_float(&self) -> f64 {
+        match self.value {
+            Some(AtomValue::Int(value)) => value as f64,
+            Some(AtomValue::Float(value)) => value,
+            _ => 0.0,
+        }
+    }
+
+    /// The value as a symbol, anything other than a symbol is an empty symbol.
+    pub fn get_symbol(&self) -> SymbolRef {
+        match &self.value {
+            Some(AtomValue::Symbol(symbol)) => symbol.clone(),
+            _ => SymbolRef::default(),
+        }
+    }
+}
+
+impl fmt::Display for Atom {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.value {
+            Some(AtomValue::Int(value)) => write!(f, "{value}"),
+            Some(AtomValue::Float(value)) => write!(f, "{value}"),
+            Some(AtomValue::Symbol(symbol)) => write!(f, "{symbol}"),
+            None => Ok(()),
+        }
+    }
+}
+
+impl From<AtomValue> for Atom {
+    fn from(value: AtomValue) -> Self {
+        Self { value: Some(value) }
+    }
+}
+
+impl From<SymbolRef> for Atom {
+    fn from(symbol: SymbolRef) -> Self {
+        Self::from(AtomValue::Symbol(symbol))
+    }
+}
+
+impl From<isize> for Atom {
+    fn from(value: isize) -> Self {
+        Self::from(AtomValue::Int(value))
+    }
+}
+
+impl From<i64> for Atom {
+    fn from(value: i64) -> Self {
+        Self::from(AtomValue::Int(value as isize))
+    }
+}
+
+impl From<i32> for Atom {
+    fn from(value: i32) -> Self {
+        Self::from(AtomValue::Int(value as isize))
+    }
+}
+
+impl From<f64> for Atom {
+    fn from(value: f64) -> Self {
+        Self::from(AtomValue::Float(value))
+    }
+}
+
+impl From<f32> for Atom {
+    fn from(value: f32) -> Self {
+        Self::from(AtomValue::Float(f64::from(value)))
+    }
+}
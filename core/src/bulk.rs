@@ -0,0 +1,97 @@
+use crate::api::bulk_target::*;
+use crate::error::RytmExternalError;
+use crate::types::ObjectTypeSelector;
+use std::collections::VecDeque;
+
+const PATTERN_COUNT: usize = 128;
+const KIT_COUNT: usize = 128;
+const POOL_SOUND_COUNT: usize = 128;
+const WORK_BUFFER_SOUND_COUNT: usize = 12;
+const GLOBAL_COUNT: usize = 4;
+
+#[derive(Debug, Copy, Clone)]
+pub enum BulkOperation {
+    Query,
+    Send,
+}
+
+impl BulkOperation {
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Query => "query",
+            Self::Send => "send",
+        }
+    }
+}
+
+/// Every object of a project, in the order they're queried or sent.
+pub fn all_objects() -> impl Iterator<Item = ObjectTypeSelector> {
+    std::iter::once(ObjectTypeSelector::Settings)
+        .chain((0..GLOBAL_COUNT).map(ObjectTypeSelector::Global))
+        .chain((0..POOL_SOUND_COUNT).map(ObjectTypeSelector::Sound))
+        .chain((0..KIT_COUNT).map(ObjectTypeSelector::Kit))
+        .chain((0..PATTERN_COUNT).map(ObjectTypeSelector::Pattern))
+        .chain(work_buffer())
+}
+
+fn work_buffer() -> impl Iterator<Item = ObjectTypeSelector> {
+    std::iter::once(ObjectTypeSelector::GlobalWorkBuffer)
+        .chain((0..WORK_BUFFER_SOUND_COUNT).map(ObjectTypeSelector::SoundWorkBuffer))
+        .chain(std::iter::once(ObjectTypeSelector::KitWorkBuffer))
+        .chain(std::iter::once(ObjectTypeSelector::PatternWorkBuffer))
+}
+
+/// A list of objects which are queried or sent one by one with a spacing in between.
+#[derive(Debug)]
+pub struct BulkJob {
+    operation: BulkOperation,
+    pending: VecDeque<ObjectTypeSelector>,
+    total: usize,
+}
+
+impl BulkJob {
+    pub fn new(operation: BulkOperation, target: &str) -> Result<Self, RytmExternalError> {
+        let pending: VecDeque<ObjectTypeSelector> = match target {
+            ALL => all_objects().collect(),
+            PATTERNS => (0..PATTERN_COUNT)
+                .map(ObjectTypeSelector::Pattern)
+                .collect(),
+            KITS => (0..KIT_COUNT).map(ObjectTypeSelector::Kit).collect(),
+            SOUNDS => (0..POOL_SOUND_COUNT)
+                .map(ObjectTypeSelector::Sound)
+                .collect(),
+            GLOBALS => (0..GLOBAL_COUNT).map(ObjectTypeSelector::Global).collect(),
+            WORK_BUFFER => work_buffer().collect(),
+            other => {
+                return Err(format!(
+                    "Invalid bulk target: {other}. Possible targets are {}.",
+                    BULK_TARGETS.join(", ")
+                )
+                .into())
+            }
+        };
+
+        Ok(Self {
+            operation,
+            total: pending.len(),
+            pending,
+        })
+    }
+
+    pub const fn operation(&self) -> BulkOperation {
+        self.operation
+    }
+
+    pub fn next_object(&mut self) -> Option<ObjectTypeSelector> {
+        self.pending.pop_front()
+    }
+
+    /// Returns the count of the processed objects and the total count of the objects.
+    pub fn progress(&self) -> (usize, usize) {
+        (self.total - self.pending.len(), self.total)
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.pending.is_empty()
+    }
+}
@@ -0,0 +1,174 @@
+use crate::api::{
+    kit_element_type, pattern_action_type, plock_type, sound_action_type, track_action_type,
+};
+use crate::bulk::all_objects;
+use crate::dump::dump_object;
+use crate::error::RytmExternalError;
+use crate::types::ObjectTypeSelector;
+use rytm_rs::prelude::*;
+use serde_json::Value;
+
+/// Identifiers which tell where an object is kept rather than what it is, they differ between any two objects.
+const LOCATION_IDENTIFIERS: &[&str] = &[
+    pattern_action_type::INDEX,
+    pattern_action_type::IS_WORK_BUFFER,
+    track_action_type::OWNER_INDEX,
+    sound_action_type::IS_POOL,
+    sound_action_type::IS_KIT,
+];
+
+/// A part of the path of a differing parameter, in the order `get` expects them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PathSegment {
+    Identifier(String),
+    Index(usize),
+}
+
+/// A parameter which differs between two objects, `None` when one of them doesn't have it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Difference {
+    pub path: Vec<PathSegment>,
+    pub from: Option<Value>,
+    pub to: Option<Value>,
+}
+
+/// Lists the parameters which differ between two objects of the same type in a project.
+///
+/// Objects are compared through their dumps so the paths use the same identifiers as `get`.
+pub fn diff_objects(
+    project: &mut RytmProject,
+    from: ObjectTypeSelector,
+    to: ObjectTypeSelector,
+) -> Result<Vec<Difference>, RytmExternalError> {
+    if from.sysex_type() != to.sysex_type() {
+        return Err(format!(
+            "Invalid diff: Only objects of the same type can be compared, {} and {} are different types.",
+            from.object_type(),
+            to.object_type()
+        )
+        .into());
+    }
+
+    // Dumped one after the other since dumping a pattern links its sounds to it.
+    let from = dump_object(project, from)?;
+    Ok(diff_dumps(&from, &dump_object(project, to)?))
+}
+
+/// Lists the parameters which differ between two dumps of objects of the same type.
+pub fn diff_dumps(from: &Value, to: &Value) -> Vec<Difference> {
+    let mut differences = Vec::new();
+    diff_values(from, to, &mut Vec::new(), &mut differences);
+    differences
+}
+
+/// Lists the parameters of every object which differ between two projects.
+///
+/// Objects which serialize the same are skipped without dumping them.
+pub fn diff_projects(
+    from_project: &mut RytmProject,
+    to_project: &mut RytmProject,
+) -> Result<Vec<(ObjectTypeSelector, Difference)>, RytmExternalError> {
+    let mut differences = Vec::new();
+    for object in all_objects() {
+        if serialize_object(from_project, object)? == serialize_object(to_project, object)? {
+            continue;
+        }
+        let differences_of_object = diff_dumps(
+            &dump_object(from_project, object)?,
+            &dump_object(to_project, object)?,
+        );
+        differences.extend(
+            differences_of_object
+                .into_iter()
+                .map(|difference| (object, difference)),
+        );
+    }
+
+    Ok(differences)
+}
+
+fn serialize_object(
+    project: &RytmProject,
+    object: ObjectTypeSelector,
+) -> Result<Vec<u8>, RytmExternalError> {
+    let serialized = match object {
+        ObjectTypeSelector::Pattern(index) => serde_json::to_vec(&project.patterns()[index]),
+        ObjectTypeSelector::PatternWorkBuffer => {
+            serde_json::to_vec(project.work_buffer().pattern())
+        }
+        ObjectTypeSelector::Kit(index) => serde_json::to_vec(&project.kits()[index]),
+        ObjectTypeSelector::KitWorkBuffer => serde_json::to_vec(project.work_buffer().kit()),
+        ObjectTypeSelector::Sound(index) => serde_json::to_vec(&project.pool_sounds()[index]),
+        ObjectTypeSelector::SoundWorkBuffer(index) => {
+            serde_json::to_vec(&project.work_buffer().sounds()[index])
+        }
+        ObjectTypeSelector::Global(index) => serde_json::to_vec(&project.globals()[index]),
+        ObjectTypeSelector::GlobalWorkBuffer => serde_json::to_vec(project.work_buffer().global()),
+        ObjectTypeSelector::Settings => serde_json::to_vec(project.settings()),
+    };
+
+    Ok(serialized?)
+}
+
+fn diff_values(
+    from: &Value,
+    to: &Value,
+    path: &mut Vec<PathSegment>,
+    differences: &mut Vec<Difference>,
+) {
+    match (from, to) {
+        (Value::Object(from_fields), Value::Object(to_fields)) => {
+            let mut keys = from_fields
+                .keys()
+                .chain(to_fields.keys())
+                .collect::<Vec<_>>();
+            keys.sort();
+            keys.dedup();
+
+            for key in keys {
+                if LOCATION_IDENTIFIERS.contains(&key.as_str()) {
+                    continue;
+                }
+                let pushed = push_key(path, key);
+                match (from_fields.get(key), to_fields.get(key)) {
+                    (Some(from), Some(to)) => diff_values(from, to, path, differences),
+                    (from, to) => differences.push(Difference {
+                        path: path.clone(),
+                        from: from.cloned(),
+                        to: to.cloned(),
+                    }),
+                }
+                path.truncate(path.len() - pushed);
+            }
+        }
+        (Value::Array(from_values), Value::Array(to_values))
+            if from_values.len() == to_values.len() =>
+        {
+            for (index, (from, to)) in from_values.iter().zip(to_values).enumerate() {
+                path.push(PathSegment::Index(index));
+                diff_values(from, to, path, differences);
+                path.pop();
+            }
+        }
+        _ if from != to => differences.push(Difference {
+            path: path.clone(),
+            from: Some(from.clone()),
+            to: Some(to.clone()),
+        }),
+        _ => {}
+    }
+}
+
+/// Pushes the identifier which `get` expects for a key of a dump and returns how many segments are pushed.
+///
+/// Tracks and trigs are addressed only by their index, kit sounds with `sound` and parameter locks with `plockget`.
+fn push_key(path: &mut Vec<PathSegment>, key: &str) -> usize {
+    let identifier = match key {
+        "tracks" | "trigs" => return 0,
+        "sounds" => kit_element_type::SOUND,
+        "plocks" => plock_type::PLOCK_GET,
+        _ => key,
+    };
+    path.push(PathSegment::Identifier(identifier.to_owned()));
+    1
+}
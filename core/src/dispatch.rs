@@ -0,0 +1,74 @@
+//! Pairs every object type with the handlers of its `get` and `set`.
+//!
+//! Both messages are routed from this table so a getter and a setter of an object type can not drift apart.
+
+use crate::api::{global, global_wb, kit, kit_wb, pattern, pattern_wb, settings, sound, sound_wb};
+use crate::atom::Atom;
+use crate::error::RytmExternalError;
+use crate::live::LiveSnapshot;
+use crate::rytm::Rytm;
+use crate::types::ObjectTypeSelector;
+
+/// Handles a `get` or a `set` with the atoms following the selector and the index of the object.
+///
+/// Objects which are not indexable receive index 0.
+pub type Handler = fn(&Rytm, &[Atom], usize) -> Result<(), RytmExternalError>;
+
+/// The handlers of `get` and `set` for an object type.
+#[derive(Copy, Clone)]
+pub struct ObjectHandlers {
+    pub get: Handler,
+    pub set: Handler,
+}
+
+impl ObjectTypeSelector {
+    pub fn handlers(&self) -> ObjectHandlers {
+        match self {
+            Self::Pattern(_) => ObjectHandlers {
+                get: pattern::handle_pattern_get,
+                set: pattern::handle_pattern_set,
+            },
+            Self::PatternWorkBuffer => ObjectHandlers {
+                get: |rytm, atoms, _| pattern_wb::handle_pattern_wb_get(rytm, atoms),
+                set: |rytm, atoms, _| pattern_wb::handle_pattern_wb_set(rytm, atoms),
+            },
+            Self::Kit(_) => ObjectHandlers {
+                get: kit::handle_kit_get,
+                set: kit::handle_kit_set,
+            },
+            Self::KitWorkBuffer => ObjectHandlers {
+                get: |rytm, atoms, _| kit_wb::handle_kit_wb_get(rytm, atoms),
+                set: |rytm, atoms, _| {
+                    rytm.set_with_live_output(LiveSnapshot::kit, || {
+                        kit_wb::handle_kit_wb_set(rytm, atoms)
+                    })
+                },
+            },
+            Self::Sound(_) => ObjectHandlers {
+                get: sound::handle_sound_get,
+                set: sound::handle_sound_set,
+            },
+            Self::SoundWorkBuffer(_) => ObjectHandlers {
+                get: sound_wb::handle_sound_wb_get,
+                set: |rytm, atoms, index| {
+                    rytm.set_with_live_output(
+                        |project| LiveSnapshot::sound(project, index),
+                        || sound_wb::handle_sound_wb_set(rytm, atoms, index),
+                    )
+                },
+            },
+            Self::Global(_) => ObjectHandlers {
+                get: global::handle_global_get,
+                set: global::handle_global_set,
+            },
+            Self::GlobalWorkBuffer => ObjectHandlers {
+                get: |rytm, atoms, _| global_wb::handle_global_wb_get(rytm, atoms),
+                set: |rytm, atoms, _| global_wb::handle_global_wb_set(rytm, atoms),
+            },
+            Self::Settings => ObjectHandlers {
+                get: |rytm, atoms, _| settings::handle_settings_get(rytm, atoms),
+                set: |rytm, atoms, _| settings::handle_settings_set(rytm, atoms),
+            },
+        }
+    }
+}
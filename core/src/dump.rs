@@ -0,0 +1,427 @@
+use crate::action::get::{
+    global::{global_get_enum_value, global_get_value},
+    kit::{kit_get_element_value, kit_get_enum_value, kit_get_value},
+    machine::{machine_get_enum_value, machine_get_value},
+    pattern::{pattern_get_enum_value, pattern_get_value},
+    settings::{settings_get_enum_value, settings_get_value},
+    sound::{sound_get_enum_value, sound_get_value},
+    track::{track_get_enum_value, track_get_value},
+    trig::{trig_get_enum_value, trig_get_value},
+};
+use crate::action::machine::machine_parameter_types_of;
+use crate::action::plock::{
+    get::{trig_plock_get_enum_value, trig_plock_get_value},
+    machine::{trig_plock_get_machine_enum_value, trig_plock_get_machine_value},
+};
+use crate::api::{
+    global_action_type::{self, GLOBAL_ACTION_TYPES, GLOBAL_READ_ONLY_ACTION_TYPES},
+    global_enum_type::{self, GLOBAL_ENUM_TYPES},
+    kit_action_type::{self, KIT_ACTION_TYPES, KIT_READ_ONLY_ACTION_TYPES},
+    kit_element_type::{self, KIT_ELEMENTS},
+    kit_enum_type::{self, KIT_ENUM_TYPES},
+    pattern_action_type::{PATTERN_ACTION_TYPES, PATTERN_READ_ONLY_ACTION_TYPES},
+    pattern_enum_type::PATTERN_ENUM_TYPES,
+    settings_action_type::{self, SETTINGS_ACTION_TYPES, SETTINGS_READ_ONLY_ACTION_TYPES},
+    settings_enum_type::SETTINGS_ENUM_TYPES,
+    sound_action_type::{self, SOUND_ACTION_TYPES, SOUND_READ_ONLY_ACTION_TYPES},
+    sound_enum_type::{self, SOUND_ENUM_TYPES},
+    sound_machine_enum_type::SOUND_MACHINE_ENUM_TYPES,
+    track_action_type::{TRACK_ACTION_TYPES, TRACK_READ_ONLY_ACTION_TYPES},
+    track_enum_type::TRACK_ENUM_TYPES,
+    trig_action_type::{self, TRIG_ACTION_TYPES},
+    trig_enum_type::TRIG_ENUM_TYPES,
+};
+use crate::atom::{Atom, AtomValue};
+use crate::edit::locked_trigs;
+use crate::error::{EnumError, IdentifierError, RytmExternalError};
+use crate::types::ObjectTypeSelector;
+use crate::util::{get_plock_sound_for_track, link_plock_sounds};
+use rytm_rs::object::{
+    pattern::{track::Track, Trig},
+    Global, Kit, Pattern, Settings, Sound,
+};
+use rytm_rs::prelude::*;
+use serde_json::{Map, Value};
+
+// Identifiers which are followed by an index in `get` are dumped as arrays of these lengths.
+const MODULATION_SLOT_COUNT: usize = 4;
+const SOUND_TRACK_COUNT: usize = 12;
+// Kit elements also include the fx track.
+const KIT_TRACK_COUNT: usize = 13;
+// Velocity and sound lock of a trig read this until they are set, it is not a value which can be set.
+const UNSET_TRIG_VALUE: isize = 255;
+
+type Fields = Map<String, Value>;
+
+/// Builds a nested json object of every identifier and enum which `get` exposes for an object.
+///
+/// Keys are the identifiers and enum types used in `get`, values are what `get` would output for them.
+/// A pattern is linked to the sounds which its machine parameter locks are reached through before it is dumped.
+pub fn dump_object(
+    project: &mut RytmProject,
+    object: ObjectTypeSelector,
+) -> Result<Value, RytmExternalError> {
+    match object {
+        ObjectTypeSelector::Pattern(index) => {
+            link_plock_sounds(project, Some(index))?;
+            dump_pattern(project, &project.patterns()[index])
+        }
+        ObjectTypeSelector::PatternWorkBuffer => {
+            link_plock_sounds(project, None)?;
+            dump_pattern(project, project.work_buffer().pattern())
+        }
+        ObjectTypeSelector::Kit(index) => dump_kit(&project.kits()[index]),
+        ObjectTypeSelector::KitWorkBuffer => dump_kit(project.work_buffer().kit()),
+        ObjectTypeSelector::Sound(index) => dump_sound(&project.pool_sounds()[index]),
+        ObjectTypeSelector::SoundWorkBuffer(index) => {
+            dump_sound(&project.work_buffer().sounds()[index])
+        }
+        ObjectTypeSelector::Global(index) => dump_global(&project.globals()[index]),
+        ObjectTypeSelector::GlobalWorkBuffer => dump_global(project.work_buffer().global()),
+        ObjectTypeSelector::Settings => dump_settings(project.settings()),
+    }
+}
+
+fn dump_settings(settings: &Settings) -> Result<Value, RytmExternalError> {
+    let mut fields = Fields::new();
+
+    for &action in SETTINGS_READ_ONLY_ACTION_TYPES
+        .iter()
+        .chain(SETTINGS_ACTION_TYPES)
+    {
+        let value = if action == settings_action_type::MUTE {
+            indexed(SOUND_TRACK_COUNT, |index| {
+                Ok(atom_to_json(&settings_get_value(
+                    settings,
+                    action,
+                    Some(&index_atom(index)),
+                )?))
+            })
+        } else {
+            settings_get_value(settings, action, None).map(|atom| atom_to_json(&atom))
+        };
+        insert_if_supported(&mut fields, action, value)?;
+    }
+
+    for &enum_type in SETTINGS_ENUM_TYPES {
+        let value = settings_get_enum_value(settings, enum_type).map(Value::from);
+        insert_if_supported(&mut fields, enum_type, value)?;
+    }
+
+    Ok(Value::Object(fields))
+}
+
+fn dump_global(global: &Global) -> Result<Value, RytmExternalError> {
+    let mut fields = Fields::new();
+
+    for &action in GLOBAL_READ_ONLY_ACTION_TYPES
+        .iter()
+        .chain(GLOBAL_ACTION_TYPES)
+    {
+        let value = match action {
+            global_action_type::ROUTE_TO_MAIN | global_action_type::SEND_TO_FX => {
+                indexed(SOUND_TRACK_COUNT, |index| {
+                    Ok(global_get_value(global, action, Some(&index_atom(index)))?.into())
+                })
+            }
+            _ => global_get_value(global, action, None).map(Value::from),
+        };
+        insert_if_supported(&mut fields, action, value)?;
+    }
+
+    for &enum_type in GLOBAL_ENUM_TYPES {
+        let value = if enum_type == global_enum_type::TRACK_CHANNELS {
+            indexed(SOUND_TRACK_COUNT, |index| {
+                Ok(global_get_enum_value(global, enum_type, &index.to_string())?.into())
+            })
+        } else {
+            global_get_enum_value(global, enum_type, "").map(Value::from)
+        };
+        insert_if_supported(&mut fields, enum_type, value)?;
+    }
+
+    Ok(Value::Object(fields))
+}
+
+pub(crate) fn dump_kit(kit: &Kit) -> Result<Value, RytmExternalError> {
+    let mut fields = Fields::new();
+
+    for &action in KIT_READ_ONLY_ACTION_TYPES.iter().chain(KIT_ACTION_TYPES) {
+        let value = match action {
+            kit_action_type::CONTROL_IN_1_MOD_AMT | kit_action_type::CONTROL_IN_2_MOD_AMT => {
+                indexed(MODULATION_SLOT_COUNT, |index| {
+                    Ok(atom_to_json(&kit_get_value(
+                        kit,
+                        action,
+                        Some(&index_atom(index)),
+                    )?))
+                })
+            }
+            _ => kit_get_value(kit, action, None).map(|atom| atom_to_json(&atom)),
+        };
+        insert_if_supported(&mut fields, action, value)?;
+    }
+
+    for &enum_type in KIT_ENUM_TYPES {
+        let value = match enum_type {
+            kit_enum_type::CONTROL_IN_1_MOD_TARGET | kit_enum_type::CONTROL_IN_2_MOD_TARGET => {
+                indexed(MODULATION_SLOT_COUNT, |index| {
+                    Ok(kit_get_enum_value(kit, enum_type, &index.to_string())?.into())
+                })
+            }
+            _ => kit_get_enum_value(kit, enum_type, "").map(Value::from),
+        };
+        insert_if_supported(&mut fields, enum_type, value)?;
+    }
+
+    for &element_type in KIT_ELEMENTS {
+        if element_type == kit_element_type::SOUND {
+            continue;
+        }
+        let value = indexed(KIT_TRACK_COUNT, |index| {
+            Ok(atom_to_json(&kit_get_element_value(
+                kit,
+                element_type,
+                index,
+            )?))
+        });
+        insert_if_supported(&mut fields, element_type, value)?;
+    }
+
+    let sounds = kit
+        .sounds()
+        .iter()
+        .map(dump_sound)
+        .collect::<Result<Vec<_>, _>>()?;
+    fields.insert("sounds".to_owned(), Value::Array(sounds));
+
+    Ok(Value::Object(fields))
+}
+
+pub(crate) fn dump_sound(sound: &Sound) -> Result<Value, RytmExternalError> {
+    let mut fields = Fields::new();
+
+    for &action in SOUND_READ_ONLY_ACTION_TYPES
+        .iter()
+        .chain(SOUND_ACTION_TYPES)
+    {
+        let value = match action {
+            sound_action_type::VEL_MOD_AMT | sound_action_type::AT_MOD_AMT => {
+                indexed(MODULATION_SLOT_COUNT, |index| {
+                    Ok(atom_to_json(&sound_get_value(
+                        sound,
+                        action,
+                        Some(&index_atom(index)),
+                    )?))
+                })
+            }
+            _ => sound_get_value(sound, action, None).map(|atom| atom_to_json(&atom)),
+        };
+        insert_if_supported(&mut fields, action, value)?;
+    }
+
+    for &enum_type in SOUND_ENUM_TYPES {
+        let value = match enum_type {
+            sound_enum_type::VELOCITY_MOD_TARGET | sound_enum_type::AFTER_TOUCH_MOD_TARGET => {
+                indexed(MODULATION_SLOT_COUNT, |index| {
+                    Ok(sound_get_enum_value(sound, enum_type, &index.to_string())?.into())
+                })
+            }
+            _ => sound_get_enum_value(sound, enum_type, "").map(Value::from),
+        };
+        insert_if_supported(&mut fields, enum_type, value)?;
+    }
+
+    for &parameter in machine_parameter_types_of(sound.machine_type()) {
+        let value = if SOUND_MACHINE_ENUM_TYPES.contains(&parameter) {
+            machine_get_enum_value(sound, parameter).map(Value::from)
+        } else {
+            machine_get_value(sound, parameter).map(|atom| atom_to_json(&atom))
+        };
+        insert_if_supported(&mut fields, parameter, value)?;
+    }
+
+    Ok(Value::Object(fields))
+}
+
+fn dump_pattern(project: &RytmProject, pattern: &Pattern) -> Result<Value, RytmExternalError> {
+    let mut fields = Fields::new();
+
+    for &action in PATTERN_READ_ONLY_ACTION_TYPES
+        .iter()
+        .chain(PATTERN_ACTION_TYPES)
+    {
+        let value = pattern_get_value(pattern, action).map(|atom| atom_to_json(&atom));
+        insert_if_supported(&mut fields, action, value)?;
+    }
+
+    for &enum_type in PATTERN_ENUM_TYPES {
+        let value = pattern_get_enum_value(pattern, enum_type).map(Value::from);
+        insert_if_supported(&mut fields, enum_type, value)?;
+    }
+
+    // Machine parameter locks are reached through the sounds which the pattern is linked to.
+    let maybe_pattern_index = (!pattern.is_work_buffer_pattern()).then(|| pattern.index());
+    // Looking up every parameter lock of every trig is slow, only trigs which have any are looked up.
+    let locked_trigs = locked_trigs(pattern)?;
+    let tracks = pattern
+        .tracks()
+        .iter()
+        .map(|track| {
+            let sound = get_plock_sound_for_track(project, maybe_pattern_index, track.index());
+            dump_track(track, sound, &locked_trigs[track.index()])
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    fields.insert("tracks".to_owned(), Value::Array(tracks));
+
+    Ok(Value::Object(fields))
+}
+
+fn dump_track(
+    track: &Track,
+    sound: Option<&Sound>,
+    locked_trigs: &[bool],
+) -> Result<Value, RytmExternalError> {
+    let mut fields = Fields::new();
+
+    for &action in TRACK_READ_ONLY_ACTION_TYPES
+        .iter()
+        .chain(TRACK_ACTION_TYPES)
+    {
+        let value = track_get_value(track, action).map(Value::from);
+        insert_if_supported(&mut fields, action, value)?;
+    }
+
+    for &enum_type in TRACK_ENUM_TYPES {
+        let value = track_get_enum_value(track, enum_type).map(Value::from);
+        insert_if_supported(&mut fields, enum_type, value)?;
+    }
+
+    let trigs = track
+        .trigs()
+        .iter()
+        .map(|trig| dump_trig(trig, sound, locked_trigs[trig.index()]))
+        .collect::<Result<Vec<_>, _>>()?;
+    fields.insert("trigs".to_owned(), Value::Array(trigs));
+
+    Ok(Value::Object(fields))
+}
+
+fn dump_trig(trig: &Trig, sound: Option<&Sound>, locked: bool) -> Result<Value, RytmExternalError> {
+    let mut fields = Fields::new();
+
+    for &action in TRIG_ACTION_TYPES {
+        let value = trig_get_value(trig, action);
+        let unset = matches!(
+            action,
+            trig_action_type::VELOCITY | trig_action_type::SOUND_LOCK
+        ) && matches!(value, Ok(UNSET_TRIG_VALUE));
+        // They are left out so the dump can be loaded back.
+        if unset {
+            continue;
+        }
+        insert_if_supported(&mut fields, action, value.map(Value::from))?;
+    }
+
+    for &enum_type in TRIG_ENUM_TYPES {
+        let value = trig_get_enum_value(trig, enum_type).map(Value::from);
+        insert_if_supported(&mut fields, enum_type, value)?;
+    }
+
+    let plocks = if locked {
+        dump_trig_plocks(trig, sound)?
+    } else {
+        Value::Object(Fields::new())
+    };
+    fields.insert("plocks".to_owned(), plocks);
+
+    Ok(Value::Object(fields))
+}
+
+/// Only the parameters which are locked are included.
+fn dump_trig_plocks(trig: &Trig, sound: Option<&Sound>) -> Result<Value, RytmExternalError> {
+    let mut fields = Fields::new();
+
+    for &action in KIT_ACTION_TYPES.iter().chain(SOUND_ACTION_TYPES) {
+        let value = trig_plock_get_value(trig, action)
+            .map(|maybe_atom| maybe_atom.as_ref().map(atom_to_json));
+        insert_if_locked(&mut fields, action, value)?;
+    }
+
+    // The machine of a sound can't be locked.
+    for &enum_type in KIT_ENUM_TYPES
+        .iter()
+        .chain(SOUND_ENUM_TYPES)
+        .filter(|&&enum_type| enum_type != sound_enum_type::MACHINE_TYPE)
+    {
+        let value = trig_plock_get_enum_value(trig, enum_type)
+            .map(|maybe_value| maybe_value.map(Value::from));
+        insert_if_locked(&mut fields, enum_type, value)?;
+    }
+
+    if let Some(sound) = sound {
+        for &parameter in machine_parameter_types_of(sound.machine_type()) {
+            let value = if SOUND_MACHINE_ENUM_TYPES.contains(&parameter) {
+                trig_plock_get_machine_enum_value(trig, sound, parameter)
+                    .map(|maybe_value| maybe_value.map(Value::from))
+            } else {
+                trig_plock_get_machine_value(trig, sound, parameter)
+                    .map(|maybe_atom| maybe_atom.as_ref().map(atom_to_json))
+            };
+            insert_if_locked(&mut fields, parameter, value)?;
+        }
+    }
+
+    Ok(Value::Object(fields))
+}
+
+/// Identifiers which `get` does not accept for an object are left out of the dump.
+fn insert_if_supported(
+    fields: &mut Fields,
+    key: &str,
+    value: Result<Value, RytmExternalError>,
+) -> Result<(), RytmExternalError> {
+    insert_if_locked(fields, key, value.map(Some))
+}
+
+fn insert_if_locked(
+    fields: &mut Fields,
+    key: &str,
+    value: Result<Option<Value>, RytmExternalError>,
+) -> Result<(), RytmExternalError> {
+    match value {
+        Ok(Some(value)) => {
+            fields.insert(key.to_owned(), value);
+            Ok(())
+        }
+        Ok(None)
+        | Err(
+            RytmExternalError::Identifier(IdentifierError::InvalidType(_))
+            | RytmExternalError::Enum(EnumError::InvalidEnumType(_)),
+        ) => Ok(()),
+        Err(err) => Err(err),
+    }
+}
+
+fn indexed(
+    count: usize,
+    value_at: impl Fn(usize) -> Result<Value, RytmExternalError>,
+) -> Result<Value, RytmExternalError> {
+    (0..count)
+        .map(value_at)
+        .collect::<Result<Vec<_>, _>>()
+        .map(Value::Array)
+}
+
+pub(crate) fn index_atom(index: usize) -> Atom {
+    Atom::from(AtomValue::Int(index as isize))
+}
+
+pub(crate) fn atom_to_json(atom: &Atom) -> Value {
+    match atom.get_value() {
+        Some(AtomValue::Int(value)) => value.into(),
+        Some(AtomValue::Float(value)) => value.into(),
+        Some(AtomValue::Symbol(value)) => value.to_string().into(),
+        _ => Value::Null,
+    }
+}
@@ -0,0 +1,632 @@
+use crate::error::RytmExternalError;
+use crate::selection::{TRACK_COUNT, TRIG_COUNT};
+use crate::types::ObjectTypeSelector;
+use crate::util::{decode_sysex_payload, encode_sysex_payload, kit_as_sysex};
+use rytm_rs::error::RytmError;
+use rytm_rs::object::{Pattern, Sound};
+use rytm_rs::prelude::*;
+use std::ops::Range;
+
+// Layout of the raw pattern structure which is encoded in a pattern sysex dump.
+const TRACKS_OFFSET: usize = 4;
+const TRACK_SIZE: usize = 641;
+const TRIG_FLAG_BITS: usize = 14;
+/// Notes, velocities, note lengths, micro timings, retrig lengths, retrig rates and retrig velocity offsets.
+const TRIG_ARRAYS: Range<usize> = 112..560;
+const NOTES_OFFSET: usize = 112;
+const MICRO_TIMINGS_OFFSET: usize = 304;
+const RETRIG_LENGTHS_OFFSET: usize = 368;
+const RETRIG_RATES_OFFSET: usize = 432;
+const NUMBER_OF_STEPS_OFFSET: usize = 565;
+const SOUND_LOCKS_OFFSET: usize = 567;
+/// Defaults, length, quantize, speed, probability, euclidean mode and scale of a track.
+const TRACK_SETTINGS: [Range<usize>; 2] = [560..567, 631..641];
+/// The raw pattern also holds the fx track after the 12 tracks.
+const RAW_TRACK_COUNT: usize = 13;
+const PLOCK_SEQS_OFFSET: usize = TRACKS_OFFSET + RAW_TRACK_COUNT * TRACK_SIZE;
+const PLOCK_SEQ_COUNT: usize = 72;
+const PLOCK_SEQ_SIZE: usize = 66;
+
+// Layout of the raw kit structure which is encoded in a kit sysex dump.
+const KIT_SOUNDS_OFFSET: usize = 46;
+const SOUND_SIZE: usize = 162;
+
+const UNUSED: u8 = 0xFF;
+/// Marks the slot which holds the fine values of the compound parameter lock in the slot before it.
+const COMPANION: u8 = 0x80;
+/// The position of the unset trig condition in the enum of rytm-rs which it encodes instead of `0x7F`.
+const UNSET_TRIG_CONDITION_INDEX: u8 = 65;
+
+/// A parameter lock of a trig with its type, value and the fine value of compound locks.
+#[derive(Debug, Clone, Copy)]
+pub struct PlockData {
+    pub plock_type: u8,
+    pub value: u8,
+    pub fine: Option<u8>,
+}
+
+/// Everything a pattern stores for a single trig including its flags, locks and parameter locks.
+#[derive(Debug, Clone)]
+pub struct TrigData {
+    pub index: usize,
+    flags: u16,
+    bytes: Vec<u8>,
+    sound_lock: u8,
+    plocks: Vec<PlockData>,
+}
+
+/// The copied trigs of a track, whole tracks also carry their settings.
+#[derive(Debug, Clone)]
+pub struct TrackData {
+    pub index: usize,
+    settings: Option<Vec<u8>>,
+    pub trigs: Vec<TrigData>,
+}
+
+/// The contents which `copy` stores for `paste`.
+#[derive(Debug, Clone)]
+pub enum Clipboard {
+    /// A whole pattern as a sysex dump, it is retargeted to the pattern it is pasted to.
+    Pattern(Vec<u8>),
+    Tracks(Vec<TrackData>),
+}
+
+impl Clipboard {
+    /// Copies the whole pattern or the selected tracks, copying all trigs of a track copies its settings also.
+    pub fn copy(
+        project: &RytmProject,
+        object: ObjectTypeSelector,
+        selection: Option<(&[usize], Option<&[usize]>)>,
+    ) -> Result<Self, RytmExternalError> {
+        let raw = RawPattern::new(pattern(project, object))?;
+        let Some((tracks, trigs)) = selection else {
+            return Ok(Self::Pattern(raw.encode()));
+        };
+
+        Ok(Self::Tracks(
+            tracks
+                .iter()
+                .map(|&track| raw.track(track, trigs))
+                .collect(),
+        ))
+    }
+
+    /// Pastes the clipboard to a pattern.
+    ///
+    /// Tracks and trigs are pasted to where they're copied from unless a track and a trig to start from is given.
+    pub fn paste(
+        &self,
+        project: &mut RytmProject,
+        object: ObjectTypeSelector,
+        start: Option<(usize, Option<usize>)>,
+    ) -> Result<(), RytmExternalError> {
+        let tracks = match self {
+            Self::Pattern(sysex) => {
+                if start.is_some() {
+                    return Err("Invalid format: A whole pattern is in the clipboard, it can only be pasted to a whole pattern. Example: paste pattern_wb".into());
+                }
+                let mut sysex = sysex.clone();
+                object.retarget_sysex_response(&mut sysex)?;
+                project.update_from_sysex_response(&sysex)?;
+                return Ok(());
+            }
+            Self::Tracks(tracks) => tracks,
+        };
+
+        let first_track = tracks.iter().map(|track| track.index).min().unwrap_or(0);
+        let first_trig = tracks
+            .iter()
+            .flat_map(|track| track.trigs.iter().map(|trig| trig.index))
+            .min()
+            .unwrap_or(0);
+        let (track_start, trig_start) = match start {
+            Some((track, trig)) => (track, trig.unwrap_or(first_trig)),
+            None => (first_track, first_trig),
+        };
+        let offset = |index: usize, first: usize, start: usize, count: usize, name: &str| {
+            let index = index - first + start;
+            if index < count {
+                Ok(index)
+            } else {
+                Err(RytmExternalError::from(format!(
+                    "Invalid parameter: The clipboard does not fit in the pattern when pasted to track {track_start} and trig {trig_start}. {name} {index} is out of range."
+                )))
+            }
+        };
+
+        let mut raw = RawPattern::new(pattern(project, object))?;
+        for track in tracks {
+            let track_index = offset(track.index, first_track, track_start, TRACK_COUNT, "Track")?;
+            if let Some(ref settings) = track.settings {
+                raw.set_track_settings(track_index, settings);
+            }
+            for trig in &track.trigs {
+                let trig_index = offset(trig.index, first_trig, trig_start, TRIG_COUNT, "Trig")?;
+                raw.set_trig(track_index, trig_index, trig)?;
+            }
+        }
+        raw.commit(project, object)
+    }
+}
+
+/// Clears the trigs and parameter locks of the selected trigs or all trigs of the selected tracks.
+pub fn clear(
+    project: &mut RytmProject,
+    object: ObjectTypeSelector,
+    tracks: &[usize],
+    trigs: Option<&[usize]>,
+) -> Result<(), RytmExternalError> {
+    let empty = RawPattern::new(&Pattern::try_default(0)?)?;
+    let mut raw = RawPattern::new(pattern(project, object))?;
+    for &track in tracks {
+        for trig in empty.track(track, trigs).trigs {
+            raw.set_trig(track, trig.index, &trig)?;
+        }
+    }
+    raw.commit(project, object)
+}
+
+/// Rotates the trigs of the selected tracks by an amount of steps in the length of each track.
+///
+/// Positive amounts move trigs later, trigs which pass the end of the track wrap around to its start.
+pub fn shift(
+    project: &mut RytmProject,
+    object: ObjectTypeSelector,
+    tracks: &[usize],
+    amount: isize,
+) -> Result<(), RytmExternalError> {
+    rearrange(project, object, tracks, |index, length| {
+        // Reducing the amount first keeps the sum in range for any amount.
+        (index + amount.rem_euclid(length as isize) as usize) % length
+    })
+}
+
+/// Reverses the order of the trigs of the selected tracks in the length of each track.
+pub fn reverse(
+    project: &mut RytmProject,
+    object: ObjectTypeSelector,
+    tracks: &[usize],
+) -> Result<(), RytmExternalError> {
+    rearrange(project, object, tracks, |index, length| length - 1 - index)
+}
+
+/// Moves every trig in the length of the selected tracks to the index which `to` returns for it.
+fn rearrange(
+    project: &mut RytmProject,
+    object: ObjectTypeSelector,
+    tracks: &[usize],
+    to: impl Fn(usize, usize) -> usize,
+) -> Result<(), RytmExternalError> {
+    let mut raw = RawPattern::new(pattern(project, object))?;
+    for &track in tracks {
+        let length = raw.number_of_steps(track);
+        let trigs = (0..length)
+            .map(|index| raw.trig(track, index))
+            .collect::<Vec<_>>();
+        for trig in &trigs {
+            raw.set_trig(track, to(trig.index, length), trig)?;
+        }
+    }
+    raw.commit(project, object)
+}
+
+fn pattern(project: &RytmProject, object: ObjectTypeSelector) -> &Pattern {
+    match object {
+        ObjectTypeSelector::Pattern(index) => &project.patterns()[index],
+        _ => project.work_buffer().pattern(),
+    }
+}
+
+/// Encodes a pattern as a sysex dump which rytm-rs can decode again.
+///
+/// rytm-rs encodes unset trig conditions as the position of `Unset` in its enum but only decodes them from `0x7F`,
+/// which is also what the device uses. Use this instead of `Pattern::as_sysex` whenever the dump is decoded again
+/// or sent out.
+pub fn encode_pattern_for_redecode(pattern: &Pattern) -> Result<Vec<u8>, RytmExternalError> {
+    let sysex = pattern.as_sysex()?;
+    let mut raw = decode_sysex_payload(&sysex);
+    fix_unset_trig_conditions(&mut raw);
+    Ok(encode_sysex_payload(&sysex, &raw))
+}
+
+/// Returns which trigs of every track of a pattern have a parameter lock, indexed by track and trig.
+pub fn locked_trigs(pattern: &Pattern) -> Result<Vec<[bool; TRIG_COUNT]>, RytmExternalError> {
+    let raw = RawPattern::new(pattern)?;
+    let mut locked = vec![[false; TRIG_COUNT]; RAW_TRACK_COUNT];
+    for slot in 0..PLOCK_SEQ_COUNT {
+        let (plock_type, track) = raw.plock_slot(slot);
+        if plock_type == UNUSED || track as usize >= RAW_TRACK_COUNT {
+            continue;
+        }
+        let data = RawPattern::plock_seq_offset(slot) + 2;
+        for (trig, &value) in raw.raw[data..data + TRIG_COUNT].iter().enumerate() {
+            locked[track as usize][trig] |= value != UNUSED;
+        }
+    }
+
+    Ok(locked)
+}
+
+/// Encodes unset trig conditions of a raw pattern as `0x7F`.
+///
+/// The condition is spread over the most significant bits of the note, micro timing, retrig length and retrig rate.
+fn fix_unset_trig_conditions(raw: &mut [u8]) {
+    for track in 0..RAW_TRACK_COUNT {
+        let offset = RawPattern::track_offset(track);
+        for trig in 0..TRIG_COUNT {
+            let byte = |array: usize| raw[offset + array + trig];
+            let condition = ((byte(NOTES_OFFSET) & 0x80) >> 1)
+                | ((byte(MICRO_TIMINGS_OFFSET) & 0xC0) >> 2)
+                | ((byte(RETRIG_LENGTHS_OFFSET) & 0x80) >> 4)
+                | ((byte(RETRIG_RATES_OFFSET) & 0xE0) >> 5);
+            if condition == UNSET_TRIG_CONDITION_INDEX {
+                raw[offset + MICRO_TIMINGS_OFFSET + trig] |= 0xC0;
+                raw[offset + RETRIG_LENGTHS_OFFSET + trig] |= 0x80;
+                raw[offset + RETRIG_RATES_OFFSET + trig] |= 0xE0;
+            }
+        }
+    }
+}
+
+/// A pattern decoded from its sysex dump to its raw structure.
+///
+/// Editing the raw structure keeps the trig flags, the locks which are not set and the parameter locks of the trigs
+/// intact and a failed edit leaves the pattern untouched since it is only decoded back to the project when committed.
+struct RawPattern {
+    sysex: Vec<u8>,
+    raw: Vec<u8>,
+}
+
+impl RawPattern {
+    fn new(pattern: &Pattern) -> Result<Self, RytmExternalError> {
+        let sysex = encode_pattern_for_redecode(pattern)?;
+        Ok(Self {
+            raw: decode_sysex_payload(&sysex),
+            sysex,
+        })
+    }
+
+    fn commit(
+        self,
+        project: &mut RytmProject,
+        object: ObjectTypeSelector,
+    ) -> Result<(), RytmExternalError> {
+        let mut sysex = self.encode();
+        object.retarget_sysex_response(&mut sysex)?;
+        project.update_from_sysex_response(&sysex)?;
+        Ok(())
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        encode_sysex_payload(&self.sysex, &self.raw)
+    }
+
+    const fn track_offset(track: usize) -> usize {
+        TRACKS_OFFSET + track * TRACK_SIZE
+    }
+
+    const fn plock_seq_offset(slot: usize) -> usize {
+        PLOCK_SEQS_OFFSET + slot * PLOCK_SEQ_SIZE
+    }
+
+    fn number_of_steps(&self, track: usize) -> usize {
+        (self.raw[Self::track_offset(track) + NUMBER_OF_STEPS_OFFSET] as usize).clamp(1, 64)
+    }
+
+    /// Returns the parameter lock type and the track of a parameter lock slot.
+    fn plock_slot(&self, slot: usize) -> (u8, u8) {
+        let offset = Self::plock_seq_offset(slot);
+        (self.raw[offset], self.raw[offset + 1])
+    }
+
+    fn plock_data_mut(&mut self, slot: usize, trig: usize) -> &mut u8 {
+        &mut self.raw[Self::plock_seq_offset(slot) + 2 + trig]
+    }
+
+    fn is_companion(&self, slot: usize) -> bool {
+        slot < PLOCK_SEQ_COUNT && self.plock_slot(slot) == (COMPANION, COMPANION)
+    }
+
+    fn is_free(&self, slot: usize) -> bool {
+        let (plock_type, track) = self.plock_slot(slot);
+        plock_type == UNUSED || track == UNUSED
+    }
+
+    fn track(&self, track: usize, trigs: Option<&[usize]>) -> TrackData {
+        let offset = Self::track_offset(track);
+        TrackData {
+            index: track,
+            settings: trigs.is_none().then(|| {
+                TRACK_SETTINGS
+                    .iter()
+                    .flat_map(|range| self.raw[offset + range.start..offset + range.end].to_vec())
+                    .collect()
+            }),
+            trigs: trigs.map_or_else(
+                || (0..TRIG_COUNT).map(|trig| self.trig(track, trig)).collect(),
+                |trigs| trigs.iter().map(|&trig| self.trig(track, trig)).collect(),
+            ),
+        }
+    }
+
+    fn set_track_settings(&mut self, track: usize, settings: &[u8]) {
+        let offset = Self::track_offset(track);
+        let mut settings = settings.iter();
+        for range in TRACK_SETTINGS {
+            for (byte, &setting) in self.raw[offset + range.start..offset + range.end]
+                .iter_mut()
+                .zip(&mut settings)
+            {
+                *byte = setting;
+            }
+        }
+    }
+
+    fn trig(&self, track: usize, trig: usize) -> TrigData {
+        let offset = Self::track_offset(track);
+
+        let flags = (0..TRIG_FLAG_BITS).fold(0_u16, |flags, bit| {
+            let position = trig * TRIG_FLAG_BITS + bit;
+            let byte = self.raw[offset + position / 8];
+            (flags << 1) | u16::from((byte >> (7 - position % 8)) & 1)
+        });
+
+        let plocks = (0..PLOCK_SEQ_COUNT)
+            .filter_map(|slot| {
+                let (plock_type, plock_track) = self.plock_slot(slot);
+                let value = self.raw[Self::plock_seq_offset(slot) + 2 + trig];
+                (plock_type != UNUSED && plock_track as usize == track && value != UNUSED).then(
+                    || PlockData {
+                        plock_type,
+                        value,
+                        fine: self
+                            .is_companion(slot + 1)
+                            .then(|| self.raw[Self::plock_seq_offset(slot + 1) + 2 + trig]),
+                    },
+                )
+            })
+            .collect();
+
+        TrigData {
+            index: trig,
+            flags,
+            bytes: TRIG_ARRAYS
+                .step_by(64)
+                .map(|array| self.raw[offset + array + trig])
+                .collect(),
+            sound_lock: self.raw[offset + SOUND_LOCKS_OFFSET + trig],
+            plocks,
+        }
+    }
+
+    fn set_trig(
+        &mut self,
+        track: usize,
+        trig: usize,
+        data: &TrigData,
+    ) -> Result<(), RytmExternalError> {
+        let offset = Self::track_offset(track);
+
+        for bit in 0..TRIG_FLAG_BITS {
+            let position = trig * TRIG_FLAG_BITS + bit;
+            let mask = 1 << (7 - position % 8);
+            let byte = &mut self.raw[offset + position / 8];
+            if data.flags & (1 << (TRIG_FLAG_BITS - 1 - bit)) == 0 {
+                *byte &= !mask;
+            } else {
+                *byte |= mask;
+            }
+        }
+        for (array, &value) in TRIG_ARRAYS.step_by(64).zip(&data.bytes) {
+            self.raw[offset + array + trig] = value;
+        }
+        self.raw[offset + SOUND_LOCKS_OFFSET + trig] = data.sound_lock;
+
+        self.clear_plocks(track, trig);
+        for &plock in &data.plocks {
+            self.set_plock(track, trig, plock)?;
+        }
+
+        Ok(())
+    }
+
+    /// Clears the parameter locks of a trig and releases the slots which become empty.
+    fn clear_plocks(&mut self, track: usize, trig: usize) {
+        for slot in 0..PLOCK_SEQ_COUNT {
+            let (plock_type, plock_track) = self.plock_slot(slot);
+            if plock_type == UNUSED || plock_track as usize != track {
+                continue;
+            }
+
+            let compound = self.is_companion(slot + 1);
+            *self.plock_data_mut(slot, trig) = UNUSED;
+            if compound {
+                *self.plock_data_mut(slot + 1, trig) = UNUSED;
+            }
+
+            let data = Self::plock_seq_offset(slot) + 2;
+            if self.raw[data..data + 64]
+                .iter()
+                .all(|&value| value == UNUSED)
+            {
+                for slot in slot..=slot + usize::from(compound) {
+                    let offset = Self::plock_seq_offset(slot);
+                    self.raw[offset] = UNUSED;
+                    self.raw[offset + 1] = UNUSED;
+                }
+            }
+        }
+    }
+
+    /// Sets a parameter lock of a trig, compound locks need a free slot for their fine values right after them.
+    fn set_plock(
+        &mut self,
+        track: usize,
+        trig: usize,
+        plock: PlockData,
+    ) -> Result<(), RytmExternalError> {
+        let slot = (0..PLOCK_SEQ_COUNT)
+            .find(|&slot| self.plock_slot(slot) == (plock.plock_type, track as u8))
+            .or_else(|| {
+                let slot = (0..PLOCK_SEQ_COUNT).find(|&slot| {
+                    self.is_free(slot)
+                        && (plock.fine.is_none()
+                            || (slot + 1 < PLOCK_SEQ_COUNT && self.is_free(slot + 1)))
+                })?;
+                let offset = Self::plock_seq_offset(slot);
+                self.raw[offset] = plock.plock_type;
+                self.raw[offset + 1] = track as u8;
+                if plock.fine.is_some() {
+                    self.raw[offset + PLOCK_SEQ_SIZE] = COMPANION;
+                    self.raw[offset + PLOCK_SEQ_SIZE + 1] = COMPANION;
+                }
+                Some(slot)
+            })
+            .ok_or(RytmError::ParameterLockMemoryFull)?;
+
+        *self.plock_data_mut(slot, trig) = plock.value;
+        if let Some(fine) = plock.fine {
+            *self.plock_data_mut(slot + 1, trig) = fine;
+        }
+
+        Ok(())
+    }
+}
+
+/// Where a sound is kept in the project, the sound pool, the work buffer or a track of a kit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SoundSlot {
+    Pool(usize),
+    WorkBuffer(usize),
+    /// A kit index and a track index.
+    Kit(usize, usize),
+    KitWorkBuffer(usize),
+}
+
+impl SoundSlot {
+    /// Copies a sound to another slot replacing the sound in it.
+    ///
+    /// The sound takes the index, the kit number and the assigned track of the slot it is copied to.
+    pub fn copy(self, project: &mut RytmProject, to: Self) -> Result<(), RytmExternalError> {
+        let raw = self.read(project)?;
+        to.write(project, &raw)
+    }
+
+    /// Swaps the sounds of two slots.
+    ///
+    /// When the second sound can not be written the first slot is restored so the project is left untouched.
+    pub fn swap(self, project: &mut RytmProject, other: Self) -> Result<(), RytmExternalError> {
+        let raw = self.read(project)?;
+        let other_raw = other.read(project)?;
+
+        self.write(project, &other_raw)?;
+        if let Err(err) = other.write(project, &raw) {
+            self.write(project, &raw)?;
+            return Err(err);
+        }
+
+        Ok(())
+    }
+
+    /// The object which holds the sound, sounds of a kit are held by their kit.
+    pub const fn object(self) -> ObjectTypeSelector {
+        match self {
+            Self::Pool(index) => ObjectTypeSelector::Sound(index),
+            Self::WorkBuffer(index) => ObjectTypeSelector::SoundWorkBuffer(index),
+            Self::Kit(kit, _) => ObjectTypeSelector::Kit(kit),
+            Self::KitWorkBuffer(_) => ObjectTypeSelector::KitWorkBuffer,
+        }
+    }
+
+    pub(crate) fn sound(self, project: &RytmProject) -> &Sound {
+        match self {
+            Self::Pool(index) => &project.pool_sounds()[index],
+            Self::WorkBuffer(index) => &project.work_buffer().sounds()[index],
+            Self::Kit(kit, track) => &project.kits()[kit].sounds()[track],
+            Self::KitWorkBuffer(track) => &project.work_buffer().kit().sounds()[track],
+        }
+    }
+
+    pub(crate) fn sound_mut(self, project: &mut RytmProject) -> &mut Sound {
+        match self {
+            Self::Pool(index) => &mut project.pool_sounds_mut()[index],
+            Self::WorkBuffer(index) => &mut project.work_buffer_mut().sounds_mut()[index],
+            Self::Kit(kit, track) => &mut project.kits_mut()[kit].sounds_mut()[track],
+            Self::KitWorkBuffer(track) => {
+                &mut project.work_buffer_mut().kit_mut().sounds_mut()[track]
+            }
+        }
+    }
+
+    /// Reads the raw structure of the sound.
+    fn read(self, project: &RytmProject) -> Result<Vec<u8>, RytmExternalError> {
+        // Sounds without a machine can not be encoded again once they're decoded.
+        if self.sound(project).machine_type() == MachineType::Unset {
+            return Err(format!("Invalid sound: The sound in {self} has no machine yet, please query it from the device first.").into());
+        }
+
+        match self {
+            Self::Pool(_) | Self::WorkBuffer(_) => {
+                Ok(decode_sysex_payload(&self.sound(project).as_sysex()?))
+            }
+            Self::Kit(kit, track) => {
+                let raw = decode_sysex_payload(&kit_as_sysex(&project.kits()[kit])?);
+                Ok(raw[Self::kit_sound_range(track)].to_vec())
+            }
+            Self::KitWorkBuffer(track) => {
+                let raw = decode_sysex_payload(&kit_as_sysex(project.work_buffer().kit())?);
+                Ok(raw[Self::kit_sound_range(track)].to_vec())
+            }
+        }
+    }
+
+    /// Writes a raw sound to the slot by decoding it to the project as if it was sent by the device.
+    ///
+    /// Decoding sets the bookkeeping of the sound, kit sounds are written in a dump of their kit.
+    fn write(self, project: &mut RytmProject, raw: &[u8]) -> Result<(), RytmExternalError> {
+        let (object, mut sysex) = match self {
+            Self::Pool(index) => (
+                ObjectTypeSelector::Sound(index),
+                encode_sysex_payload(&self.sound(project).as_sysex()?, raw),
+            ),
+            Self::WorkBuffer(index) => (
+                ObjectTypeSelector::SoundWorkBuffer(index),
+                encode_sysex_payload(&self.sound(project).as_sysex()?, raw),
+            ),
+            Self::Kit(kit, track) => (
+                ObjectTypeSelector::Kit(kit),
+                Self::kit_sysex_with_sound(&kit_as_sysex(&project.kits()[kit])?, track, raw),
+            ),
+            Self::KitWorkBuffer(track) => (
+                ObjectTypeSelector::KitWorkBuffer,
+                Self::kit_sysex_with_sound(&kit_as_sysex(project.work_buffer().kit())?, track, raw),
+            ),
+        };
+
+        object.retarget_sysex_response(&mut sysex)?;
+        project.update_from_sysex_response(&sysex)?;
+        Ok(())
+    }
+
+    fn kit_sysex_with_sound(sysex: &[u8], track: usize, sound: &[u8]) -> Vec<u8> {
+        let mut raw = decode_sysex_payload(sysex);
+        raw[Self::kit_sound_range(track)].copy_from_slice(sound);
+        encode_sysex_payload(sysex, &raw)
+    }
+
+    const fn kit_sound_range(track: usize) -> Range<usize> {
+        let offset = KIT_SOUNDS_OFFSET + track * SOUND_SIZE;
+        offset..offset + SOUND_SIZE
+    }
+}
+
+impl std::fmt::Display for SoundSlot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Pool(index) => write!(f, "sound {index}"),
+            Self::WorkBuffer(index) => write!(f, "sound_wb {index}"),
+            Self::Kit(kit, track) => write!(f, "kit {kit} sound {track}"),
+            Self::KitWorkBuffer(track) => write!(f, "kit_wb sound {track}"),
+        }
+    }
+}
@@ -1,4 +1,3 @@
-use median::max_sys;
 use rytm_rs::error::RytmError;
 
 #[derive(thiserror::Error, Debug)]
@@ -183,14 +182,14 @@ pub enum SetError {
     #[error(
         "Invalid setter format: \"{0}\".
         Accepted formats:
-            get pattern_wb <identifier> <parameter>
-            get pattern_wb <enum>  
-            get pattern_wb <track-index> <identifier> <parameter>
-            get pattern_wb <track-index> <enum>
-            get pattern_wb <track-index> <trig-index> <identifier> <parameter>
-            get pattern_wb <track-index> <trig-index> <enum>
-            get pattern_wb <track-index> <trig-index> plockset <identifier> <parameter>
-            get pattern_wb <track-index> <trig-index> plockset <enum>
+            set pattern_wb <identifier> <parameter>
+            set pattern_wb <enum>  
+            set pattern_wb <track-index> <identifier> <parameter>
+            set pattern_wb <track-index> <enum>
+            set pattern_wb <track-index> <trig-index> <identifier> <parameter>
+            set pattern_wb <track-index> <trig-index> <enum>
+            set pattern_wb <track-index> <trig-index> plockset <identifier> <parameter>
+            set pattern_wb <track-index> <trig-index> plockset <enum>
             set pattern_wb <track-index> <trig-index> plockclear <identifier>
             set pattern_wb <track-index> <trig-index> plockclear <enum>"
     )]
@@ -202,6 +201,8 @@ pub enum SetError {
 pub enum EnumError {
     #[error("Invalid enum type: {0}")]
     InvalidEnumType(String),
+    #[error("Invalid enum type: {0} can not be parameter locked, the machine of a trig is always the machine of its sound.")]
+    NotParameterLockable(String),
 }
 
 #[derive(thiserror::Error, Debug)]
@@ -211,6 +212,8 @@ pub enum IdentifierError {
     InvalidType(String),
     #[error("Invalid parameter following {1}: {0}")]
     InvalidParameter(String, String),
+    #[error("Invalid machine parameter: {0} does not belong to the machine {1} which is assigned to this sound. Parameters of {1} are: {2}")]
+    InvalidMachineParameter(String, String, String),
 }
 
 /// Wrapper error type for all rytm errors.
@@ -235,6 +238,10 @@ pub enum RytmExternalError {
     RytmSdk(#[from] RytmError),
     #[error("{0}")]
     StringConversionError(#[from] std::str::Utf8Error),
+    #[error("{0}")]
+    Io(#[from] std::io::Error),
+    #[error("{0}")]
+    Serialization(#[from] serde_json::Error),
 
     #[error("Not implemented, if you need this api open an issue in https://github.com/alisomay/rytm-external.")]
     NotYetImplemented,
@@ -257,37 +264,3 @@ impl From<String> for RytmExternalError {
         Self::Custom(s)
     }
 }
-
-impl RytmExternalError {
-    pub fn obj_post(&self, obj: *mut max_sys::t_object) {
-        match self {
-            Self::Custom(err) => median::object::error(obj, err.to_string()),
-            Self::Query(err) => median::object::error(obj, err.to_string()),
-            Self::Send(err) => median::object::error(obj, err.to_string()),
-            Self::Get(err) => median::object::error(obj, err.to_string()),
-            Self::Set(err) => median::object::error(obj, err.to_string()),
-            Self::Enum(err) => median::object::error(obj, err.to_string()),
-            Self::Identifier(err) => median::object::error(obj, err.to_string()),
-            Self::RytmSdk(err) => median::object::error(obj, err.to_string()),
-            Self::StringConversionError(err) => median::object::error(obj, err.to_string()),
-            Self::NotYetImplemented => {
-                median::object::error(obj, "Not yet implemented.".to_string());
-            }
-        }
-    }
-
-    pub fn post(&self) {
-        match self {
-            Self::Custom(err) => median::error(err.to_string()),
-            Self::Query(err) => median::error(err.to_string()),
-            Self::Send(err) => median::error(err.to_string()),
-            Self::Get(err) => median::error(err.to_string()),
-            Self::Set(err) => median::error(err.to_string()),
-            Self::Enum(err) => median::error(err.to_string()),
-            Self::Identifier(err) => median::error(err.to_string()),
-            Self::RytmSdk(err) => median::error(err.to_string()),
-            Self::StringConversionError(err) => median::error(err.to_string()),
-            Self::NotYetImplemented => median::error("Not yet implemented.".to_string()),
-        }
-    }
-}
@@ -0,0 +1,130 @@
+use crate::atom::{Atom, AtomValue};
+use crate::error::RytmExternalError;
+use crate::util::string_from_atom_slice;
+use rytm_rs::object::pattern::track::Track;
+use rytm_rs::prelude::*;
+
+/// A euclidean layer which spreads pulses as evenly as possible over steps.
+#[derive(Debug, Clone, Copy)]
+pub struct EuclidLayer {
+    pub pulses: usize,
+    pub steps: usize,
+    pub rotation: usize,
+}
+
+impl EuclidLayer {
+    /// Renders the layer to a list of steps where `true` is a pulse.
+    ///
+    /// Rotation moves the pulses later in time wrapping around the steps.
+    pub fn render(&self) -> Vec<bool> {
+        let pattern = bjorklund(self.pulses, self.steps);
+        let rotation = self.rotation % self.steps;
+        (0..self.steps)
+            .map(|step| pattern[(step + self.steps - rotation) % self.steps])
+            .collect()
+    }
+}
+
+/// Distributes pulses over steps with the algorithm of Bjorklund.
+///
+/// The distribution always starts with a pulse, e.g. `3` pulses over `8` steps is `x..x..x.`.
+pub fn bjorklund(pulses: usize, steps: usize) -> Vec<bool> {
+    if pulses == 0 {
+        return vec![false; steps];
+    }
+
+    let mut heads = vec![vec![true]; pulses];
+    let mut remainders = vec![vec![false]; steps - pulses];
+
+    while remainders.len() > 1 {
+        let paired = heads.len().min(remainders.len());
+        let rest = if heads.len() > paired {
+            heads.split_off(paired)
+        } else {
+            remainders.split_off(paired)
+        };
+
+        for (head, remainder) in heads.iter_mut().zip(remainders) {
+            head.extend(remainder);
+        }
+        remainders = rest;
+    }
+
+    heads.into_iter().chain(remainders).flatten().collect()
+}
+
+/// Parses `<pulses> <steps> [<rotation>] [<pulses2>] [<rotation2>]` and renders the layers into the trigs of a track.
+///
+/// The second layer shares the steps of the first one and is combined with it, a step is enabled if any layer has a pulse in it.
+/// The rhythm repeats over the length of the track and enabled trigs get the default trig velocity of the track.
+pub fn handle_track_euclid(
+    track: &mut Track,
+    atoms: &[Atom],
+    select: usize,
+) -> Result<(), RytmExternalError> {
+    let parameters = atoms[select.min(atoms.len())..]
+        .iter()
+        .map(|atom| match atom.get_value() {
+            Some(AtomValue::Int(value)) if value >= 0 => Ok(value as usize),
+            _ => Err(RytmExternalError::from(format!(
+                "Invalid parameter: {}. euclid only accepts non-negative integers.",
+                string_from_atom_slice(atoms)
+            ))),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let (pulses, steps, rotation, second_layer) = match parameters[..] {
+        [pulses, steps] => (pulses, steps, 0, None),
+        [pulses, steps, rotation] => (pulses, steps, rotation, None),
+        [pulses, steps, rotation, pulses2] => (pulses, steps, rotation, Some((pulses2, 0))),
+        [pulses, steps, rotation, pulses2, rotation2] => {
+            (pulses, steps, rotation, Some((pulses2, rotation2)))
+        }
+        _ => return Err(format!(
+            "Invalid format: {}. euclid should be followed by <pulses> <steps> [<rotation>] [<pulses2>] [<rotation2>]. Example: set pattern_wb 0 euclid 3 8",
+            string_from_atom_slice(atoms)
+        )
+        .into()),
+    };
+
+    if !(1..=64).contains(&steps) {
+        return Err(
+            format!("Invalid parameter: {steps}. euclid steps must be between 1 and 64.").into(),
+        );
+    }
+    for pulses in std::iter::once(pulses).chain(second_layer.map(|(pulses, _)| pulses)) {
+        if pulses > steps {
+            return Err(format!("Invalid parameter: {pulses}. euclid pulses must be between 0 and the number of steps ({steps}).").into());
+        }
+    }
+
+    let mut rhythm = EuclidLayer {
+        pulses,
+        steps,
+        rotation,
+    }
+    .render();
+    if let Some((pulses, rotation)) = second_layer {
+        let layer = EuclidLayer {
+            pulses,
+            steps,
+            rotation,
+        }
+        .render();
+        for (step, pulse) in rhythm.iter_mut().zip(layer) {
+            *step |= pulse;
+        }
+    }
+
+    let length = track.number_of_steps();
+    let velocity = track.default_trig_velocity().max(1);
+    for (index, trig) in track.trigs_mut().iter_mut().take(length).enumerate() {
+        let enable = rhythm[index % steps];
+        trig.set_trig_enable(enable);
+        if enable {
+            trig.set_velocity(velocity)?;
+        }
+    }
+
+    Ok(())
+}
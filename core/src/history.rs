@@ -0,0 +1,391 @@
+use crate::diff::{diff_dumps, Difference};
+use crate::dump::dump_object;
+use crate::edit::encode_pattern_for_redecode;
+use crate::error::RytmExternalError;
+use crate::types::ObjectTypeSelector;
+use crate::util::{get_plock_sound_for_track, kit_number_of_pattern};
+use rytm_rs::object::{Global, Kit, Settings, Sound};
+use rytm_rs::prelude::*;
+use serde_json::Value;
+use std::collections::VecDeque;
+
+/// The number of changes which can be undone unless the `historydepth` attribute is set.
+pub const DEFAULT_HISTORY_DEPTH: usize = 50;
+
+/// Objects as they were at some point in time.
+pub struct Snapshot {
+    objects: Vec<(ObjectTypeSelector, SnapshotObject)>,
+}
+
+/// Clones of patterns share their parameter lock pool with the original so patterns are kept as sysex.
+///
+/// Restoring them as if they were received from the device also links them to their kits again. The sounds which their
+/// machine parameter locks are reached through are kept along to list the locks, they are not restored.
+enum SnapshotObject {
+    Pattern(Vec<u8>, Vec<Sound>),
+    Kit(Box<Kit>),
+    Sound(Box<Sound>),
+    Global(Box<Global>),
+    Settings(Box<Settings>),
+}
+
+impl Snapshot {
+    fn take(
+        project: &RytmProject,
+        objects: &[ObjectTypeSelector],
+    ) -> Result<Self, RytmExternalError> {
+        let objects = objects
+            .iter()
+            .map(|&object| {
+                let taken = match object {
+                    ObjectTypeSelector::Pattern(index) => SnapshotObject::Pattern(
+                        encode_pattern_for_redecode(&project.patterns()[index])?,
+                        plock_sounds(project, Some(index)),
+                    ),
+                    ObjectTypeSelector::PatternWorkBuffer => SnapshotObject::Pattern(
+                        encode_pattern_for_redecode(project.work_buffer().pattern())?,
+                        plock_sounds(project, None),
+                    ),
+                    ObjectTypeSelector::Kit(index) => {
+                        SnapshotObject::Kit(Box::new(project.kits()[index].clone()))
+                    }
+                    ObjectTypeSelector::KitWorkBuffer => {
+                        SnapshotObject::Kit(Box::new(project.work_buffer().kit().clone()))
+                    }
+                    ObjectTypeSelector::Sound(index) => {
+                        SnapshotObject::Sound(Box::new(project.pool_sounds()[index].clone()))
+                    }
+                    ObjectTypeSelector::SoundWorkBuffer(index) => SnapshotObject::Sound(Box::new(
+                        project.work_buffer().sounds()[index].clone(),
+                    )),
+                    ObjectTypeSelector::Global(index) => {
+                        SnapshotObject::Global(Box::new(project.globals()[index]))
+                    }
+                    ObjectTypeSelector::GlobalWorkBuffer => {
+                        SnapshotObject::Global(Box::new(*project.work_buffer().global()))
+                    }
+                    ObjectTypeSelector::Settings => {
+                        SnapshotObject::Settings(Box::new(*project.settings()))
+                    }
+                };
+                Ok((object, taken))
+            })
+            .collect::<Result<Vec<_>, RytmExternalError>>()?;
+
+        Ok(Self { objects })
+    }
+
+    fn restore(&self, project: &mut RytmProject) -> Result<(), RytmExternalError> {
+        for (object, taken) in &self.objects {
+            match (*object, taken) {
+                (_, SnapshotObject::Pattern(sysex, _)) => {
+                    project.update_from_sysex_response(sysex)?
+                }
+                (ObjectTypeSelector::Kit(index), SnapshotObject::Kit(kit)) => {
+                    project.kits_mut()[index] = (**kit).clone();
+                }
+                (ObjectTypeSelector::KitWorkBuffer, SnapshotObject::Kit(kit)) => {
+                    *project.work_buffer_mut().kit_mut() = (**kit).clone();
+                }
+                (ObjectTypeSelector::Sound(index), SnapshotObject::Sound(sound)) => {
+                    project.pool_sounds_mut()[index] = (**sound).clone();
+                }
+                (ObjectTypeSelector::SoundWorkBuffer(index), SnapshotObject::Sound(sound)) => {
+                    project.work_buffer_mut().sounds_mut()[index] = (**sound).clone();
+                }
+                (ObjectTypeSelector::Global(index), SnapshotObject::Global(global)) => {
+                    project.globals_mut()[index] = **global;
+                }
+                (ObjectTypeSelector::GlobalWorkBuffer, SnapshotObject::Global(global)) => {
+                    *project.work_buffer_mut().global_mut() = **global;
+                }
+                (ObjectTypeSelector::Settings, SnapshotObject::Settings(settings)) => {
+                    *project.settings_mut() = **settings;
+                }
+                _ => unreachable!("Snapshots are taken in the type of their object."),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Writes the snapshot to the project and returns the state it replaced.
+    ///
+    /// Objects which are written before a failing one are put back so the project is left untouched.
+    fn swap(&self, project: &mut RytmProject) -> Result<Self, RytmExternalError> {
+        let current = Self::take(project, &self.selectors())?;
+        if let Err(err) = self.restore(project) {
+            // The failing object itself is never written so putting it back can only fail in the same way.
+            let _ = current.restore(project);
+            return Err(err);
+        }
+
+        Ok(current)
+    }
+
+    fn selectors(&self) -> Vec<ObjectTypeSelector> {
+        self.objects.iter().map(|(object, _)| *object).collect()
+    }
+
+    /// Dumps the objects as they are in the snapshot.
+    ///
+    /// Patterns are kept as sysex so the snapshot is restored to a scratch project to dump it. The sounds which the
+    /// machine parameter locks of the patterns were reached through are put where the patterns reach them.
+    fn dumps(&self, scratch: &mut RytmProject) -> Vec<Option<Value>> {
+        if self.restore(scratch).is_err() {
+            return vec![None; self.objects.len()];
+        }
+        for (object, taken) in &self.objects {
+            let SnapshotObject::Pattern(_, sounds) = taken else {
+                continue;
+            };
+            let target: Option<&mut [Sound]> = match *object {
+                ObjectTypeSelector::Pattern(index) => kit_number_of_pattern(scratch, index)
+                    .map(|kit_number| &mut scratch.kits_mut()[kit_number].sounds_mut()[..]),
+                _ => Some(scratch.work_buffer_mut().sounds_mut()),
+            };
+            if let Some(target) = target {
+                target.clone_from_slice(sounds);
+            }
+        }
+
+        self.objects
+            .iter()
+            .map(|(object, _)| dump_object(scratch, *object).ok())
+            .collect()
+    }
+}
+
+/// Clones the sounds which the machine parameter locks of a pattern are reached through.
+fn plock_sounds(project: &RytmProject, maybe_pattern_index: Option<usize>) -> Vec<Sound> {
+    (0..)
+        .map_while(|track_index| {
+            get_plock_sound_for_track(project, maybe_pattern_index, track_index).cloned()
+        })
+        .collect()
+}
+
+/// A change of the project with the state of the objects it touched on both sides of it.
+pub struct Change {
+    description: String,
+    /// Consecutive changes with the same key are merged into one.
+    merge_key: Option<String>,
+    /// The state which undo or redo swaps in, the one from before the change while it can be undone.
+    snapshot: Snapshot,
+    /// The state which the project is in, only kept to list the parameters of the change.
+    current: Option<Snapshot>,
+    /// Listed from the snapshots once the history is listed since dumping is slow.
+    differences: Option<Vec<(ObjectTypeSelector, Difference)>>,
+}
+
+impl Change {
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    /// The parameters which the change touched with their values before and after it.
+    ///
+    /// Empty until they're listed with [`History::list_differences`].
+    pub fn differences(&self) -> &[(ObjectTypeSelector, Difference)] {
+        self.differences.as_deref().unwrap_or_default()
+    }
+
+    fn list_differences(&mut self, scratch: &mut RytmProject, undone: bool) {
+        if self.differences.is_some() {
+            return;
+        }
+        let Some(current) = &self.current else {
+            self.differences = Some(Vec::new());
+            return;
+        };
+
+        let (before, after) = if undone {
+            (current, &self.snapshot)
+        } else {
+            (&self.snapshot, current)
+        };
+        let differences = before
+            .objects
+            .iter()
+            .zip(before.dumps(scratch).into_iter().zip(after.dumps(scratch)))
+            .filter_map(|((object, _), dumps)| match dumps {
+                (Some(before), Some(after)) => Some((*object, before, after)),
+                _ => None,
+            })
+            .flat_map(|(object, before, after)| {
+                diff_dumps(&before, &after)
+                    .into_iter()
+                    .map(move |difference| (object, difference))
+            })
+            .collect();
+        self.differences = Some(differences);
+    }
+}
+
+/// Changes which can be undone and redone, the most recent ones are at the back.
+pub struct History {
+    undo: VecDeque<Change>,
+    redo: Vec<Change>,
+    depth: usize,
+    /// Where snapshots are restored to list the parameters of the changes, created when they're first listed.
+    scratch: Option<Box<RytmProject>>,
+}
+
+impl Default for History {
+    fn default() -> Self {
+        Self {
+            undo: VecDeque::new(),
+            redo: Vec::new(),
+            depth: DEFAULT_HISTORY_DEPTH,
+            scratch: None,
+        }
+    }
+}
+
+impl History {
+    pub const fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// A depth of 0 turns recording off, lowering it drops the oldest changes.
+    pub fn set_depth(&mut self, depth: usize) {
+        self.depth = depth;
+        while self.undo.len() > depth {
+            self.undo.pop_front();
+        }
+        let excess = self.redo.len().saturating_sub(depth);
+        self.redo.drain(..excess);
+    }
+
+    /// Takes the state of the objects which a change is about to touch, nothing is taken when recording is off.
+    pub fn before_change(
+        &self,
+        project: &RytmProject,
+        objects: &[ObjectTypeSelector],
+    ) -> Result<Option<Snapshot>, RytmExternalError> {
+        if self.depth == 0 {
+            return Ok(None);
+        }
+        Snapshot::take(project, objects).map(Some)
+    }
+
+    /// Records a change which succeeded with the state from before it.
+    ///
+    /// A new change makes the changes which were undone before it impossible to redo. A change with the same merge key
+    /// as the most recent change is merged into it, undoing it then goes back to before the first of them.
+    /// Objects which can not be taken after the change are still restored by undo, only their parameters are not listed.
+    pub fn record(
+        &mut self,
+        description: String,
+        merge_key: Option<String>,
+        snapshot: Snapshot,
+        project: &RytmProject,
+    ) {
+        let current = Snapshot::take(project, &snapshot.selectors()).ok();
+
+        if merge_key.is_some() && self.redo.is_empty() {
+            if let Some(last) = self.undo.back_mut().filter(|last| {
+                last.merge_key == merge_key && last.snapshot.selectors() == snapshot.selectors()
+            }) {
+                last.description = description;
+                last.current = current;
+                last.differences = None;
+                return;
+            }
+        }
+
+        self.redo.clear();
+        self.undo.push_back(Change {
+            description,
+            merge_key,
+            snapshot,
+            current,
+            differences: None,
+        });
+        if self.undo.len() > self.depth {
+            self.undo.pop_front();
+        }
+    }
+
+    /// Lists the parameters of the changes which are not listed yet.
+    pub fn list_differences(&mut self) {
+        let scratch = self.scratch.get_or_insert_with(Box::default);
+        for change in &mut self.undo {
+            change.list_differences(scratch, false);
+        }
+        for change in &mut self.redo {
+            change.list_differences(scratch, true);
+        }
+    }
+
+    /// Restores the objects of the most recent change and returns its description.
+    ///
+    /// A change which can not be restored stays in the history and the project is left untouched.
+    pub fn undo(&mut self, project: &mut RytmProject) -> Result<String, RytmExternalError> {
+        let change = self
+            .undo
+            .pop_back()
+            .ok_or("Invalid undo: There is no change to undo.")?;
+        match change.snapshot.swap(project) {
+            Ok(snapshot) => {
+                let description = change.description.clone();
+                self.redo.push(Change {
+                    current: Some(change.snapshot),
+                    snapshot,
+                    ..change
+                });
+                Ok(description)
+            }
+            Err(err) => {
+                let message = format!(
+                    "Invalid undo: {} could not be undone. {err}",
+                    change.description
+                );
+                self.undo.push_back(change);
+                Err(message.into())
+            }
+        }
+    }
+
+    /// Applies the most recently undone change again and returns its description.
+    pub fn redo(&mut self, project: &mut RytmProject) -> Result<String, RytmExternalError> {
+        let change = self
+            .redo
+            .pop()
+            .ok_or("Invalid redo: There is no undone change to redo.")?;
+        match change.snapshot.swap(project) {
+            Ok(snapshot) => {
+                let description = change.description.clone();
+                self.undo.push_back(Change {
+                    current: Some(change.snapshot),
+                    snapshot,
+                    ..change
+                });
+                Ok(description)
+            }
+            Err(err) => {
+                let message = format!(
+                    "Invalid redo: {} could not be redone. {err}",
+                    change.description
+                );
+                self.redo.push(change);
+                Err(message.into())
+            }
+        }
+    }
+
+    /// The changes which can be undone, the most recent first.
+    pub fn undoable(&self) -> impl Iterator<Item = &Change> {
+        self.undo.iter().rev()
+    }
+
+    /// The changes which can be redone, the next one to redo first.
+    pub fn redoable(&self) -> impl Iterator<Item = &Change> {
+        self.redo.iter().rev()
+    }
+
+    pub fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+    }
+}
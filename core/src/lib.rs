@@ -0,0 +1,52 @@
+// Currently for the initial version we're working in a relatively relaxed way, later on we may want to be more strict.
+// When the stabilization increases.
+#![warn(clippy::all, clippy::pedantic, clippy::nursery)]
+#![allow(
+    clippy::module_name_repetitions,
+    clippy::wildcard_imports,
+    clippy::similar_names,
+    clippy::missing_errors_doc,
+    clippy::missing_panics_doc,
+    clippy::cast_possible_truncation,
+    clippy::cast_possible_wrap,
+    clippy::cast_sign_loss,
+    clippy::enum_glob_use,
+    clippy::missing_safety_doc,
+    clippy::significant_drop_tightening,
+    clippy::too_many_lines,
+    clippy::non_std_lazy_statics
+)]
+#![allow(clippy::must_use_candidate)]
+
+//! The `rytm` external without Max.
+//!
+//! Messages go in as atoms and what they output comes back as [`output::Output`]s,
+//! the Max object only converts its atoms and forwards the outputs to its outlets.
+
+pub mod action;
+pub mod address;
+pub mod api;
+pub mod atom;
+pub mod bulk;
+pub mod diff;
+pub mod dispatch;
+pub mod dump;
+pub mod edit;
+pub mod error;
+pub mod euclid;
+pub mod history;
+pub mod live;
+pub mod load;
+pub mod morph;
+pub mod output;
+pub mod random;
+pub mod rytm;
+pub mod selection;
+pub mod types;
+pub mod util;
+
+// Should be only set through the debug 1 or debug 0 messages.
+// Should be only set from one place in the code, no other functions or threads.
+// Make sure that no other code is accessing this variable while it is being set.
+// Anything other than that is undefined behavior.
+pub static mut RYTM_EXTERNAL_DEBUG: bool = false;
@@ -0,0 +1,622 @@
+use crate::error::RytmExternalError;
+use crate::types::ObjectTypeSelector;
+use crate::util::{decode_sysex_payload, encode_sysex_payload};
+use rytm_rs::error::RytmError;
+use rytm_rs::object::{Kit, Sound};
+use rytm_rs::prelude::*;
+
+/// The parameter number of a sound or kit parameter as a cc number and as an nrpn parameter.
+#[derive(Debug, Clone, Copy)]
+pub struct LiveParameter {
+    pub cc: u8,
+    pub nrpn_msb: u8,
+    pub nrpn_lsb: u8,
+}
+
+const fn sound_parameter(cc: u8, nrpn_lsb: u8) -> LiveParameter {
+    LiveParameter {
+        cc,
+        nrpn_msb: 1,
+        nrpn_lsb,
+    }
+}
+
+/// An fx parameter of the kit with its value in the `0..=127` range the device uses on the wire.
+///
+/// Bipolar values are offset by 64.
+struct FxParameter {
+    parameter: LiveParameter,
+    get: fn(&Kit) -> u8,
+    set: fn(&mut Kit, u8) -> Result<(), RytmError>,
+}
+
+const fn fx_parameter(
+    cc: u8,
+    nrpn_lsb: u8,
+    get: fn(&Kit) -> u8,
+    set: fn(&mut Kit, u8) -> Result<(), RytmError>,
+) -> FxParameter {
+    FxParameter {
+        parameter: LiveParameter {
+            cc,
+            nrpn_msb: 2,
+            nrpn_lsb,
+        },
+        get,
+        set,
+    }
+}
+
+/// Where the 14 bit parameter words start in a decoded sound.
+const SOUND_PARAMETERS_OFFSET: usize = 0x1C;
+
+/// The parameters of a sound in the order of their 14 bit words in a decoded sound.
+///
+/// Words which can not be sent live are `None`.
+pub const SOUND_PARAMETERS: [Option<LiveParameter>; 41] = [
+    // Synth page
+    Some(sound_parameter(16, 0)),
+    Some(sound_parameter(17, 1)),
+    Some(sound_parameter(18, 2)),
+    Some(sound_parameter(19, 3)),
+    Some(sound_parameter(20, 4)),
+    Some(sound_parameter(21, 5)),
+    Some(sound_parameter(22, 6)),
+    Some(sound_parameter(23, 7)),
+    // Sample page: tune, fine tune, slot, bit reduction, start, end, loop, level
+    Some(sound_parameter(24, 8)),
+    Some(sound_parameter(25, 9)),
+    Some(sound_parameter(27, 11)),
+    Some(sound_parameter(26, 10)),
+    Some(sound_parameter(28, 12)),
+    Some(sound_parameter(29, 13)),
+    Some(sound_parameter(30, 14)),
+    Some(sound_parameter(31, 15)),
+    // Filter page
+    Some(sound_parameter(70, 16)),
+    Some(sound_parameter(71, 17)),
+    Some(sound_parameter(72, 18)),
+    Some(sound_parameter(73, 19)),
+    Some(sound_parameter(74, 20)),
+    Some(sound_parameter(75, 21)),
+    Some(sound_parameter(76, 22)),
+    Some(sound_parameter(77, 23)),
+    // Amp page: attack, hold, decay, overdrive, delay send, reverb send, pan, volume
+    Some(sound_parameter(78, 24)),
+    Some(sound_parameter(79, 25)),
+    Some(sound_parameter(80, 26)),
+    Some(sound_parameter(81, 27)),
+    Some(sound_parameter(82, 28)),
+    Some(sound_parameter(83, 29)),
+    Some(sound_parameter(10, 30)),
+    Some(sound_parameter(7, 31)),
+    // Accent level
+    None,
+    // Lfo page
+    Some(sound_parameter(102, 32)),
+    Some(sound_parameter(103, 33)),
+    Some(sound_parameter(104, 34)),
+    Some(sound_parameter(105, 35)),
+    Some(sound_parameter(106, 36)),
+    Some(sound_parameter(107, 37)),
+    Some(sound_parameter(108, 38)),
+    Some(sound_parameter(109, 39)),
+];
+
+/// The level of a track which is sent on the channel of the track.
+const TRACK_LEVEL: LiveParameter = LiveParameter {
+    cc: 95,
+    nrpn_msb: 1,
+    nrpn_lsb: 100,
+};
+
+/// The fx parameters which have a known cc and nrpn mapping.
+const FX_PARAMETERS: [FxParameter; 21] = [
+    fx_parameter(
+        16,
+        0,
+        |kit| kit.fx_delay().time() as u8,
+        |kit, value| kit.fx_delay_mut().set_time(value.into()),
+    ),
+    fx_parameter(
+        17,
+        1,
+        |kit| u8::from(kit.fx_delay().ping_pong()),
+        |kit, value| {
+            kit.fx_delay_mut().set_ping_pong(value != 0);
+            Ok(())
+        },
+    ),
+    fx_parameter(
+        18,
+        2,
+        |kit| (kit.fx_delay().stereo_width() + 64) as u8,
+        |kit, value| kit.fx_delay_mut().set_stereo_width(isize::from(value) - 64),
+    ),
+    fx_parameter(
+        20,
+        4,
+        |kit| kit.fx_delay().hpf() as u8,
+        |kit, value| kit.fx_delay_mut().set_hpf(value.into()),
+    ),
+    fx_parameter(
+        21,
+        5,
+        |kit| kit.fx_delay().lpf() as u8,
+        |kit, value| kit.fx_delay_mut().set_lpf(value.into()),
+    ),
+    fx_parameter(
+        22,
+        6,
+        |kit| kit.fx_delay().reverb_send() as u8,
+        |kit, value| kit.fx_delay_mut().set_reverb_send(value.into()),
+    ),
+    fx_parameter(
+        23,
+        7,
+        |kit| kit.fx_delay().volume() as u8,
+        |kit, value| kit.fx_delay_mut().set_volume(value.into()),
+    ),
+    fx_parameter(
+        24,
+        8,
+        |kit| kit.fx_reverb().pre_delay() as u8,
+        |kit, value| kit.fx_reverb_mut().set_pre_delay(value.into()),
+    ),
+    fx_parameter(
+        25,
+        9,
+        |kit| kit.fx_reverb().decay() as u8,
+        |kit, value| kit.fx_reverb_mut().set_decay(value.into()),
+    ),
+    fx_parameter(
+        26,
+        10,
+        |kit| kit.fx_reverb().freq() as u8,
+        |kit, value| kit.fx_reverb_mut().set_freq(value.into()),
+    ),
+    fx_parameter(
+        27,
+        11,
+        |kit| kit.fx_reverb().gain() as u8,
+        |kit, value| kit.fx_reverb_mut().set_gain(value.into()),
+    ),
+    fx_parameter(
+        28,
+        12,
+        |kit| kit.fx_reverb().hpf() as u8,
+        |kit, value| kit.fx_reverb_mut().set_hpf(value.into()),
+    ),
+    fx_parameter(
+        29,
+        13,
+        |kit| kit.fx_reverb().lpf() as u8,
+        |kit, value| kit.fx_reverb_mut().set_lpf(value.into()),
+    ),
+    fx_parameter(
+        31,
+        15,
+        |kit| kit.fx_reverb().volume() as u8,
+        |kit, value| kit.fx_reverb_mut().set_volume(value.into()),
+    ),
+    fx_parameter(
+        78,
+        24,
+        |kit| kit.fx_compressor().threshold() as u8,
+        |kit, value| kit.fx_compressor_mut().set_threshold(value.into()),
+    ),
+    fx_parameter(
+        81,
+        27,
+        |kit| kit.fx_compressor().gain() as u8,
+        |kit, value| kit.fx_compressor_mut().set_gain(value.into()),
+    ),
+    fx_parameter(
+        84,
+        30,
+        |kit| kit.fx_compressor().mix() as u8,
+        |kit, value| kit.fx_compressor_mut().set_mix(value.into()),
+    ),
+    fx_parameter(
+        85,
+        31,
+        |kit| kit.fx_compressor().volume() as u8,
+        |kit, value| kit.fx_compressor_mut().set_volume(value.into()),
+    ),
+    fx_parameter(
+        102,
+        32,
+        |kit| (kit.fx_lfo().speed() + 64) as u8,
+        |kit, value| kit.fx_lfo_mut().set_speed(isize::from(value) - 64),
+    ),
+    fx_parameter(
+        104,
+        34,
+        |kit| (kit.fx_lfo().fade() + 64) as u8,
+        |kit, value| kit.fx_lfo_mut().set_fade(isize::from(value) - 64),
+    ),
+    fx_parameter(
+        107,
+        37,
+        |kit| kit.fx_lfo().start_phase_or_slew() as u8,
+        |kit, value| kit.fx_lfo_mut().set_start_phase(value.into()),
+    ),
+];
+
+/// A part of a work buffer which is taken before a setter runs so the changes can be sent live after it.
+pub enum LiveSnapshot {
+    Sound(usize, Box<Sound>),
+    Kit(Box<Kit>),
+}
+
+impl LiveSnapshot {
+    pub fn sound(project: &RytmProject, sound_index: usize) -> Self {
+        Self::Sound(
+            sound_index,
+            Box::new(project.work_buffer().sounds()[sound_index].clone()),
+        )
+    }
+
+    pub fn kit(project: &RytmProject) -> Self {
+        Self::Kit(Box::new(project.work_buffer().kit().clone()))
+    }
+
+    /// Compares the snapshot with the current work buffer and encodes the changed parameters as midi messages.
+    ///
+    /// The messages are cc or nrpn depending on the parameter output type of the work buffer global.
+    pub fn messages(&self, project: &RytmProject) -> Result<Vec<u8>, RytmExternalError> {
+        let midi_config = project.work_buffer().global().midi_config();
+        let output = midi_config.port_config().parameter_output_type();
+        let channels = midi_config.channels();
+
+        let mut messages = Vec::new();
+        match self {
+            Self::Sound(sound_index, before) => {
+                let after = &project.work_buffer().sounds()[*sound_index];
+                if let Some(channel) = channel_number(channels.track_channel(*sound_index)?) {
+                    sound_messages(before, after, channel, output, &mut messages)?;
+                }
+            }
+            Self::Kit(before) => {
+                let after = project.work_buffer().kit();
+
+                for track_index in 0..12 {
+                    let Some(channel) = channel_number(channels.track_channel(track_index)?) else {
+                        continue;
+                    };
+
+                    let level = after.track_level(track_index)?;
+                    if level != before.track_level(track_index)? {
+                        push_message(&mut messages, channel, output, TRACK_LEVEL, level as u8, 0);
+                    }
+
+                    sound_messages(
+                        &before.sounds()[track_index],
+                        &after.sounds()[track_index],
+                        channel,
+                        output,
+                        &mut messages,
+                    )?;
+                }
+
+                if let Some(channel) = channel_number(channels.track_fx_channel()) {
+                    for fx in &FX_PARAMETERS {
+                        let value = (fx.get)(after);
+                        if value != (fx.get)(before) {
+                            push_message(&mut messages, channel, output, fx.parameter, value, 0);
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(messages)
+    }
+}
+
+/// A parameter change received from the device as a cc message or an nrpn sequence.
+#[derive(Debug, Clone, Copy)]
+pub struct LiveChange {
+    pub channel: u8,
+    pub control: LiveControl,
+    pub coarse: u8,
+    pub fine: u8,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum LiveControl {
+    Cc(u8),
+    Nrpn(u8, u8),
+}
+
+impl LiveParameter {
+    const fn is(self, control: LiveControl) -> bool {
+        match control {
+            LiveControl::Cc(cc) => self.cc == cc,
+            LiveControl::Nrpn(msb, lsb) => self.nrpn_msb == msb && self.nrpn_lsb == lsb,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+struct NrpnState {
+    parameter_msb: u8,
+    parameter_lsb: u8,
+    coarse: u8,
+}
+
+/// Parses the midi bytes received from the device to parameter changes.
+///
+/// Running status is supported, messages other than control changes are ignored.
+#[derive(Debug, Default)]
+pub struct LiveInput {
+    status: u8,
+    controller: Option<u8>,
+    nrpn: [NrpnState; 16],
+}
+
+impl LiveInput {
+    /// Takes the next byte of the midi stream and returns a change once a cc message or an nrpn sequence is complete.
+    pub const fn push(&mut self, byte: u8) -> Option<LiveChange> {
+        match byte {
+            // Real time messages may appear anywhere and do not cancel the running status.
+            0xF8..=0xFF => None,
+            0x80..=0xF7 => {
+                self.status = byte;
+                self.controller = None;
+                None
+            }
+            value => {
+                if self.status & 0xF0 != 0xB0 {
+                    return None;
+                }
+
+                let Some(controller) = self.controller.take() else {
+                    self.controller = Some(value);
+                    return None;
+                };
+
+                self.control_change(self.status & 0x0F, controller, value)
+            }
+        }
+    }
+
+    const fn control_change(
+        &mut self,
+        channel: u8,
+        controller: u8,
+        value: u8,
+    ) -> Option<LiveChange> {
+        let nrpn = &mut self.nrpn[channel as usize];
+        let (control, coarse, fine) = match controller {
+            99 => {
+                nrpn.parameter_msb = value;
+                return None;
+            }
+            98 => {
+                nrpn.parameter_lsb = value;
+                return None;
+            }
+            6 => {
+                nrpn.coarse = value;
+                return None;
+            }
+            // The device always finishes an nrpn sequence with the fine value.
+            38 => (
+                LiveControl::Nrpn(nrpn.parameter_msb, nrpn.parameter_lsb),
+                nrpn.coarse,
+                value,
+            ),
+            cc => (LiveControl::Cc(cc), value, 0),
+        };
+
+        Some(LiveChange {
+            channel,
+            control,
+            coarse,
+            fine,
+        })
+    }
+}
+
+impl LiveChange {
+    /// The objects which the change touches, the work buffer kit and the work buffer sounds of the tracks on its channel.
+    pub fn objects(
+        &self,
+        project: &RytmProject,
+    ) -> Result<Vec<ObjectTypeSelector>, RytmExternalError> {
+        let channels = *project.work_buffer().global().midi_config().channels();
+        let is_sound_parameter = SOUND_PARAMETERS
+            .iter()
+            .flatten()
+            .any(|parameter| parameter.is(self.control));
+        let mut objects = Vec::new();
+
+        for track_index in 0..12 {
+            if channel_number(channels.track_channel(track_index)?) != Some(self.channel) {
+                continue;
+            }
+            if TRACK_LEVEL.is(self.control) {
+                objects.push(ObjectTypeSelector::KitWorkBuffer);
+            } else if is_sound_parameter {
+                objects.push(ObjectTypeSelector::SoundWorkBuffer(track_index));
+            }
+        }
+
+        if channel_number(channels.track_fx_channel()) == Some(self.channel)
+            && FX_PARAMETERS.iter().any(|fx| fx.parameter.is(self.control))
+        {
+            objects.push(ObjectTypeSelector::KitWorkBuffer);
+        }
+        objects.dedup();
+
+        Ok(objects)
+    }
+
+    /// The parameter of the change and the channel it is received on.
+    ///
+    /// Format: `live <channel> cc <cc>` or `live <channel> nrpn <msb> <lsb>`
+    pub fn parameter(&self) -> String {
+        match self.control {
+            LiveControl::Cc(cc) => format!("live {} cc {cc}", self.channel),
+            LiveControl::Nrpn(msb, lsb) => format!("live {} nrpn {msb} {lsb}", self.channel),
+        }
+    }
+
+    /// Describes the change in the history.
+    ///
+    /// Format: `live <channel> cc <cc> <value>` or `live <channel> nrpn <msb> <lsb> <value>`
+    pub fn description(&self) -> String {
+        format!("{} {}", self.parameter(), self.coarse)
+    }
+
+    /// Applies the change to the work buffer of the project.
+    ///
+    /// Sound parameters are applied to every work buffer sound of the tracks on the channel of the change and
+    /// fx parameters to the work buffer kit if the channel is the fx track channel.
+    ///
+    /// Returns the objects which are updated with the index of their track, the fx track is `12`.
+    pub fn apply(
+        &self,
+        project: &mut RytmProject,
+    ) -> Result<Vec<(ObjectTypeSelector, usize)>, RytmExternalError> {
+        let channels = *project.work_buffer().global().midi_config().channels();
+        let mut updated = Vec::new();
+
+        for track_index in 0..12 {
+            if channel_number(channels.track_channel(track_index)?) != Some(self.channel) {
+                continue;
+            }
+
+            if TRACK_LEVEL.is(self.control) {
+                project
+                    .work_buffer_mut()
+                    .kit_mut()
+                    .set_track_level(track_index, self.coarse.into())?;
+                updated.push((ObjectTypeSelector::KitWorkBuffer, track_index));
+                continue;
+            }
+
+            let Some(word_index) = SOUND_PARAMETERS.iter().position(|parameter| {
+                parameter.is_some_and(|parameter| parameter.is(self.control))
+            }) else {
+                continue;
+            };
+
+            let sound = &project.work_buffer().sounds()[track_index];
+            // Sounds without a machine can not be encoded again once they're decoded.
+            if sound.machine_type() == MachineType::Unset {
+                return Err(format!("Invalid live input: The sound of track {track_index} has no machine yet, please query the work buffer from the device first.").into());
+            }
+            let sysex = sound.as_sysex()?;
+            let mut raw = decode_sysex_payload(&sysex);
+            let offset = SOUND_PARAMETERS_OFFSET + word_index * 2;
+            raw[offset] = self.coarse;
+            raw[offset + 1] = self.fine << 1;
+
+            let mut sysex = encode_sysex_payload(&sysex, &raw);
+            // Encoded sounds carry the dump id of the sound pool.
+            ObjectTypeSelector::SoundWorkBuffer(track_index).retarget_sysex_response(&mut sysex)?;
+            project.update_from_sysex_response(&sysex)?;
+            updated.push((
+                ObjectTypeSelector::SoundWorkBuffer(track_index),
+                track_index,
+            ));
+        }
+
+        if channel_number(channels.track_fx_channel()) == Some(self.channel) {
+            if let Some(fx) = FX_PARAMETERS
+                .iter()
+                .find(|fx| fx.parameter.is(self.control))
+            {
+                (fx.set)(project.work_buffer_mut().kit_mut(), self.coarse)?;
+                updated.push((ObjectTypeSelector::KitWorkBuffer, 12));
+            }
+        }
+
+        Ok(updated)
+    }
+
+    /// The cc number of the change which is also used for nrpn changes in notifications.
+    pub fn cc(&self) -> Option<u8> {
+        match self.control {
+            LiveControl::Cc(cc) => Some(cc),
+            LiveControl::Nrpn(..) => SOUND_PARAMETERS
+                .iter()
+                .flatten()
+                .chain(FX_PARAMETERS.iter().map(|fx| &fx.parameter))
+                .chain(std::iter::once(&TRACK_LEVEL))
+                .find(|parameter| parameter.is(self.control))
+                .map(|parameter| parameter.cc),
+        }
+    }
+}
+
+const fn channel_number(channel: MidiChannel) -> Option<u8> {
+    match channel {
+        MidiChannel::Channel(channel) => Some(channel as u8),
+        MidiChannel::Auto | MidiChannel::Off => None,
+    }
+}
+
+fn sound_messages(
+    before: &Sound,
+    after: &Sound,
+    channel: u8,
+    output: MidiParameterOutput,
+    messages: &mut Vec<u8>,
+) -> Result<(), RytmExternalError> {
+    let before = decode_sysex_payload(&before.as_sysex()?);
+    let after = decode_sysex_payload(&after.as_sysex()?);
+
+    for (word_index, parameter) in SOUND_PARAMETERS.iter().enumerate() {
+        let Some(parameter) = parameter else {
+            continue;
+        };
+
+        let offset = SOUND_PARAMETERS_OFFSET + word_index * 2;
+        let (before, after) = (&before[offset..offset + 2], &after[offset..offset + 2]);
+        if before != after {
+            // The fine byte of a word uses 8 bits where nrpn has 7.
+            push_message(
+                messages,
+                channel,
+                output,
+                *parameter,
+                after[0],
+                after[1] >> 1,
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Appends a cc message or an nrpn sequence with the coarse and fine value of a parameter.
+fn push_message(
+    messages: &mut Vec<u8>,
+    channel: u8,
+    output: MidiParameterOutput,
+    parameter: LiveParameter,
+    coarse: u8,
+    fine: u8,
+) {
+    let status = 0xB0 | (channel & 0x0F);
+    match output {
+        MidiParameterOutput::Cc => messages.extend([status, parameter.cc, coarse & 0x7F]),
+        MidiParameterOutput::Nrpn => messages.extend([
+            status,
+            99,
+            parameter.nrpn_msb,
+            status,
+            98,
+            parameter.nrpn_lsb,
+            status,
+            6,
+            coarse & 0x7F,
+            status,
+            38,
+            fine & 0x7F,
+        ]),
+    }
+}
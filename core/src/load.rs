@@ -0,0 +1,565 @@
+use crate::action::plock::{
+    get::{trig_plock_get_enum_value, trig_plock_get_value},
+    handle_trig_plock_setter_action,
+    machine::{trig_plock_get_machine_enum_value, trig_plock_get_machine_value},
+    sound_for_machine_plock,
+};
+use crate::action::set::{
+    global::{handle_global_set_action, handle_global_set_enum_value},
+    handle_set_action,
+    kit::{handle_kit_set_action, handle_kit_set_enum_value, handle_kit_set_kit_element},
+    pattern::{handle_pattern_enum_set_action, PatternSetAction},
+    settings::{handle_settings_set_action, handle_settings_set_enum_value},
+    sound::{handle_sound_set_action, handle_sound_set_enum_value},
+    track::{handle_track_enum_set_action, TrackSetAction},
+    trig::{handle_trig_enum_set_action, TrigSetAction},
+    SetAction,
+};
+use crate::api::{
+    global_action_type::{self, GLOBAL_ACTION_TYPES, GLOBAL_READ_ONLY_ACTION_TYPES},
+    global_enum_type::{self, GLOBAL_ENUM_TYPES},
+    kit_action_type::{self, KIT_ACTION_TYPES, KIT_READ_ONLY_ACTION_TYPES},
+    kit_element_type::{self, KIT_ELEMENTS},
+    kit_enum_type::{self, KIT_ENUM_TYPES},
+    machine_parameter_type::MACHINE_PARAMETER_TYPES,
+    pattern_action_type::{PATTERN_ACTION_TYPES, PATTERN_READ_ONLY_ACTION_TYPES},
+    pattern_enum_type::PATTERN_ENUM_TYPES,
+    plock_type::{PLOCK_CLEAR, PLOCK_SET},
+    settings_action_type::{self, SETTINGS_ACTION_TYPES, SETTINGS_READ_ONLY_ACTION_TYPES},
+    settings_enum_type::SETTINGS_ENUM_TYPES,
+    sound_action_type::{self, SOUND_ACTION_TYPES, SOUND_READ_ONLY_ACTION_TYPES},
+    sound_enum_type::{self, SOUND_ENUM_TYPES},
+    sound_machine_enum_type::SOUND_MACHINE_ENUM_TYPES,
+    track_action_type::{TRACK_ACTION_TYPES, TRACK_READ_ONLY_ACTION_TYPES},
+    track_enum_type::TRACK_ENUM_TYPES,
+    trig_action_type::TRIG_ACTION_TYPES,
+    trig_enum_type::TRIG_ENUM_TYPES,
+};
+use crate::atom::SymbolRef;
+use crate::atom::{Atom, AtomValue};
+use crate::dump::{atom_to_json, index_atom};
+use crate::error::RytmExternalError;
+use crate::types::ObjectTypeSelector;
+use crate::util::{get_plock_sound_for_track, link_plock_sounds};
+use rytm_rs::object::{
+    pattern::{track::Track, Trig},
+    Global, Kit, Pattern, Settings, Sound,
+};
+use rytm_rs::prelude::*;
+use serde_json::{Map, Value};
+
+type Fields = Map<String, Value>;
+
+/// A parameter lock which is applied after every other key of a pattern is validated.
+struct PlockChange<'a> {
+    track_index: usize,
+    trig_index: usize,
+    key: &'a str,
+    value: &'a Value,
+}
+
+/// Applies every key of a json object in the format of `dump` to an object of the project.
+///
+/// The keys are applied to a copy of the object which replaces the original only if every key is valid,
+/// a single invalid key or value leaves the project untouched.
+/// Keys which are read only like `index` or `version` are skipped so a dump can be loaded back as it is,
+/// every other key is passed to its setter and an error of the setter is returned.
+pub fn load_object(
+    project: &mut RytmProject,
+    object: ObjectTypeSelector,
+    value: &Value,
+) -> Result<(), RytmExternalError> {
+    let fields = as_fields(object.object_type().to_string().as_str(), value)?;
+
+    match object {
+        ObjectTypeSelector::Pattern(index) => {
+            // Linked before the copy since the copy shares the parameter lock pool of the pattern.
+            link_plock_sounds(project, Some(index))?;
+            let mut pattern = project.patterns()[index].clone();
+            let plocks = load_pattern(&mut pattern, fields)?;
+            load_plocks(project, Some(index), &pattern, &plocks)?;
+            project.patterns_mut()[index] = pattern;
+        }
+        ObjectTypeSelector::PatternWorkBuffer => {
+            link_plock_sounds(project, None)?;
+            let mut pattern = project.work_buffer().pattern().clone();
+            let plocks = load_pattern(&mut pattern, fields)?;
+            load_plocks(project, None, &pattern, &plocks)?;
+            *project.work_buffer_mut().pattern_mut() = pattern;
+        }
+        ObjectTypeSelector::Kit(index) => {
+            let mut kit = project.kits()[index].clone();
+            load_kit(&mut kit, fields)?;
+            project.kits_mut()[index] = kit;
+        }
+        ObjectTypeSelector::KitWorkBuffer => {
+            let mut kit = project.work_buffer().kit().clone();
+            load_kit(&mut kit, fields)?;
+            *project.work_buffer_mut().kit_mut() = kit;
+        }
+        ObjectTypeSelector::Sound(index) => {
+            let mut sound = project.pool_sounds()[index].clone();
+            load_sound(&mut sound, fields)?;
+            project.pool_sounds_mut()[index] = sound;
+        }
+        ObjectTypeSelector::SoundWorkBuffer(index) => {
+            let mut sound = project.work_buffer().sounds()[index].clone();
+            load_sound(&mut sound, fields)?;
+            project.work_buffer_mut().sounds_mut()[index] = sound;
+        }
+        ObjectTypeSelector::Global(index) => {
+            let mut global = project.globals()[index];
+            load_global(&mut global, fields)?;
+            project.globals_mut()[index] = global;
+        }
+        ObjectTypeSelector::GlobalWorkBuffer => {
+            let mut global = *project.work_buffer().global();
+            load_global(&mut global, fields)?;
+            *project.work_buffer_mut().global_mut() = global;
+        }
+        ObjectTypeSelector::Settings => {
+            let mut settings = *project.settings();
+            load_settings(&mut settings, fields)?;
+            *project.settings_mut() = settings;
+        }
+    }
+
+    Ok(())
+}
+
+fn load_settings(settings: &mut Settings, fields: &Fields) -> Result<(), RytmExternalError> {
+    for (key, value) in fields {
+        let key = key.as_str();
+        if SETTINGS_ENUM_TYPES.contains(&key) {
+            let result = handle_settings_set_enum_value(settings, key, as_str(key, value)?);
+            for_key(key, result)?;
+        } else if key == settings_action_type::MUTE {
+            for (index, muted) in as_array(key, value)?.iter().enumerate() {
+                let action = if as_bool(key, muted)? {
+                    settings_action_type::MUTE
+                } else {
+                    settings_action_type::UNMUTE
+                };
+                let result = handle_settings_set_action(settings, action, &index_atom(index));
+                for_key(key, result)?;
+            }
+        } else if SETTINGS_ACTION_TYPES.contains(&key) {
+            let result = handle_settings_set_action(settings, key, &as_atom(key, value)?);
+            for_key(key, result)?;
+        } else if SETTINGS_READ_ONLY_ACTION_TYPES.contains(&key) {
+            // Read only keys of a dump are skipped.
+        } else {
+            return Err(unknown_key("settings", key));
+        }
+    }
+
+    Ok(())
+}
+
+fn load_global(global: &mut Global, fields: &Fields) -> Result<(), RytmExternalError> {
+    for (key, value) in fields {
+        let key = key.as_str();
+        if key == global_enum_type::TRACK_CHANNELS {
+            for (index, channel) in as_array(key, value)?.iter().enumerate() {
+                let result = handle_global_set_enum_value(
+                    global,
+                    key,
+                    as_str(key, channel)?,
+                    Some(&index_atom(index)),
+                );
+                for_key(key, result)?;
+            }
+        } else if GLOBAL_ENUM_TYPES.contains(&key) {
+            let result = handle_global_set_enum_value(global, key, as_str(key, value)?, None);
+            for_key(key, result)?;
+        } else if key == global_action_type::ROUTE_TO_MAIN || key == global_action_type::SEND_TO_FX
+        {
+            for (index, enabled) in as_array(key, value)?.iter().enumerate() {
+                let enabled = Atom::from(AtomValue::Int(isize::from(as_bool(key, enabled)?)));
+                let result =
+                    handle_global_set_action(global, key, &index_atom(index), Some(&enabled));
+                for_key(key, result)?;
+            }
+        } else if GLOBAL_ACTION_TYPES.contains(&key) {
+            let result = handle_global_set_action(global, key, &as_atom(key, value)?, None);
+            for_key(key, result)?;
+        } else if GLOBAL_READ_ONLY_ACTION_TYPES.contains(&key) {
+            // Read only keys of a dump are skipped.
+        } else {
+            return Err(unknown_key("global", key));
+        }
+    }
+
+    Ok(())
+}
+
+pub(crate) fn load_kit(kit: &mut Kit, fields: &Fields) -> Result<(), RytmExternalError> {
+    for (key, value) in fields {
+        let key = key.as_str();
+        match key {
+            kit_enum_type::CONTROL_IN_1_MOD_TARGET | kit_enum_type::CONTROL_IN_2_MOD_TARGET => {
+                for (index, target) in as_array(key, value)?.iter().enumerate() {
+                    let result = handle_kit_set_enum_value(
+                        kit,
+                        key,
+                        as_str(key, target)?,
+                        Some(&index_atom(index)),
+                    );
+                    for_key(key, result)?;
+                }
+            }
+            kit_action_type::CONTROL_IN_1_MOD_AMT | kit_action_type::CONTROL_IN_2_MOD_AMT => {
+                let action = SymbolRef::from(key);
+                for (index, amount) in as_array(key, value)?.iter().enumerate() {
+                    let result = handle_kit_set_action(
+                        kit,
+                        &action,
+                        &index_atom(index),
+                        Some(&as_atom(key, amount)?),
+                    );
+                    for_key(key, result)?;
+                }
+            }
+            "sounds" => {
+                for (index, sound) in as_array(key, value)?.iter().enumerate() {
+                    let sound_fields = as_fields(key, sound)?;
+                    let sound = kit.sounds_mut().get_mut(index).ok_or_else(|| {
+                        RytmExternalError::from(format!(
+                            "Invalid load format: The kit doesn't have a sound at index {index}."
+                        ))
+                    })?;
+                    load_sound(sound, sound_fields)?;
+                }
+            }
+            element_type
+                if KIT_ELEMENTS.contains(&element_type)
+                    && element_type != kit_element_type::SOUND =>
+            {
+                for (index, element) in as_array(key, value)?.iter().enumerate() {
+                    // Enum elements are set in the `<element>:<value>` format.
+                    let element = match element {
+                        Value::String(enum_value) => symbol_atom(&format!("{key}:{enum_value}")),
+                        _ => as_atom(key, element)?,
+                    };
+                    let result = handle_kit_set_kit_element(kit, key, index, &element);
+                    for_key(key, result)?;
+                }
+            }
+            enum_type if KIT_ENUM_TYPES.contains(&enum_type) => {
+                let result = handle_kit_set_enum_value(kit, key, as_str(key, value)?, None);
+                for_key(key, result)?;
+            }
+            action if KIT_ACTION_TYPES.contains(&action) => {
+                let result =
+                    handle_kit_set_action(kit, &SymbolRef::from(key), &as_atom(key, value)?, None);
+                for_key(key, result)?;
+            }
+            // Read only keys of a dump are skipped.
+            action if KIT_READ_ONLY_ACTION_TYPES.contains(&action) => {}
+            other => return Err(unknown_key("kit", other)),
+        }
+    }
+
+    Ok(())
+}
+
+pub(crate) fn load_sound(sound: &mut Sound, fields: &Fields) -> Result<(), RytmExternalError> {
+    // Machine parameters are resolved against the machine type so it is set before anything else.
+    if let Some(machine_type) = fields.get(sound_enum_type::MACHINE_TYPE) {
+        let key = sound_enum_type::MACHINE_TYPE;
+        let result = handle_sound_set_enum_value(sound, key, as_str(key, machine_type)?, None);
+        for_key(key, result)?;
+    }
+
+    for (key, value) in fields {
+        let key = key.as_str();
+        match key {
+            sound_enum_type::MACHINE_TYPE => {}
+            sound_enum_type::VELOCITY_MOD_TARGET | sound_enum_type::AFTER_TOUCH_MOD_TARGET => {
+                for (index, target) in as_array(key, value)?.iter().enumerate() {
+                    let result = handle_sound_set_enum_value(
+                        sound,
+                        key,
+                        as_str(key, target)?,
+                        Some(&index_atom(index)),
+                    );
+                    for_key(key, result)?;
+                }
+            }
+            sound_action_type::VEL_MOD_AMT | sound_action_type::AT_MOD_AMT => {
+                for (index, amount) in as_array(key, value)?.iter().enumerate() {
+                    let result = handle_sound_set_action(
+                        sound,
+                        key,
+                        &index_atom(index),
+                        Some(&as_atom(key, amount)?),
+                    );
+                    for_key(key, result)?;
+                }
+            }
+            enum_type
+                if SOUND_ENUM_TYPES.contains(&enum_type)
+                    || SOUND_MACHINE_ENUM_TYPES.contains(&enum_type) =>
+            {
+                let result = handle_sound_set_enum_value(sound, key, as_str(key, value)?, None);
+                for_key(key, result)?;
+            }
+            action
+                if SOUND_ACTION_TYPES.contains(&action)
+                    || MACHINE_PARAMETER_TYPES.contains(&action) =>
+            {
+                let result = handle_sound_set_action(sound, key, &as_atom(key, value)?, None);
+                for_key(key, result)?;
+            }
+            // Read only keys of a dump are skipped.
+            action if SOUND_READ_ONLY_ACTION_TYPES.contains(&action) => {}
+            other => return Err(unknown_key("sound", other)),
+        }
+    }
+
+    Ok(())
+}
+
+fn load_pattern<'a>(
+    pattern: &mut Pattern,
+    fields: &'a Fields,
+) -> Result<Vec<PlockChange<'a>>, RytmExternalError> {
+    let mut plocks = Vec::new();
+
+    for (key, value) in fields {
+        let key = key.as_str();
+        if key == "tracks" {
+            for (index, track) in as_array(key, value)?.iter().enumerate() {
+                let track_fields = as_fields(key, track)?;
+                let track = pattern.tracks_mut().get_mut(index).ok_or_else(|| {
+                    RytmExternalError::from(format!(
+                        "Invalid load format: The pattern doesn't have a track at index {index}."
+                    ))
+                })?;
+                load_track(track, track_fields, &mut plocks)?;
+            }
+        } else if PATTERN_ENUM_TYPES.contains(&key) {
+            let result = handle_pattern_enum_set_action(pattern, key, as_str(key, value)?);
+            for_key(key, result)?;
+        } else if PATTERN_ACTION_TYPES.contains(&key) {
+            let result = handle_set_action(SetAction::Pattern(PatternSetAction {
+                pattern,
+                action: SymbolRef::from(key),
+                parameter: &as_atom(key, value)?,
+            }));
+            for_key(key, result)?;
+        } else if PATTERN_READ_ONLY_ACTION_TYPES.contains(&key) {
+            // Read only keys of a dump are skipped.
+        } else {
+            return Err(unknown_key("pattern", key));
+        }
+    }
+
+    Ok(plocks)
+}
+
+fn load_track<'a>(
+    track: &mut Track,
+    fields: &'a Fields,
+    plocks: &mut Vec<PlockChange<'a>>,
+) -> Result<(), RytmExternalError> {
+    for (key, value) in fields {
+        let key = key.as_str();
+        if key == "trigs" {
+            for (index, trig) in as_array(key, value)?.iter().enumerate() {
+                let trig_fields = as_fields(key, trig)?;
+                let track_index = track.index();
+                let trig = track.trigs_mut().get_mut(index).ok_or_else(|| {
+                    RytmExternalError::from(format!(
+                        "Invalid load format: The track doesn't have a trig at index {index}."
+                    ))
+                })?;
+                load_trig(track_index, trig, trig_fields, plocks)?;
+            }
+        } else if TRACK_ENUM_TYPES.contains(&key) {
+            let result = handle_track_enum_set_action(track, key, as_str(key, value)?);
+            for_key(key, result)?;
+        } else if TRACK_ACTION_TYPES.contains(&key) {
+            let result = handle_set_action(SetAction::Track(TrackSetAction {
+                track,
+                action: SymbolRef::from(key),
+                parameter: &as_atom(key, value)?,
+            }));
+            for_key(key, result)?;
+        } else if TRACK_READ_ONLY_ACTION_TYPES.contains(&key) {
+            // Read only keys of a dump are skipped.
+        } else {
+            return Err(unknown_key("track", key));
+        }
+    }
+
+    Ok(())
+}
+
+fn load_trig<'a>(
+    track_index: usize,
+    trig: &mut Trig,
+    fields: &'a Fields,
+    plocks: &mut Vec<PlockChange<'a>>,
+) -> Result<(), RytmExternalError> {
+    for (key, value) in fields {
+        let key = key.as_str();
+        if key == "plocks" {
+            for (plock_key, plock_value) in as_fields(key, value)? {
+                plocks.push(PlockChange {
+                    track_index,
+                    trig_index: trig.index(),
+                    key: plock_key.as_str(),
+                    value: plock_value,
+                });
+            }
+        } else if TRIG_ENUM_TYPES.contains(&key) {
+            let result = handle_trig_enum_set_action(trig, key, as_str(key, value)?);
+            for_key(key, result)?;
+        } else if TRIG_ACTION_TYPES.contains(&key) {
+            let result = handle_set_action(SetAction::Trig(TrigSetAction {
+                trig,
+                action: SymbolRef::from(key),
+                parameter: &as_atom(key, value)?,
+            }));
+            for_key(key, result)?;
+        } else {
+            return Err(unknown_key("trig", key));
+        }
+    }
+
+    Ok(())
+}
+
+/// Parameter locks live in the pool which the pattern shares with its copy, so they can't be validated on the copy.
+/// They're applied last and the ones which are already applied are reverted if any of them fails.
+fn load_plocks(
+    project: &RytmProject,
+    maybe_pattern_index: Option<usize>,
+    pattern: &Pattern,
+    plocks: &[PlockChange],
+) -> Result<(), RytmExternalError> {
+    let mut applied = Vec::new();
+
+    for plock in plocks {
+        let trig = &pattern.tracks()[plock.track_index].trigs()[plock.trig_index];
+        let sound = get_plock_sound_for_track(project, maybe_pattern_index, plock.track_index);
+
+        let result = plock_value(trig, sound, plock.key).and_then(|previous| {
+            set_plock(trig, sound, plock.key, Some(plock.value))?;
+            Ok(previous)
+        });
+
+        match result {
+            Ok(previous) => applied.push((trig, sound, plock.key, previous)),
+            Err(err) => {
+                for (trig, sound, key, previous) in applied.into_iter().rev() {
+                    // Reverting restores values which were already in the pool thus it can't fail.
+                    let _ = set_plock(trig, sound, key, previous.as_ref());
+                }
+                return Err(format!("Invalid load value for plock {}: {err}", plock.key).into());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+pub(crate) fn plock_value(
+    trig: &Trig,
+    sound: Option<&Sound>,
+    key: &str,
+) -> Result<Option<Value>, RytmExternalError> {
+    if SOUND_MACHINE_ENUM_TYPES.contains(&key) {
+        let sound = sound_for_machine_plock(sound)?;
+        Ok(trig_plock_get_machine_enum_value(trig, sound, key)?.map(Value::from))
+    } else if MACHINE_PARAMETER_TYPES.contains(&key) {
+        let sound = sound_for_machine_plock(sound)?;
+        Ok(trig_plock_get_machine_value(trig, sound, key)?
+            .as_ref()
+            .map(atom_to_json))
+    } else if KIT_ENUM_TYPES.contains(&key) || SOUND_ENUM_TYPES.contains(&key) {
+        Ok(trig_plock_get_enum_value(trig, key)?.map(Value::from))
+    } else {
+        Ok(trig_plock_get_value(trig, key)?.as_ref().map(atom_to_json))
+    }
+}
+
+/// Sets a parameter lock in the format of `plockset` or clears it if there is no value.
+pub(crate) fn set_plock(
+    trig: &Trig,
+    sound: Option<&Sound>,
+    key: &str,
+    maybe_value: Option<&Value>,
+) -> Result<(), RytmExternalError> {
+    let is_enum = KIT_ENUM_TYPES.contains(&key)
+        || SOUND_ENUM_TYPES.contains(&key)
+        || SOUND_MACHINE_ENUM_TYPES.contains(&key);
+
+    let (selector, atoms) = match (maybe_value, is_enum) {
+        (Some(value), true) => (
+            PLOCK_SET,
+            vec![symbol_atom(&format!("{key}:{}", as_str(key, value)?))],
+        ),
+        (Some(value), false) => (PLOCK_SET, vec![symbol_atom(key), as_atom(key, value)?]),
+        (None, true) => (PLOCK_CLEAR, vec![symbol_atom(&format!("{key}:"))]),
+        (None, false) => (PLOCK_CLEAR, vec![symbol_atom(key)]),
+    };
+
+    handle_trig_plock_setter_action(trig, sound, selector, &atoms, 0)
+}
+
+/// Tells which key of the dictionary a setter failed for.
+fn for_key(key: &str, result: Result<(), RytmExternalError>) -> Result<(), RytmExternalError> {
+    result.map_err(|err| format!("Invalid load value for {key}: {err}").into())
+}
+
+fn unknown_key(object_type: &str, key: &str) -> RytmExternalError {
+    format!("Invalid load format: {key} is not a valid key for a {object_type}.").into()
+}
+
+fn as_fields<'a>(key: &str, value: &'a Value) -> Result<&'a Fields, RytmExternalError> {
+    value
+        .as_object()
+        .ok_or_else(|| format!("Invalid load format: {key} should be a dictionary.").into())
+}
+
+fn as_array<'a>(key: &str, value: &'a Value) -> Result<&'a Vec<Value>, RytmExternalError> {
+    value
+        .as_array()
+        .ok_or_else(|| format!("Invalid load format: {key} should be an array.").into())
+}
+
+fn as_str<'a>(key: &str, value: &'a Value) -> Result<&'a str, RytmExternalError> {
+    value
+        .as_str()
+        .ok_or_else(|| format!("Invalid load format: {key} should be a symbol.").into())
+}
+
+fn as_bool(key: &str, value: &Value) -> Result<bool, RytmExternalError> {
+    match value {
+        Value::Bool(value) => Ok(*value),
+        Value::Number(number) if number.as_i64().is_some() => Ok(number.as_i64() != Some(0)),
+        _ => Err(format!("Invalid load format: {key} should be 0 or 1.").into()),
+    }
+}
+
+fn as_atom(key: &str, value: &Value) -> Result<Atom, RytmExternalError> {
+    Ok(match value {
+        Value::Bool(value) => Atom::from(AtomValue::Int(isize::from(*value))),
+        Value::Number(number) => Atom::from(number.as_i64().map_or_else(
+            || AtomValue::Float(number.as_f64().unwrap_or_default()),
+            |value| AtomValue::Int(value as isize),
+        )),
+        Value::String(value) => symbol_atom(value),
+        _ => {
+            return Err(
+                format!("Invalid load format: {key} should be a number or a symbol.").into(),
+            )
+        }
+    })
+}
+
+fn symbol_atom(value: &str) -> Atom {
+    Atom::from(SymbolRef::from(value))
+}
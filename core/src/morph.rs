@@ -0,0 +1,142 @@
+use crate::api::{
+    kit_action_type, machine_parameter_type::MACHINE_PARAMETER_TYPES, sound_enum_type,
+};
+use crate::dump::{dump_kit, dump_sound};
+use crate::edit::SoundSlot;
+use crate::error::RytmExternalError;
+use crate::load::{load_kit, load_sound};
+use crate::types::ObjectTypeSelector;
+use rytm_rs::prelude::*;
+use serde_json::{Map, Value};
+
+type Fields = Map<String, Value>;
+
+/// An object which can be morphed, either a sound wherever it lives or a whole kit.
+#[derive(Debug, Clone, Copy)]
+pub enum MorphObject {
+    Sound(SoundSlot),
+    Kit(ObjectTypeSelector),
+}
+
+impl MorphObject {
+    /// The object which holds the morphed sound or kit.
+    pub const fn object(self) -> ObjectTypeSelector {
+        match self {
+            Self::Sound(slot) => slot.object(),
+            Self::Kit(object) => object,
+        }
+    }
+
+    fn dump(self, project: &RytmProject) -> Result<Fields, RytmExternalError> {
+        let value = match self {
+            Self::Sound(slot) => {
+                let sound = slot.sound(project);
+                if sound.machine_type() == MachineType::Unset {
+                    return Err(format!(
+                        "Invalid sound: The sound in {slot} has no machine yet, please query it from the device first."
+                    )
+                    .into());
+                }
+                dump_sound(sound)?
+            }
+            Self::Kit(ObjectTypeSelector::Kit(index)) => dump_kit(&project.kits()[index])?,
+            Self::Kit(_) => dump_kit(project.work_buffer().kit())?,
+        };
+
+        match value {
+            Value::Object(fields) => Ok(fields),
+            _ => unreachable!("Dumps of sounds and kits are always json objects."),
+        }
+    }
+}
+
+/// Writes the parameters of `from` and `to` interpolated at `position` to `target`.
+///
+/// Numeric parameters are interpolated linearly, enums and toggles step from `from` to `to` at the midpoint.
+/// Machine parameters are only interpolated when both sounds have the same machine, otherwise they step with the machine.
+/// Names are left untouched and a single invalid value leaves the project untouched.
+pub fn morph(
+    project: &mut RytmProject,
+    from: MorphObject,
+    to: MorphObject,
+    position: f64,
+    target: MorphObject,
+) -> Result<(), RytmExternalError> {
+    let fields = morph_fields(&from.dump(project)?, &to.dump(project)?, position);
+
+    match target {
+        MorphObject::Sound(slot) => {
+            let mut sound = slot.sound(project).clone();
+            load_sound(&mut sound, &fields)?;
+            *slot.sound_mut(project) = sound;
+        }
+        MorphObject::Kit(ObjectTypeSelector::Kit(index)) => {
+            let mut kit = project.kits()[index].clone();
+            load_kit(&mut kit, &fields)?;
+            project.kits_mut()[index] = kit;
+        }
+        MorphObject::Kit(_) => {
+            let mut kit = project.work_buffer().kit().clone();
+            load_kit(&mut kit, &fields)?;
+            *project.work_buffer_mut().kit_mut() = kit;
+        }
+    }
+
+    Ok(())
+}
+
+fn morph_fields(from: &Fields, to: &Fields, position: f64) -> Fields {
+    let same_machine =
+        from.get(sound_enum_type::MACHINE_TYPE) == to.get(sound_enum_type::MACHINE_TYPE);
+    let nearest_fields = if position < 0.5 { from } else { to };
+
+    nearest_fields
+        .iter()
+        .filter(|(key, _)| key.as_str() != kit_action_type::NAME)
+        .map(|(key, value)| {
+            let interpolate = same_machine || !MACHINE_PARAMETER_TYPES.contains(&key.as_str());
+            let value = match (from.get(key), to.get(key)) {
+                (Some(from), Some(to)) if interpolate => morph_value(from, to, position),
+                _ => value.clone(),
+            };
+            (key.clone(), value)
+        })
+        .collect()
+}
+
+fn morph_value(from: &Value, to: &Value, position: f64) -> Value {
+    match (from, to) {
+        (Value::Number(from_number), Value::Number(to_number)) => {
+            let (Some(start), Some(end)) = (from_number.as_f64(), to_number.as_f64()) else {
+                return nearest(from, to, position).clone();
+            };
+            let value = (end - start).mul_add(position, start);
+            if from_number.is_f64() || to_number.is_f64() {
+                Value::from(value)
+            } else {
+                Value::from(value.round() as i64)
+            }
+        }
+        (Value::Array(from_values), Value::Array(to_values))
+            if from_values.len() == to_values.len() =>
+        {
+            from_values
+                .iter()
+                .zip(to_values)
+                .map(|(from, to)| morph_value(from, to, position))
+                .collect()
+        }
+        (Value::Object(from_fields), Value::Object(to_fields)) => {
+            Value::Object(morph_fields(from_fields, to_fields, position))
+        }
+        _ => nearest(from, to, position).clone(),
+    }
+}
+
+const fn nearest<'a>(from: &'a Value, to: &'a Value, position: f64) -> &'a Value {
+    if position < 0.5 {
+        from
+    } else {
+        to
+    }
+}
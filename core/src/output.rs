@@ -0,0 +1,66 @@
+use crate::atom::Atom;
+use crate::util::LockOrRecover;
+use std::sync::{Arc, Mutex};
+
+/// Something which leaves the external while a message is handled, in the order it is sent.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Output {
+    /// Bytes for the device, sysex dumps and queries or cc and nrpn messages.
+    Sysex(Vec<u8>),
+    /// A message of the query outlet, e.g. the result of a `get`.
+    Query(Vec<Atom>),
+    /// A message of the query outlet which is followed by a dictionary, e.g. the result of a `dump`.
+    ///
+    /// The host registers the json object as a dictionary and appends `dictionary <name>` to the atoms.
+    Dictionary(Vec<Atom>, serde_json::Value),
+    /// A message of the notification outlet, e.g. `decoded kit 0 0 2998`.
+    Notification(Vec<Atom>),
+    /// An error which does not stop what caused it, e.g. an object which fails in a bulk send.
+    Error(String),
+    /// Asks the host to call `bulk_tick` after the given milliseconds.
+    ScheduleBulkTick(i64),
+    /// Asks the host to cancel the scheduled `bulk_tick`.
+    CancelBulkTick,
+}
+
+/// Outputs which wait to be taken by the host, shared by the outlets so their order is kept.
+#[derive(Debug, Clone, Default)]
+pub struct OutputQueue(Arc<Mutex<Vec<Output>>>);
+
+impl OutputQueue {
+    pub fn push(&self, output: Output) {
+        self.0.lock_or_recover().push(output);
+    }
+
+    /// Takes every output which is sent since the last call.
+    pub fn take(&self) -> Vec<Output> {
+        std::mem::take(&mut *self.0.lock_or_recover())
+    }
+}
+
+/// An outlet which sends lists of atoms to the output queue.
+#[derive(Debug, Clone)]
+pub struct Outlet {
+    queue: OutputQueue,
+    output: fn(Vec<Atom>) -> Output,
+}
+
+impl Outlet {
+    pub fn query(queue: &OutputQueue) -> Self {
+        Self {
+            queue: queue.clone(),
+            output: Output::Query,
+        }
+    }
+
+    pub fn notification(queue: &OutputQueue) -> Self {
+        Self {
+            queue: queue.clone(),
+            output: Output::Notification,
+        }
+    }
+
+    pub fn send(&self, atoms: &[Atom]) {
+        self.queue.push((self.output)(atoms.to_vec()));
+    }
+}
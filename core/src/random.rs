@@ -0,0 +1,375 @@
+use crate::action::get::{kit::kit_get_value, sound::sound_get_value, track::track_get_value};
+use crate::action::set::{
+    kit::handle_kit_set_action,
+    sound::handle_sound_set_action,
+    track::{handle_track_set_action, TrackSetAction},
+};
+use crate::api::{kit_action_type::*, random_type::*, sound_action_type::*, track_action_type::*};
+use crate::atom::SymbolRef;
+use crate::atom::{Atom, AtomValue};
+use crate::edit::SoundSlot;
+use crate::error::RytmExternalError;
+use crate::types::ObjectTypeSelector;
+use rytm_rs::object::{pattern::track::Track, Kit, Pattern, Sound};
+use rytm_rs::prelude::*;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// A parameter which can be randomized with the group it belongs to and the range its setter accepts.
+struct RandomParameter {
+    identifier: &'static str,
+    /// Parameters without a group are only randomized when they're asked for by their identifier.
+    group: Option<&'static str>,
+    min: f64,
+    max: f64,
+    float: bool,
+}
+
+const fn int(identifier: &'static str, group: &'static str, min: f64, max: f64) -> RandomParameter {
+    RandomParameter {
+        identifier,
+        group: Some(group),
+        min,
+        max,
+        float: false,
+    }
+}
+
+const fn float(
+    identifier: &'static str,
+    group: &'static str,
+    min: f64,
+    max: f64,
+) -> RandomParameter {
+    RandomParameter {
+        identifier,
+        group: Some(group),
+        min,
+        max,
+        float: true,
+    }
+}
+
+const SOUND_PARAMETERS: &[RandomParameter] = &[
+    int(AMP_ATTACK, AMP, 0.0, 127.0),
+    int(AMP_HOLD, AMP, 0.0, 127.0),
+    int(AMP_DECAY, AMP, 0.0, 127.0),
+    int(AMP_OVERDRIVE, AMP, 0.0, 127.0),
+    int(AMP_DELAY_SEND, AMP, 0.0, 127.0),
+    int(AMP_REVERB_SEND, AMP, 0.0, 127.0),
+    int(AMP_PAN, AMP, -64.0, 63.0),
+    int(AMP_VOLUME, AMP, 0.0, 127.0),
+    int(FILT_ATTACK, FILTER, 0.0, 127.0),
+    int(FILT_HOLD, FILTER, 0.0, 127.0),
+    int(FILT_DECAY, FILTER, 0.0, 127.0),
+    int(FILT_RELEASE, FILTER, 0.0, 127.0),
+    int(FILT_CUTOFF, FILTER, 0.0, 127.0),
+    int(FILT_RESONANCE, FILTER, 0.0, 127.0),
+    int(FILT_ENVELOPE_AMOUNT, FILTER, -64.0, 63.0),
+    int(LFO_SPEED, LFO, -64.0, 63.0),
+    int(LFO_FADE, LFO, -64.0, 63.0),
+    int(LFO_START_PHASE_OR_SLEW, LFO, 0.0, 127.0),
+    float(LFO_DEPTH, LFO, -128.0, 127.99),
+    int(SAMP_TUNE, SAMP, -24.0, 24.0),
+    int(SAMP_FINE_TUNE, SAMP, -64.0, 63.0),
+    int(SAMP_NUMBER, SAMP, 0.0, 127.0),
+    int(SAMP_BIT_REDUCTION, SAMP, 0.0, 127.0),
+    float(SAMP_START, SAMP, 0.0, 120.0),
+    float(SAMP_END, SAMP, 0.0, 120.0),
+    int(SAMP_VOLUME, SAMP, 0.0, 127.0),
+    RandomParameter {
+        identifier: ACCENT_LEVEL,
+        group: None,
+        min: 0.0,
+        max: 127.0,
+        float: false,
+    },
+];
+
+const KIT_PARAMETERS: &[RandomParameter] = &[
+    int(FX_DELAY_TIME, FX, 0.0, 127.0),
+    int(FX_DELAY_STEREO_WIDTH, FX, -64.0, 63.0),
+    int(FX_DELAY_FEEDBACK, FX, 0.0, 198.0),
+    int(FX_DELAY_HPF, FX, 0.0, 127.0),
+    int(FX_DELAY_LPF, FX, 0.0, 127.0),
+    int(FX_DELAY_REVERB_SEND, FX, 0.0, 127.0),
+    int(FX_DELAY_VOLUME, FX, 0.0, 127.0),
+    int(FX_REVERB_PRE_DELAY, FX, 0.0, 127.0),
+    int(FX_REVERB_DECAY, FX, 0.0, 127.0),
+    int(FX_REVERB_FREQ, FX, 0.0, 127.0),
+    int(FX_REVERB_GAIN, FX, 0.0, 127.0),
+    int(FX_REVERB_HPF, FX, 0.0, 127.0),
+    int(FX_REVERB_LPF, FX, 0.0, 127.0),
+    int(FX_REVERB_VOLUME, FX, 0.0, 127.0),
+    int(FX_COMP_THRESHOLD, FX, 0.0, 127.0),
+    int(FX_COMP_GAIN, FX, 0.0, 127.0),
+    int(FX_COMP_MIX, FX, 0.0, 127.0),
+    int(FX_COMP_VOLUME, FX, 0.0, 127.0),
+    int(FX_LFO_SPEED, FX, -64.0, 63.0),
+    int(FX_LFO_FADE, FX, -64.0, 63.0),
+    int(FX_LFO_START_PHASE_OR_SLEW, FX, 0.0, 127.0),
+    float(FX_LFO_DEPTH, FX, -128.0, 127.99),
+    int(FX_DISTORTION_DELAY_OVERDRIVE, FX, 0.0, 127.0),
+    int(FX_DISTORTION_AMOUNT, FX, 0.0, 127.0),
+    int(FX_DISTORTION_SYMMETRY, FX, -64.0, 63.0),
+];
+
+const TRACK_PARAMETERS: &[RandomParameter] = &[
+    int(DEF_TRIG_NOTE, TRACK, 0.0, 127.0),
+    int(DEF_TRIG_VELOCITY, TRACK, 0.0, 127.0),
+    int(DEF_TRIG_PROB, TRACK, 0.0, 100.0),
+    int(QUANTIZE_AMOUNT, TRACK, 0.0, 127.0),
+    // Changing the length of a track moves its trigs in and out of the pattern so it is only randomized when asked for.
+    RandomParameter {
+        identifier: NUMBER_OF_STEPS,
+        group: None,
+        min: 1.0,
+        max: 64.0,
+        float: false,
+    },
+];
+
+/// How the new values of the parameters are picked.
+#[derive(Debug, Clone, Copy)]
+pub enum RandomMode {
+    /// Picks any value in the range of the parameter.
+    Randomize,
+    /// Moves the current value by a random amount up to this fraction of the range of the parameter.
+    Mutate(f64),
+}
+
+/// What randomize changes, a sound, the fx of a kit or tracks of a pattern.
+#[derive(Debug, Clone)]
+pub enum RandomTarget {
+    Sound(SoundSlot),
+    Kit(ObjectTypeSelector),
+    Tracks(ObjectTypeSelector, Vec<usize>),
+}
+
+/// A xorshift random number generator, the same seed always produces the same values.
+#[derive(Debug, Clone)]
+pub struct Random {
+    state: u64,
+}
+
+impl Random {
+    pub const fn new(seed: u64) -> Self {
+        // Spreads the bits of small seeds so consecutive seeds don't start with similar values, the state can't be zero.
+        let mut state = seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        state = (state ^ (state >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        state = (state ^ (state >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        state ^= state >> 31;
+        Self {
+            state: if state == 0 { 1 } else { state },
+        }
+    }
+
+    const fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        self.state
+    }
+
+    /// A value in `0.0..1.0` made from the 52 bits of the mantissa of a float in `1.0..2.0`.
+    fn next_f64(&mut self) -> f64 {
+        f64::from_bits(0x3FF0_0000_0000_0000 | (self.next_u64() >> 12)) - 1.0
+    }
+}
+
+impl Default for Random {
+    fn default() -> Self {
+        Self::new(
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |duration| duration.as_nanos() as u64),
+        )
+    }
+}
+
+impl RandomParameter {
+    fn next_value(&self, random: &mut Random, mode: RandomMode, current: f64) -> Atom {
+        let value = match mode {
+            RandomMode::Randomize if self.float => {
+                random.next_f64().mul_add(self.max - self.min, self.min)
+            }
+            // Every integer in the range is equally likely.
+            RandomMode::Randomize => {
+                (self.min + (random.next_f64() * (self.max - self.min + 1.0)).floor()).min(self.max)
+            }
+            RandomMode::Mutate(amount) => {
+                let value = random
+                    .next_f64()
+                    .mul_add(2.0, -1.0)
+                    .mul_add(amount * (self.max - self.min), current);
+                if self.float {
+                    value
+                } else {
+                    value.round()
+                }
+            }
+        }
+        .clamp(self.min, self.max);
+
+        if self.float {
+            Atom::from(AtomValue::Float(value))
+        } else {
+            Atom::from(AtomValue::Int(value as isize))
+        }
+    }
+}
+
+/// Sets random values to the parameters which are selected by their identifiers or their groups.
+///
+/// Values go through the same setters as `set` and are applied to a copy of the object,
+/// an invalid value or identifier leaves the project untouched.
+pub fn randomize(
+    project: &mut RytmProject,
+    target: &RandomTarget,
+    selectors: &[String],
+    mode: RandomMode,
+    random: &mut Random,
+) -> Result<(), RytmExternalError> {
+    match target {
+        RandomTarget::Sound(slot) => {
+            let parameters = select_parameters(SOUND_PARAMETERS, selectors, "sounds")?;
+            let mut sound = slot.sound(project).clone();
+            randomize_sound(&mut sound, &parameters, mode, random)?;
+            *slot.sound_mut(project) = sound;
+        }
+        RandomTarget::Kit(object) => {
+            let parameters = select_parameters(KIT_PARAMETERS, selectors, "kits")?;
+            let kit = match object {
+                ObjectTypeSelector::Kit(index) => &mut project.kits_mut()[*index],
+                _ => project.work_buffer_mut().kit_mut(),
+            };
+            let mut randomized = kit.clone();
+            randomize_kit(&mut randomized, &parameters, mode, random)?;
+            *kit = randomized;
+        }
+        RandomTarget::Tracks(object, tracks) => {
+            let parameters = select_parameters(TRACK_PARAMETERS, selectors, "tracks")?;
+            let pattern = match object {
+                ObjectTypeSelector::Pattern(index) => &mut project.patterns_mut()[*index],
+                _ => project.work_buffer_mut().pattern_mut(),
+            };
+            let mut randomized = pattern.clone();
+            randomize_tracks(&mut randomized, tracks, &parameters, mode, random)?;
+            *pattern = randomized;
+        }
+    }
+
+    Ok(())
+}
+
+fn randomize_sound(
+    sound: &mut Sound,
+    parameters: &[&RandomParameter],
+    mode: RandomMode,
+    random: &mut Random,
+) -> Result<(), RytmExternalError> {
+    for parameter in parameters {
+        let current = current_value(mode, || sound_get_value(sound, parameter.identifier, None))?;
+        let value = parameter.next_value(random, mode, current);
+        handle_sound_set_action(sound, parameter.identifier, &value, None)?;
+    }
+    Ok(())
+}
+
+fn randomize_kit(
+    kit: &mut Kit,
+    parameters: &[&RandomParameter],
+    mode: RandomMode,
+    random: &mut Random,
+) -> Result<(), RytmExternalError> {
+    for parameter in parameters {
+        let current = current_value(mode, || kit_get_value(kit, parameter.identifier, None))?;
+        let value = parameter.next_value(random, mode, current);
+        handle_kit_set_action(kit, &SymbolRef::from(parameter.identifier), &value, None)?;
+    }
+    Ok(())
+}
+
+fn randomize_tracks(
+    pattern: &mut Pattern,
+    tracks: &[usize],
+    parameters: &[&RandomParameter],
+    mode: RandomMode,
+    random: &mut Random,
+) -> Result<(), RytmExternalError> {
+    for &track_index in tracks {
+        let track: &mut Track = pattern.tracks_mut().get_mut(track_index).ok_or_else(|| {
+            RytmExternalError::from(format!(
+                "Invalid index: The pattern doesn't have a track at index {track_index} which can be randomized."
+            ))
+        })?;
+        for parameter in parameters {
+            let current = current_value(mode, || {
+                track_get_value(track, parameter.identifier)
+                    .map(|value| Atom::from(AtomValue::Int(value as isize)))
+            })?;
+            let value = parameter.next_value(random, mode, current);
+            handle_track_set_action(TrackSetAction {
+                track,
+                action: SymbolRef::from(parameter.identifier),
+                parameter: &value,
+            })?;
+        }
+    }
+    Ok(())
+}
+
+/// Reads the current value of a parameter only when it is needed to mutate it.
+fn current_value(
+    mode: RandomMode,
+    get: impl FnOnce() -> Result<Atom, RytmExternalError>,
+) -> Result<f64, RytmExternalError> {
+    if matches!(mode, RandomMode::Randomize) {
+        return Ok(0.0);
+    }
+
+    match get()?.get_value() {
+        Some(AtomValue::Int(value)) => Ok(f64::from(value as i32)),
+        Some(AtomValue::Float(value)) => Ok(value),
+        _ => Err("Invalid value: Only numeric parameters can be mutated.".into()),
+    }
+}
+
+/// Resolves groups and identifiers to the parameters they select in the order of the table
+/// so the same seed gives the same values regardless of the order of the selectors.
+fn select_parameters<'a>(
+    table: &'a [RandomParameter],
+    selectors: &[String],
+    object_name: &str,
+) -> Result<Vec<&'a RandomParameter>, RytmExternalError> {
+    if let Some(unknown) = selectors.iter().find(|selector| {
+        !table.iter().any(|parameter| {
+            parameter.identifier == selector.as_str() || parameter.group == Some(selector.as_str())
+        })
+    }) {
+        let mut groups = table
+            .iter()
+            .filter_map(|parameter| parameter.group)
+            .collect::<Vec<_>>();
+        groups.dedup();
+        let identifiers = table
+            .iter()
+            .map(|parameter| parameter.identifier)
+            .collect::<Vec<_>>();
+        return Err(format!(
+            "Invalid parameter: {unknown} can not be randomized for {object_name}. Possible groups are {} and possible identifiers are {}.",
+            groups.join(", "),
+            identifiers.join(", ")
+        )
+        .into());
+    }
+
+    Ok(table
+        .iter()
+        .filter(|parameter| {
+            selectors.iter().any(|selector| {
+                parameter.identifier == selector.as_str()
+                    || parameter.group == Some(selector.as_str())
+            })
+        })
+        .collect())
+}
@@ -0,0 +1,729 @@
+use crate::api::bulk_target::{BULK_TARGETS, STOP};
+use crate::atom::{Atom, AtomValue, SymbolRef};
+use crate::bulk::{BulkJob, BulkOperation};
+use crate::edit::{encode_pattern_for_redecode, Clipboard};
+use crate::error::{GetError, SendError, SetError};
+use crate::history::History;
+use crate::live::{LiveInput, LiveSnapshot};
+use crate::output::{Outlet, Output, OutputQueue};
+use crate::random::Random;
+use crate::util::{kit_as_sysex, LockOrRecover};
+use crate::{
+    error::{QueryError, RytmExternalError},
+    util::string_from_atom_slice,
+};
+use rytm_rs::prelude::*;
+use serde_json::Value;
+
+use std::{
+    convert::TryFrom,
+    sync::{
+        atomic::AtomicBool, atomic::AtomicI64, atomic::AtomicU8, atomic::Ordering::*, Arc, Mutex,
+    },
+};
+
+use crate::types::{ObjectTypeSelector, SYSEX_DEVICE_ID_BYTE_INDEX};
+
+/// Reads a named dictionary of the host as json, `load` reads objects from it.
+pub type DictionaryReader = Box<dyn Fn(&str) -> Result<Value, RytmExternalError> + Send + Sync>;
+
+// This is the external without the Max object around it.
+pub struct Rytm {
+    pub project: Arc<Mutex<RytmProject>>,
+    pub sysex_in_buffer: Arc<Mutex<Vec<u8>>>,
+    pub buffering_sysex: AtomicBool,
+    pub device_id: AtomicU8,
+    pub outputs: OutputQueue,
+    pub query_out: Outlet,
+    pub notification_out: Outlet,
+    pub bulk_job: Mutex<Option<BulkJob>>,
+    pub bulk_spacing: AtomicI64,
+    pub live_output: AtomicBool,
+    pub live_input: Mutex<LiveInput>,
+    pub live_notify: AtomicBool,
+    pub clipboard: Mutex<Option<Clipboard>>,
+    pub random: Mutex<Random>,
+    pub history: Mutex<History>,
+    pub dictionary_reader: DictionaryReader,
+}
+
+impl Default for Rytm {
+    /// A rytm which has no dictionaries to load from.
+    fn default() -> Self {
+        Self::new(Box::new(|name| {
+            Err(format!("Invalid dictionary: There is no dictionary named {name}.").into())
+        }))
+    }
+}
+
+impl Rytm {
+    const SELECTOR_QUERY: &'static str = "query";
+    const SELECTOR_SEND: &'static str = "send";
+    pub(crate) const SELECTOR_SET: &'static str = "set";
+    const SELECTOR_GET: &'static str = "get";
+    const SELECTOR_DEBUG: &'static str = "debug";
+    const SELECTOR_WRITE: &'static str = "write";
+    const SELECTOR_READ: &'static str = "read";
+    const SELECTOR_EXPORT: &'static str = "export";
+    pub(crate) const SELECTOR_IMPORT: &'static str = "import";
+    const SELECTOR_DUMP: &'static str = "dump";
+    pub(crate) const SELECTOR_LOAD: &'static str = "load";
+    const SELECTOR_COPY: &'static str = "copy";
+    pub(crate) const SELECTOR_PASTE: &'static str = "paste";
+    pub(crate) const SELECTOR_CLEAR: &'static str = "clear";
+    pub(crate) const SELECTOR_SHIFT: &'static str = "shift";
+    pub(crate) const SELECTOR_REVERSE: &'static str = "reverse";
+    pub(crate) const SELECTOR_COPY_SOUND: &'static str = "copysound";
+    pub(crate) const SELECTOR_SWAP_SOUND: &'static str = "swapsound";
+    pub(crate) const SELECTOR_RANDOMIZE: &'static str = "randomize";
+    pub(crate) const SELECTOR_MORPH: &'static str = "morph";
+    const SELECTOR_UNDO: &'static str = "undo";
+    const SELECTOR_REDO: &'static str = "redo";
+    const SELECTOR_HISTORY: &'static str = "history";
+    const SELECTOR_DIFF: &'static str = "diff";
+
+    const NOTIFICATION_DECODED: &'static str = "decoded";
+    const NOTIFICATION_ERROR: &'static str = "error";
+    const NOTIFICATION_IGNORED: &'static str = "ignored";
+    const NOTIFICATION_PROGRESS: &'static str = "progress";
+    const NOTIFICATION_DONE: &'static str = "done";
+    const NOTIFICATION_STOPPED: &'static str = "stopped";
+    const NOTIFICATION_RECEIVED: &'static str = "received";
+
+    fn debug_mode(atoms: &[Atom]) -> Result<(), RytmExternalError> {
+        if let Some(atom) = atoms.first() {
+            if let Some(AtomValue::Int(value)) = atom.get_value() {
+                // Check lib.rs for safety.
+                // In addition debug post should be never used in this function.
+                unsafe {
+                    if value == 1 {
+                        crate::RYTM_EXTERNAL_DEBUG = true;
+                        return Ok(());
+                    } else if value == 0 {
+                        crate::RYTM_EXTERNAL_DEBUG = false;
+                        return Ok(());
+                    }
+                    return Err(RytmExternalError::from(
+                        "Invalid value: Only 0 or 1 are allowed for setting the debug mode.",
+                    ));
+                }
+            }
+            return Err(RytmExternalError::from(
+                "Invalid value: Only 0 or 1 are allowed for setting the debug mode.",
+            ));
+        }
+        Err(RytmExternalError::from(
+            "Invalid format: 0 or 1 should follow the debug keyword.",
+        ))
+    }
+
+    pub fn new(dictionary_reader: DictionaryReader) -> Self {
+        let outputs = OutputQueue::default();
+
+        Self {
+            project: Arc::new(Mutex::new(RytmProject::default())),
+            buffering_sysex: AtomicBool::new(false),
+            device_id: AtomicU8::new(0),
+            sysex_in_buffer: Arc::new(Mutex::new(Vec::with_capacity(1024 * 18))),
+            query_out: Outlet::query(&outputs),
+            notification_out: Outlet::notification(&outputs),
+            outputs,
+            bulk_job: Mutex::new(None),
+            bulk_spacing: AtomicI64::new(50),
+            live_output: AtomicBool::new(false),
+            live_input: Mutex::new(LiveInput::default()),
+            live_notify: AtomicBool::new(false),
+            clipboard: Mutex::new(None),
+            random: Mutex::new(Random::default()),
+            history: Mutex::new(History::default()),
+            dictionary_reader,
+        }
+    }
+
+    /// Takes what is output since the last call, the host forwards them to its outlets.
+    pub fn take_outputs(&self) -> Vec<Output> {
+        self.outputs.take()
+    }
+
+    /// Handles a plain text message like `get kit_wb name` and returns what it outputs.
+    pub fn message(&self, message: &str) -> Result<Vec<Output>, RytmExternalError> {
+        let atoms = Atom::parse_list(message);
+        let (selector, atoms) = atoms
+            .split_first()
+            .ok_or("Invalid format: The message is empty.")?;
+        let result = self.anything_with_selector(selector.get_symbol().as_str(), atoms);
+        let outputs = self.take_outputs();
+
+        result.map(|()| outputs)
+    }
+
+    /// Receives a byte of the first inlet, the sysexin object sends the data serially.
+    pub fn int(&self, value: isize) -> Result<(), RytmExternalError> {
+        // The sysexin object sends the data serially.
+        // We need to buffer it until we get the end of the sysex message.
+
+        if value == 0xF0 || self.buffering_sysex.load(Relaxed) {
+            self.buffering_sysex.store(true, Relaxed);
+            let mut sysex_in_buffer = self.sysex_in_buffer.lock_or_recover();
+            sysex_in_buffer.push(value as u8);
+            if value == 0xF7 {
+                self.buffering_sysex.store(false, Relaxed);
+
+                let result = match sysex_in_buffer.get(SYSEX_DEVICE_ID_BYTE_INDEX) {
+                    // Responses of other devices which share the same midi port are not for us.
+                    Some(&device_id) if device_id != self.device_id.load(Relaxed) => {
+                        self.notify_ignored(device_id, sysex_in_buffer.len());
+                        Ok(())
+                    }
+                    _ => self.handle_sysex_response(&sysex_in_buffer),
+                };
+
+                // Clear the buffer also for failed responses so the next one starts clean.
+                sysex_in_buffer.clear();
+                return result;
+            }
+            return Ok(());
+        }
+
+        Err(RytmExternalError::from(
+            "Invalid input: rytm only understands sysex messages. Please connect sysexin object to the rytm inlet to make sure you pass in only sysex messages.",
+        ))
+    }
+
+    /// Parses the midi stream of the second inlet and applies the complete cc and nrpn messages to the work buffer.
+    pub fn handle_live_input(&self, byte: u8) -> Result<(), RytmExternalError> {
+        let Some(change) = self.live_input.lock_or_recover().push(byte) else {
+            return Ok(());
+        };
+
+        let objects = change.objects(&self.project.lock_or_recover())?;
+        // Changes of parameters which no object on the channel has are not recorded.
+        if objects.is_empty() {
+            return Ok(());
+        }
+        // Consecutive changes of a parameter, like turning a knob, are undone at once.
+        let updated = self.record_described_change(
+            &change.description(),
+            Some(change.parameter()),
+            &objects,
+            || change.apply(&mut self.project.lock_or_recover()),
+        )?;
+
+        if self.live_notify.load(Relaxed) {
+            if let Some(cc) = change.cc() {
+                for (object, track_index) in updated {
+                    self.notify_received(object, track_index, cc, change.coarse);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn handle_sysex_response(&self, response: &[u8]) -> Result<(), RytmExternalError> {
+        let object = match ObjectTypeSelector::try_from_sysex_response(response) {
+            Ok(object) => object,
+            Err(err) => {
+                self.notify_error(&err);
+                return Err(err);
+            }
+        };
+
+        let description = format!(
+            "{} {} {}",
+            Self::NOTIFICATION_DECODED,
+            object.object_type(),
+            object.index()
+        );
+        self.record_described_change(&description, None, &[object], || {
+            self.apply_sysex_response(response)
+        })
+    }
+
+    /// Decodes a sysex response to the project and notifies the patch about it without recording it in the history.
+    fn apply_sysex_response(&self, response: &[u8]) -> Result<(), RytmExternalError> {
+        let decoded = ObjectTypeSelector::try_from_sysex_response(response).and_then(|object| {
+            self.project
+                .lock_or_recover()
+                .update_from_sysex_response(response)?;
+            Ok(object)
+        });
+
+        match decoded {
+            Ok(object) => {
+                self.notify_decoded(object, response.len());
+                Ok(())
+            }
+            Err(err) => {
+                self.notify_error(&err);
+                Err(err)
+            }
+        }
+    }
+
+    /// Decodes sysex responses to a copy of the project which replaces it only once every response is decoded.
+    ///
+    /// An invalid response leaves the project untouched. Changes are not recorded in the history.
+    pub(crate) fn apply_sysex_responses(
+        &self,
+        responses: &[Vec<u8>],
+    ) -> Result<(), RytmExternalError> {
+        let decoded = responses
+            .iter()
+            .map(|response| ObjectTypeSelector::try_from_sysex_response(response))
+            .collect::<Result<Vec<_>, _>>()
+            .and_then(|objects| {
+                // Boxed since the project is too large for the stack.
+                let mut project = Box::new(self.project.lock_or_recover().clone());
+                for response in responses {
+                    project.update_from_sysex_response(response)?;
+                }
+                std::mem::swap(&mut *self.project.lock_or_recover(), &mut *project);
+                Ok(objects)
+            });
+
+        match decoded {
+            Ok(objects) => {
+                for (object, response) in objects.into_iter().zip(responses) {
+                    self.notify_decoded(object, response.len());
+                }
+                Ok(())
+            }
+            Err(err) => {
+                self.notify_error(&err);
+                Err(err)
+            }
+        }
+    }
+
+    /// Notifies the patch that a sysex response is decoded and the object is updated.
+    ///
+    /// Format: `decoded <object-type> <index> <is-work-buffer> <byte-count>`
+    fn notify_decoded(&self, object: ObjectTypeSelector, byte_count: usize) {
+        self.notification_out.send(
+            &[
+                Atom::from(SymbolRef::from(Self::NOTIFICATION_DECODED)),
+                Atom::from(object.object_type()),
+                Atom::from(AtomValue::Int(object.index() as isize)),
+                Atom::from(AtomValue::Int(isize::from(object.is_work_buffer()))),
+                Atom::from(AtomValue::Int(byte_count as isize)),
+            ][..],
+        );
+    }
+
+    /// Notifies the patch that a sysex response could not be decoded.
+    ///
+    /// Format: `error <message>`
+    fn notify_error(&self, err: &RytmExternalError) {
+        self.notification_out.send(
+            &[
+                Atom::from(SymbolRef::from(Self::NOTIFICATION_ERROR)),
+                Atom::from(SymbolRef::from(err.to_string())),
+            ][..],
+        );
+    }
+
+    /// Notifies the patch that a parameter is changed from the device.
+    ///
+    /// Format: `received <object-type> <track-index> <cc> <value>`
+    fn notify_received(&self, object: ObjectTypeSelector, track_index: usize, cc: u8, value: u8) {
+        self.notification_out.send(
+            &[
+                Atom::from(SymbolRef::from(Self::NOTIFICATION_RECEIVED)),
+                Atom::from(object.object_type()),
+                Atom::from(AtomValue::Int(track_index as isize)),
+                Atom::from(AtomValue::Int(isize::from(cc))),
+                Atom::from(AtomValue::Int(isize::from(value))),
+            ][..],
+        );
+    }
+
+    /// Notifies the patch that a sysex response is ignored since it is coming from a device with a different device id.
+    ///
+    /// Format: `ignored <device-id> <byte-count>`
+    fn notify_ignored(&self, device_id: u8, byte_count: usize) {
+        self.notification_out.send(
+            &[
+                Atom::from(SymbolRef::from(Self::NOTIFICATION_IGNORED)),
+                Atom::from(AtomValue::Int(isize::from(device_id))),
+                Atom::from(AtomValue::Int(byte_count as isize)),
+            ][..],
+        );
+    }
+
+    /// Sysex messages of objects carry the device id they're received from, we override it to target our device.
+    fn with_device_id(&self, mut sysex: Vec<u8>) -> Vec<u8> {
+        if let Some(device_id) = sysex.get_mut(SYSEX_DEVICE_ID_BYTE_INDEX) {
+            *device_id = self.device_id.load(Relaxed);
+        }
+        sysex
+    }
+
+    pub fn anything_with_selector(
+        &self,
+        selector: &str,
+        atoms: &[Atom],
+    ) -> Result<(), RytmExternalError> {
+        match selector {
+            Self::SELECTOR_QUERY => self.query(atoms),
+            Self::SELECTOR_SEND => self.send(atoms),
+            Self::SELECTOR_SET => self.set(atoms),
+            Self::SELECTOR_GET => self.get(atoms),
+            Self::SELECTOR_DEBUG => Self::debug_mode(atoms),
+            Self::SELECTOR_WRITE => crate::api::project::handle_project_write(self, atoms),
+            Self::SELECTOR_READ => crate::api::project::handle_project_read(self, atoms),
+            Self::SELECTOR_EXPORT => crate::api::syx::handle_object_export(self, atoms),
+            Self::SELECTOR_IMPORT => crate::api::syx::handle_object_import(self, atoms),
+            Self::SELECTOR_DUMP => self.dump(atoms),
+            Self::SELECTOR_LOAD => crate::api::load::handle_object_load(self, atoms),
+            Self::SELECTOR_COPY => crate::api::edit::handle_pattern_copy(self, atoms),
+            Self::SELECTOR_PASTE => crate::api::edit::handle_pattern_paste(self, atoms),
+            Self::SELECTOR_CLEAR => crate::api::edit::handle_pattern_clear(self, atoms),
+            Self::SELECTOR_SHIFT => crate::api::edit::handle_pattern_shift(self, atoms),
+            Self::SELECTOR_REVERSE => crate::api::edit::handle_pattern_reverse(self, atoms),
+            Self::SELECTOR_COPY_SOUND => crate::api::edit::handle_sound_copy(self, atoms),
+            Self::SELECTOR_SWAP_SOUND => crate::api::edit::handle_sound_swap(self, atoms),
+            Self::SELECTOR_RANDOMIZE => crate::api::random::handle_randomize(self, atoms),
+            Self::SELECTOR_MORPH => crate::api::morph::handle_morph(self, atoms),
+            Self::SELECTOR_UNDO => crate::api::history::handle_undo(self, atoms),
+            Self::SELECTOR_REDO => crate::api::history::handle_redo(self, atoms),
+            Self::SELECTOR_HISTORY => crate::api::history::handle_history(self, atoms),
+            Self::SELECTOR_DIFF => crate::api::diff::handle_diff(self, atoms),
+            _ => Err(format!("Invalid selector: {selector}. Possible selectors are query, send, set, get, dump, load, copy, paste, clear, shift, reverse, copysound, swapsound, randomize, morph, undo, redo, history, diff, write, read, export, import, debug.").into()),
+        }
+    }
+
+    fn query(&self, atoms: &[Atom]) -> Result<(), RytmExternalError> {
+        if let Some(target) = Self::bulk_target(atoms) {
+            return self.start_bulk(BulkOperation::Query, &target);
+        }
+
+        let atom_pair = match (atoms.first(), atoms.get(1)) {
+            (None, Some(_) | None) => Err(QueryError::InvalidFormat),
+            _ => Ok((atoms.first().unwrap(), atoms.get(1))),
+        }?;
+
+        self.query_object(ObjectTypeSelector::try_from(atom_pair)?)
+    }
+
+    fn query_object(&self, object: ObjectTypeSelector) -> Result<(), RytmExternalError> {
+        let device_id = self.device_id.load(Relaxed);
+
+        let sysex = match object {
+            ObjectTypeSelector::Pattern(index) => {
+                PatternQuery::new_with_device_id(index, device_id)?.as_sysex()
+            }
+            ObjectTypeSelector::PatternWorkBuffer => {
+                PatternQuery::new_targeting_work_buffer_with_device_id(device_id).as_sysex()
+            }
+            ObjectTypeSelector::Kit(index) => {
+                KitQuery::new_with_device_id(index, device_id)?.as_sysex()
+            }
+            ObjectTypeSelector::KitWorkBuffer => {
+                KitQuery::new_targeting_work_buffer_with_device_id(device_id).as_sysex()
+            }
+            ObjectTypeSelector::Sound(index) => {
+                SoundQuery::new_with_device_id(index, device_id)?.as_sysex()
+            }
+            ObjectTypeSelector::SoundWorkBuffer(index) => {
+                SoundQuery::new_targeting_work_buffer_with_device_id(index, device_id)?.as_sysex()
+            }
+            ObjectTypeSelector::Global(index) => {
+                GlobalQuery::new_with_device_id(index, device_id)?.as_sysex()
+            }
+            ObjectTypeSelector::GlobalWorkBuffer => {
+                GlobalQuery::new_targeting_work_buffer_with_device_id(device_id).as_sysex()
+            }
+            ObjectTypeSelector::Settings => SettingsQuery::new_with_device_id(device_id).as_sysex(),
+        }?;
+        self.outputs.push(Output::Sysex(sysex));
+
+        Ok(())
+    }
+
+    fn send(&self, atoms: &[Atom]) -> Result<(), RytmExternalError> {
+        if let Some(target) = Self::bulk_target(atoms) {
+            return self.start_bulk(BulkOperation::Send, &target);
+        }
+
+        let atom_pair = match (atoms.first(), atoms.get(1)) {
+            (None, Some(_) | None) => Err(SendError::InvalidFormat),
+            _ => Ok((atoms.first().unwrap(), atoms.get(1))),
+        }?;
+
+        self.send_object(ObjectTypeSelector::try_from(atom_pair)?)
+    }
+
+    pub(crate) fn send_object(&self, object: ObjectTypeSelector) -> Result<(), RytmExternalError> {
+        self.outputs.push(Output::Sysex(self.object_sysex(object)?));
+
+        Ok(())
+    }
+
+    /// Encodes an object of the project as a sysex dump which targets our device.
+    pub fn object_sysex(&self, object: ObjectTypeSelector) -> Result<Vec<u8>, RytmExternalError> {
+        let mut sysex = match object {
+            ObjectTypeSelector::Pattern(index) => {
+                encode_pattern_for_redecode(&self.project.lock_or_recover().patterns()[index])?
+            }
+            ObjectTypeSelector::PatternWorkBuffer => {
+                encode_pattern_for_redecode(self.project.lock_or_recover().work_buffer().pattern())?
+            }
+            ObjectTypeSelector::Kit(index) => {
+                kit_as_sysex(&self.project.lock_or_recover().kits()[index])?
+            }
+            ObjectTypeSelector::KitWorkBuffer => {
+                kit_as_sysex(self.project.lock_or_recover().work_buffer().kit())?
+            }
+            ObjectTypeSelector::Sound(index) => {
+                self.project.lock_or_recover().pool_sounds()[index].as_sysex()?
+            }
+            ObjectTypeSelector::SoundWorkBuffer(index) => {
+                self.project.lock_or_recover().work_buffer().sounds()[index].as_sysex()?
+            }
+            ObjectTypeSelector::Global(index) => {
+                self.project.lock_or_recover().globals()[index].as_sysex()?
+            }
+            ObjectTypeSelector::GlobalWorkBuffer => self
+                .project
+                .lock_or_recover()
+                .work_buffer()
+                .global()
+                .as_sysex()?,
+            ObjectTypeSelector::Settings => self.project.lock_or_recover().settings().as_sysex()?,
+        };
+        // Work buffer sounds are encoded with the id of the sound pool.
+        object.retarget_sysex_response(&mut sysex)?;
+
+        Ok(self.with_device_id(sysex))
+    }
+
+    /// Returns the bulk target if the first atom is one, e.g. `all` in `query all`.
+    fn bulk_target(atoms: &[Atom]) -> Option<String> {
+        if let Some(AtomValue::Symbol(target)) = atoms.first().and_then(Atom::get_value) {
+            let target = target.to_string();
+            if BULK_TARGETS.contains(&target.as_str()) {
+                return Some(target);
+            }
+        }
+        None
+    }
+
+    fn start_bulk(&self, operation: BulkOperation, target: &str) -> Result<(), RytmExternalError> {
+        let mut bulk_job = self.bulk_job.lock_or_recover();
+
+        if target == STOP {
+            if let Some(job) = bulk_job.take() {
+                self.outputs.push(Output::CancelBulkTick);
+                self.notify_bulk(Self::NOTIFICATION_STOPPED, job.operation(), job.progress());
+            }
+            return Ok(());
+        }
+
+        // A new bulk operation replaces the one in progress.
+        *bulk_job = Some(BulkJob::new(operation, target)?);
+        self.outputs.push(Output::ScheduleBulkTick(0));
+
+        Ok(())
+    }
+
+    /// Called when the scheduled bulk tick is due, processes the next object of the bulk operation in progress.
+    pub fn bulk_tick(&self) {
+        let mut bulk_job = self.bulk_job.lock_or_recover();
+        let Some(job) = bulk_job.as_mut() else {
+            return;
+        };
+
+        if let Some(object) = job.next_object() {
+            let result = match job.operation() {
+                BulkOperation::Query => self.query_object(object),
+                BulkOperation::Send => self.send_object(object),
+            };
+            if let Err(err) = result {
+                self.outputs.push(Output::Error(err.to_string()));
+            }
+            self.notify_bulk(Self::NOTIFICATION_PROGRESS, job.operation(), job.progress());
+        }
+
+        if job.is_done() {
+            self.notify_bulk(Self::NOTIFICATION_DONE, job.operation(), job.progress());
+            *bulk_job = None;
+            return;
+        }
+
+        self.outputs
+            .push(Output::ScheduleBulkTick(self.bulk_spacing.load(Relaxed)));
+    }
+
+    /// Notifies the patch about the state of a bulk operation.
+    ///
+    /// Format: `<progress|done|stopped> <query|send> <processed-count> <total-count>`
+    fn notify_bulk(
+        &self,
+        notification: &str,
+        operation: BulkOperation,
+        (processed, total): (usize, usize),
+    ) {
+        self.notification_out.send(
+            &[
+                Atom::from(SymbolRef::from(notification)),
+                Atom::from(SymbolRef::from(operation.as_str())),
+                Atom::from(AtomValue::Int(processed as isize)),
+                Atom::from(AtomValue::Int(total as isize)),
+            ][..],
+        );
+    }
+
+    /// Outputs every identifier and enum of an object as a dictionary from the query outlet.
+    ///
+    /// Format: `dump <object-type> <index> dictionary <name>`
+    fn dump(&self, atoms: &[Atom]) -> Result<(), RytmExternalError> {
+        let object = ObjectTypeSelector::try_from((
+            atoms.first().ok_or(
+                "Invalid dump format: The right format should be, dump <object-type> [<index>]. Example: dump kit 0",
+            )?,
+            atoms.get(1),
+        ))?;
+
+        let value = crate::dump::dump_object(&mut self.project.lock_or_recover(), object)?;
+
+        self.outputs.push(Output::Dictionary(
+            vec![
+                Atom::from(SymbolRef::from(Self::SELECTOR_DUMP)),
+                Atom::from(object.object_type()),
+                Atom::from(AtomValue::Int(object.index() as isize)),
+            ],
+            value,
+        ));
+
+        Ok(())
+    }
+
+    fn set(&self, atoms: &[Atom]) -> Result<(), RytmExternalError> {
+        let indexable = ObjectTypeSelector::try_from((
+            atoms.first().ok_or_else(|| {
+                SetError::InvalidFormat(
+                    "Setter is incomplete. No other elements follow the get call.".to_owned(),
+                )
+            })?,
+            None,
+        ))
+        .is_err();
+
+        let atom_pair = match (atoms.first(), atoms.get(1)) {
+            (None, Some(_) | None) => Err(SetError::InvalidFormat(
+                "Setter is incomplete. No other elements follow the get call.".to_owned(),
+            )),
+            _ => {
+                if indexable {
+                    Ok((atoms.first().unwrap(), atoms.get(1)))
+                } else {
+                    Ok((atoms.first().unwrap(), None))
+                }
+            }
+        }?;
+
+        let object = ObjectTypeSelector::try_from(atom_pair)?;
+        self.record_change(Self::SELECTOR_SET, atoms, &[object], || {
+            (object.handlers().set)(self, atoms, object.index())
+        })
+    }
+
+    /// Runs a change of some objects of the project and records it in the history so it can be undone.
+    pub(crate) fn record_change(
+        &self,
+        selector: &str,
+        atoms: &[Atom],
+        objects: &[ObjectTypeSelector],
+        change: impl FnOnce() -> Result<(), RytmExternalError>,
+    ) -> Result<(), RytmExternalError> {
+        let description = format!("{selector} {}", string_from_atom_slice(atoms));
+        self.record_described_change(description.trim_end(), None, objects, change)
+    }
+
+    /// Runs a change which doesn't come from a message, like a sysex response, and records it in the history.
+    ///
+    /// The change is merged into the most recent change if both have the same merge key.
+    fn record_described_change<T>(
+        &self,
+        description: &str,
+        merge_key: Option<String>,
+        objects: &[ObjectTypeSelector],
+        change: impl FnOnce() -> Result<T, RytmExternalError>,
+    ) -> Result<T, RytmExternalError> {
+        let snapshot = self
+            .history
+            .lock_or_recover()
+            .before_change(&self.project.lock_or_recover(), objects)?;
+        let changed = change()?;
+
+        if let Some(snapshot) = snapshot {
+            self.history.lock_or_recover().record(
+                description.to_owned(),
+                merge_key,
+                snapshot,
+                &self.project.lock_or_recover(),
+            );
+        }
+
+        Ok(changed)
+    }
+
+    /// Runs a work buffer setter and sends the parameters it changed as cc or nrpn messages when `liveout` is on.
+    pub(crate) fn set_with_live_output(
+        &self,
+        snapshot: impl FnOnce(&RytmProject) -> LiveSnapshot,
+        set: impl FnOnce() -> Result<(), RytmExternalError>,
+    ) -> Result<(), RytmExternalError> {
+        if !self.live_output.load(Relaxed) {
+            return set();
+        }
+
+        self.set_and_send_live_messages(snapshot, set)
+    }
+
+    /// Runs a work buffer setter and sends the parameters it changed as cc or nrpn messages regardless of `liveout`.
+    pub(crate) fn set_and_send_live_messages(
+        &self,
+        snapshot: impl FnOnce(&RytmProject) -> LiveSnapshot,
+        set: impl FnOnce() -> Result<(), RytmExternalError>,
+    ) -> Result<(), RytmExternalError> {
+        let before = snapshot(&self.project.lock_or_recover());
+        set()?;
+        let messages = before.messages(&self.project.lock_or_recover())?;
+        if !messages.is_empty() {
+            self.outputs.push(Output::Sysex(messages));
+        }
+
+        Ok(())
+    }
+
+    fn get(&self, atoms: &[Atom]) -> Result<(), RytmExternalError> {
+        // Indexable objects look for an index as the second atom thus they'd throw an error here.
+        let indexable = ObjectTypeSelector::try_from((
+            atoms.first().ok_or_else(|| {
+                GetError::InvalidFormat(
+                    "Getter is incomplete. No other elements follow the get call.".to_owned(),
+                )
+            })?,
+            None,
+        ))
+        .is_err();
+
+        let atom_pair = match (atoms.first(), atoms.get(1)) {
+            (None, Some(_) | None) => Err(GetError::InvalidFormat(
+                "Getter is incomplete. No other elements follow the get call.".to_owned(),
+            )),
+            _ => {
+                if indexable {
+                    Ok((atoms.first().unwrap(), atoms.get(1)))
+                } else {
+                    Ok((atoms.first().unwrap(), None))
+                }
+            }
+        }?;
+
+        let object = ObjectTypeSelector::try_from(atom_pair)?;
+        (object.handlers().get)(self, atoms, object.index())
+    }
+}
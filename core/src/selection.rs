@@ -0,0 +1,261 @@
+use crate::atom::{Atom, AtomValue};
+use crate::{
+    action::{
+        plock::handle_trig_plock_setter_action,
+        set::{track::track_set, trig::trig_set},
+    },
+    address::{validate_index, PatternSlot},
+    api::plock_type::ALL_PLOCK_TYPES,
+    error::RytmExternalError,
+    load::{plock_value, set_plock},
+    util::{
+        get_plock_sound_for_track, link_plock_sounds, string_from_atom_slice,
+        try_get_atom_value_assuming_identifier_or_index_or_enum_value,
+    },
+};
+use rytm_rs::prelude::*;
+
+pub const SELECT_ALL: &str = "all";
+pub const SELECT_EVERY: &str = "every";
+const RANGE_SEPARATOR: &str = "..";
+const LIST_SEPARATOR: char = '|';
+
+/// Tracks of a pattern which rytm-rs exposes, the fx track of the raw pattern is not one of them.
+pub const TRACK_COUNT: usize = 12;
+pub const TRIG_COUNT: usize = 64;
+
+/// Indices of tracks or trigs which are selected by a setter.
+///
+/// Selectors are a single index `3`, an inclusive range `0..15`, a list `0|4|8`, `every 4` or `all`.
+#[derive(Debug, Clone)]
+pub struct IndexSelection {
+    pub indices: Vec<usize>,
+    /// The index of the atom which follows the selector.
+    pub next: usize,
+}
+
+impl IndexSelection {
+    /// Whether the atom at the given index selects many indices, a single index is an address instead.
+    pub fn selects_many(atoms: &[Atom], at: usize) -> bool {
+        match atoms.get(at).and_then(Atom::get_value) {
+            Some(AtomValue::Symbol(symbol)) => {
+                let selector = symbol.to_string();
+                selector == SELECT_ALL
+                    || selector == SELECT_EVERY
+                    || selector.contains(RANGE_SEPARATOR)
+                    || selector.contains(LIST_SEPARATOR)
+            }
+            _ => false,
+        }
+    }
+
+    /// Parses the selector at the given atom index, symbols which are not selectors are left for identifiers and enums.
+    pub fn try_parse(
+        atoms: &[Atom],
+        at: usize,
+        count: usize,
+        name: &str,
+    ) -> Result<Option<Self>, RytmExternalError> {
+        let index = |value: isize| validate_index(value, count, name);
+        let parse = |value: &str| -> Result<usize, RytmExternalError> {
+            value.parse::<isize>().map_err(|_| {
+                RytmExternalError::from(format!(
+                    "Invalid {name} selector: {}. {name} selectors should be an index, a range like 0..15, a list like 0|4|8, every <step> or all.",
+                    string_from_atom_slice(atoms)
+                ))
+            }).and_then(index)
+        };
+
+        let selector = match atoms.get(at).and_then(Atom::get_value) {
+            Some(AtomValue::Int(value)) => {
+                return Ok(Some(Self {
+                    indices: vec![index(value)?],
+                    next: at + 1,
+                }))
+            }
+            Some(AtomValue::Symbol(symbol)) => symbol.to_string(),
+            _ => return Ok(None),
+        };
+
+        let indices = if selector == SELECT_ALL {
+            (0..count).collect()
+        } else if selector == SELECT_EVERY {
+            let step = match atoms.get(at + 1).and_then(Atom::get_value) {
+                Some(AtomValue::Int(step)) if step > 0 => step as usize,
+                _ => {
+                    return Err(format!(
+                        "Invalid format: {}. every should be followed by a step which is a positive integer. Example: every 4",
+                        string_from_atom_slice(atoms)
+                    )
+                    .into())
+                }
+            };
+            return Ok(Some(Self {
+                indices: (0..count).step_by(step).collect(),
+                next: at + 2,
+            }));
+        } else if let Some((start, end)) = selector.split_once(RANGE_SEPARATOR) {
+            let (start, end) = (parse(start)?, parse(end)?);
+            if start > end {
+                return Err(format!("Invalid {name} range: {selector}. The start of a range can not be larger than its end.").into());
+            }
+            (start..=end).collect()
+        } else if selector.contains(LIST_SEPARATOR) {
+            selector
+                .split(LIST_SEPARATOR)
+                .map(parse)
+                .collect::<Result<Vec<_>, _>>()?
+        } else {
+            return Ok(None);
+        };
+
+        Ok(Some(Self {
+            indices,
+            next: at + 1,
+        }))
+    }
+}
+
+/// Applies a track or trig setter to every selected track and trig of a pattern under a single lock of the project.
+pub fn set_pattern_selection(
+    project: &mut RytmProject,
+    slot: PatternSlot,
+    tracks: &IndexSelection,
+    atoms: &[Atom],
+) -> Result<(), RytmExternalError> {
+    let trigs = IndexSelection::try_parse(atoms, tracks.next, TRIG_COUNT, "Trig")?;
+    let select = trigs.as_ref().map_or(tracks.next, |trigs| trigs.next);
+    set_pattern_elements(
+        project,
+        slot,
+        (
+            &tracks.indices,
+            trigs.as_ref().map(|trigs| trigs.indices.as_slice()),
+        ),
+        atoms,
+        select,
+    )
+}
+
+/// Applies the setter at `select` to the given tracks, or to the given trigs of each of them.
+///
+/// The setters are applied to a copy of the pattern which replaces the original only if all of them succeed.
+/// Parameter locks live in the pool which the pattern shares with its copy, so the ones which are already set are
+/// reverted if any of them fails.
+pub fn set_pattern_elements(
+    project: &mut RytmProject,
+    slot: PatternSlot,
+    (tracks, trigs): (&[usize], Option<&[usize]>),
+    atoms: &[Atom],
+    select: usize,
+) -> Result<(), RytmExternalError> {
+    let AtomValue::Symbol(action_or_enum_value) =
+        try_get_atom_value_assuming_identifier_or_index_or_enum_value(select, atoms)?
+    else {
+        return Err(format!(
+            "Invalid format: {}. The selection should be followed by an identifier or an enum.",
+            string_from_atom_slice(atoms)
+        )
+        .into());
+    };
+
+    let Some(trigs) = trigs else {
+        let mut pattern = slot.pattern(project).clone();
+        for &track_index in tracks {
+            track_set(
+                action_or_enum_value.clone(),
+                &mut pattern.tracks_mut()[track_index],
+                atoms,
+                select + 1,
+            )?;
+        }
+        *slot.pattern_mut(project) = pattern;
+        return Ok(());
+    };
+
+    let action_or_enum_value_str = action_or_enum_value.to_string();
+    if ALL_PLOCK_TYPES.contains(&action_or_enum_value_str.as_str()) {
+        link_plock_sounds(project, slot.index())?;
+        return set_plock_selection(
+            project,
+            slot,
+            (tracks, trigs),
+            &action_or_enum_value_str,
+            atoms,
+            select,
+        );
+    }
+
+    let mut pattern = slot.pattern(project).clone();
+    for &track_index in tracks {
+        for &trig_index in trigs {
+            trig_set(
+                action_or_enum_value.clone(),
+                &mut pattern.tracks_mut()[track_index].trigs_mut()[trig_index],
+                atoms,
+                select + 1,
+            )?;
+        }
+    }
+    *slot.pattern_mut(project) = pattern;
+
+    Ok(())
+}
+
+fn set_plock_selection(
+    project: &RytmProject,
+    slot: PatternSlot,
+    (tracks, trigs): (&[usize], &[usize]),
+    selector: &str,
+    atoms: &[Atom],
+    select: usize,
+) -> Result<(), RytmExternalError> {
+    // Selecting a single trig needs no reverting.
+    if let ([track_index], [trig_index]) = (tracks, trigs) {
+        let track_index = *track_index;
+        let trig = &slot.pattern(project).tracks()[track_index].trigs()[*trig_index];
+        let sound = get_plock_sound_for_track(project, slot.index(), track_index);
+        return handle_trig_plock_setter_action(trig, sound, selector, atoms, select + 1);
+    }
+
+    // The identifier or the type of the enum which is locked.
+    let key = match atoms.get(select + 1).and_then(Atom::get_value) {
+        Some(AtomValue::Symbol(symbol)) => {
+            let symbol = symbol.to_string();
+            symbol
+                .split_once(':')
+                .map_or_else(|| symbol.clone(), |(enum_type, _)| enum_type.to_owned())
+        }
+        _ => {
+            return Err(
+                "Invalid plock setter format: The list must be followed by either an identifier or enum value.".into(),
+            )
+        }
+    };
+
+    let mut applied = Vec::new();
+    for &track_index in tracks {
+        let sound = get_plock_sound_for_track(project, slot.index(), track_index);
+        for &trig_index in trigs {
+            let trig = &slot.pattern(project).tracks()[track_index].trigs()[trig_index];
+
+            let result = plock_value(trig, sound, &key).and_then(|previous| {
+                handle_trig_plock_setter_action(trig, sound, selector, atoms, select + 1)?;
+                Ok(previous)
+            });
+
+            match result {
+                Ok(previous) => applied.push((trig, sound, previous)),
+                Err(err) => {
+                    for (trig, sound, previous) in applied.into_iter().rev() {
+                        // Reverting restores values which were already in the pool thus it can't fail.
+                        let _ = set_plock(trig, sound, &key, previous.as_ref());
+                    }
+                    return Err(err);
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
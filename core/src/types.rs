@@ -0,0 +1,256 @@
+use crate::atom::SymbolRef;
+use crate::atom::{Atom, AtomValue};
+use crate::error::QueryError::InvalidFormat;
+use crate::error::QueryError::InvalidIndexType;
+use crate::error::QueryError::InvalidSelector;
+use crate::{api::object_type::*, error::RytmExternalError};
+use rytm_rs::SysexType;
+use std::convert::TryFrom;
+
+// Byte positions in the header of a sysex dump which is sent by the device.
+pub const SYSEX_DEVICE_ID_BYTE_INDEX: usize = 5;
+pub const SYSEX_DUMP_ID_BYTE_INDEX: usize = 6;
+pub const SYSEX_OBJECT_NUMBER_BYTE_INDEX: usize = 9;
+// Dump ids starting from this value are targeting the stored objects.
+const SYSEX_DUMP_ID_BASE: u8 = 0x52;
+// Dump ids starting from this value are targeting the work buffer.
+const SYSEX_WORK_BUFFER_DUMP_ID_BASE: u8 = 0x58;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ObjectTypeSelector {
+    Pattern(usize),
+    PatternWorkBuffer,
+    Kit(usize),
+    KitWorkBuffer,
+    Sound(usize),
+    SoundWorkBuffer(usize),
+    Global(usize),
+    GlobalWorkBuffer,
+    Settings,
+}
+
+impl ObjectTypeSelector {
+    pub const fn indexable(&self) -> bool {
+        matches!(
+            self,
+            Self::Pattern(_)
+                | Self::Kit(_)
+                | Self::Sound(_)
+                | Self::SoundWorkBuffer(_)
+                | Self::Global(_)
+        )
+    }
+
+    pub const fn is_work_buffer(&self) -> bool {
+        matches!(
+            self,
+            Self::PatternWorkBuffer
+                | Self::KitWorkBuffer
+                | Self::SoundWorkBuffer(_)
+                | Self::GlobalWorkBuffer
+        )
+    }
+
+    /// The index of the object, non indexable objects are always at index 0.
+    pub const fn index(&self) -> usize {
+        match self {
+            Self::Pattern(index)
+            | Self::Kit(index)
+            | Self::Sound(index)
+            | Self::SoundWorkBuffer(index)
+            | Self::Global(index) => *index,
+            _ => 0,
+        }
+    }
+
+    pub fn object_type(&self) -> SymbolRef {
+        match self {
+            Self::Pattern(_) => PATTERN.clone(),
+            Self::PatternWorkBuffer => PATTERN_WORK_BUFFER.clone(),
+            Self::Kit(_) => KIT.clone(),
+            Self::KitWorkBuffer => KIT_WORK_BUFFER.clone(),
+            Self::Sound(_) => SOUND.clone(),
+            Self::SoundWorkBuffer(_) => SOUND_WORK_BUFFER.clone(),
+            Self::Global(_) => GLOBAL.clone(),
+            Self::GlobalWorkBuffer => GLOBAL_WORK_BUFFER.clone(),
+            Self::Settings => SETTINGS.clone(),
+        }
+    }
+
+    pub const fn sysex_type(&self) -> SysexType {
+        match self {
+            Self::Pattern(_) | Self::PatternWorkBuffer => SysexType::Pattern,
+            Self::Kit(_) | Self::KitWorkBuffer => SysexType::Kit,
+            Self::Sound(_) | Self::SoundWorkBuffer(_) => SysexType::Sound,
+            Self::Global(_) | Self::GlobalWorkBuffer => SysexType::Global,
+            Self::Settings => SysexType::Settings,
+        }
+    }
+
+    /// Returns the object of the same type which is `offset` objects after this one, e.g. `sound 3` offset by 2 is `sound 5`.
+    pub fn offset_by(&self, offset: usize) -> Result<Self, RytmExternalError> {
+        let index = self.index() + offset;
+        match self {
+            _ if offset == 0 => Ok(*self),
+            Self::Pattern(_) if index <= 127 => Ok(Self::Pattern(index)),
+            Self::Kit(_) if index <= 127 => Ok(Self::Kit(index)),
+            Self::Sound(_) if index <= 127 => Ok(Self::Sound(index)),
+            Self::SoundWorkBuffer(_) if index <= 11 => Ok(Self::SoundWorkBuffer(index)),
+            Self::Global(_) if index <= 3 => Ok(Self::Global(index)),
+            _ if self.indexable() => {
+                Err(format!("Invalid index: The object at index {index} does not exist.").into())
+            }
+            _ => Err("Invalid index: This object type holds a single object.".into()),
+        }
+    }
+
+    /// Rewrites the header of a sysex dump of the same object type so it targets this object instead.
+    ///
+    /// The checksum of a dump only covers its data so the header can be changed freely.
+    pub fn retarget_sysex_response(&self, response: &mut [u8]) -> Result<(), RytmExternalError> {
+        let source = Self::try_from_sysex_response(response)?;
+        if source.sysex_type() != self.sysex_type() {
+            return Err(format!(
+                "Invalid sysex response: A {:?} dump can not be loaded to a {:?}.",
+                source.sysex_type(),
+                self.sysex_type()
+            )
+            .into());
+        }
+
+        let base = |work_buffer: bool| {
+            if work_buffer {
+                SYSEX_WORK_BUFFER_DUMP_ID_BASE
+            } else {
+                SYSEX_DUMP_ID_BASE
+            }
+        };
+        let type_offset = response[SYSEX_DUMP_ID_BYTE_INDEX] - base(source.is_work_buffer());
+        response[SYSEX_DUMP_ID_BYTE_INDEX] = base(self.is_work_buffer()) + type_offset;
+        if self.indexable() {
+            response[SYSEX_OBJECT_NUMBER_BYTE_INDEX] = self.index() as u8;
+        }
+
+        Ok(())
+    }
+
+    /// Reads the type and the index of the object from the header of a sysex dump which is sent by the device.
+    pub fn try_from_sysex_response(response: &[u8]) -> Result<Self, RytmExternalError> {
+        let (Some(&dump_id), Some(&object_number)) = (
+            response.get(SYSEX_DUMP_ID_BYTE_INDEX),
+            response.get(SYSEX_OBJECT_NUMBER_BYTE_INDEX),
+        ) else {
+            return Err(
+                "Invalid sysex response: The message is too short to be a sysex dump of rytm."
+                    .into(),
+            );
+        };
+
+        let index = object_number as usize;
+        let work_buffer = dump_id >= SYSEX_WORK_BUFFER_DUMP_ID_BASE;
+
+        match (SysexType::try_from_dump_id(dump_id)?, work_buffer) {
+            (SysexType::Pattern, false) => Ok(Self::Pattern(index)),
+            (SysexType::Pattern, true) => Ok(Self::PatternWorkBuffer),
+            (SysexType::Kit, false) => Ok(Self::Kit(index)),
+            (SysexType::Kit, true) => Ok(Self::KitWorkBuffer),
+            (SysexType::Sound, false) => Ok(Self::Sound(index)),
+            (SysexType::Sound, true) => Ok(Self::SoundWorkBuffer(index)),
+            (SysexType::Global, false) => Ok(Self::Global(index)),
+            (SysexType::Global, true) => Ok(Self::GlobalWorkBuffer),
+            (SysexType::Settings, _) => Ok(Self::Settings),
+            (SysexType::Song, _) => {
+                Err("Invalid sysex response: Songs are not supported yet.".into())
+            }
+        }
+    }
+}
+
+impl TryFrom<(&Atom, Option<&Atom>)> for ObjectTypeSelector {
+    type Error = RytmExternalError;
+
+    fn try_from((sel, index): (&Atom, Option<&Atom>)) -> Result<Self, Self::Error> {
+        match sel.get_value() {
+            Some(AtomValue::Symbol(selector_sym)) => {
+                if selector_sym == *PATTERN {
+                    index.map_or_else(
+                        || Err(InvalidFormat.into()),
+                        |atom| match atom.get_value() {
+                            Some(AtomValue::Int(index)) => match index {
+                                0..=127 => Ok(Self::Pattern(index as usize)),
+                                _ => {
+                                    Err("Pattern index must be an integer between 0 and 127".into())
+                                }
+                            },
+                            _ => Err(InvalidIndexType.into()),
+                        },
+                    )
+                } else if selector_sym == *PATTERN_WORK_BUFFER {
+                    Ok(Self::PatternWorkBuffer)
+                } else if selector_sym == *KIT {
+                    index.map_or_else(
+                        || Err(InvalidFormat.into()),
+                        |atom| match atom.get_value() {
+                            Some(AtomValue::Int(index)) => match index {
+                                0..=127 => Ok(Self::Kit(index as usize)),
+                                _ => Err("Kit index must be an integer between 0 and 127".into()),
+                            },
+                            _ => Err(InvalidIndexType.into()),
+                        },
+                    )
+                } else if selector_sym == *KIT_WORK_BUFFER {
+                    Ok(Self::KitWorkBuffer)
+                } else if selector_sym == *SOUND {
+                    index.map_or_else(
+                        || Err(InvalidFormat.into()),
+                        |atom| match atom.get_value() {
+                            Some(AtomValue::Int(index)) => match index {
+                                0..=127 => Ok(Self::Sound(index as usize)),
+                                _ => {
+                                    Err("Pool sound index must be an integer between 0 and 127"
+                                        .into())
+                                }
+                            },
+                            _ => Err(InvalidIndexType.into()),
+                        },
+                    )
+                } else if selector_sym == *SOUND_WORK_BUFFER {
+                    index.map_or_else(
+                        || Err(InvalidFormat.into()),
+                        |atom| match atom.get_value() {
+                            Some(AtomValue::Int(index)) => match index {
+                                0..=11 => Ok(Self::SoundWorkBuffer(index as usize)),
+                                _ => Err(
+                                    "Work buffer sound index must be an integer between 0 and 11"
+                                        .into(),
+                                ),
+                            },
+                            _ => Err(InvalidIndexType.into()),
+                        },
+                    )
+                } else if selector_sym == *GLOBAL {
+                    index.map_or_else(
+                        || Err(InvalidFormat.into()),
+                        |atom| match atom.get_value() {
+                            Some(AtomValue::Int(index)) => match index {
+                                0..=3 => Ok(Self::Global(index as usize)),
+                                _ => {
+                                    Err("Global slot index must be an integer between 0 and 3"
+                                        .into())
+                                }
+                            },
+                            _ => Err(InvalidIndexType.into()),
+                        },
+                    )
+                } else if selector_sym == *GLOBAL_WORK_BUFFER {
+                    Ok(Self::GlobalWorkBuffer)
+                } else if selector_sym == *SETTINGS {
+                    Ok(Self::Settings)
+                } else {
+                    Err(InvalidSelector.into())
+                }
+            }
+            _ => Err(InvalidSelector.into()),
+        }
+    }
+}
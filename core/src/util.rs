@@ -0,0 +1,431 @@
+use std::convert::TryFrom;
+
+use crate::api::kit_element_type::KIT_ELEMENTS;
+use crate::api::kit_enum_type::KIT_ENUM_TYPES;
+use crate::atom::{Atom, AtomType, AtomValue, SymbolRef};
+use crate::edit::encode_pattern_for_redecode;
+use crate::error::RytmExternalError;
+use crate::types::ObjectTypeSelector;
+use crate::{
+    api::kit_action_type::{KIT_ACTION_TYPES, KIT_READ_ONLY_ACTION_TYPES},
+    error::IdentifierError::InvalidParameter,
+};
+use rytm_rs::{
+    object::{global::types::MidiChannel, sound::types::MachineType, Kit, Sound},
+    prelude::SysexCompatible,
+    RytmProject,
+};
+use serde_json::Value;
+use std::path::PathBuf;
+use std::sync::{Mutex, MutexGuard};
+
+pub fn try_get_index_with_range(
+    atoms: &[Atom],
+    select: usize,
+    min: usize,
+    max: usize,
+    identifier: &str,
+) -> Result<usize, RytmExternalError> {
+    atoms.get(select).map_or_else(
+        || Err(format!("Invalid format: An integer index should follow the {identifier}.").into()),
+        |atom| {
+            atom.get_value().map_or_else(
+                || {
+                    Err(
+                        format!("Invalid format: An integer index should follow the {identifier}.")
+                            .into(),
+                    )
+                },
+                |value| match value {
+                    AtomValue::Int(index) => {
+                        if index < min as isize || index > max as isize {
+                            Err(format!(
+                                "Invalid format: The {identifier} must be between {min} and {max}.",
+                            )
+                            .into())
+                        } else {
+                            Ok(index as usize)
+                        }
+                    }
+                    _ => Err(format!(
+                        "Invalid format: An integer index should follow the {identifier}."
+                    )
+                    .into()),
+                },
+            )
+        },
+    )
+}
+
+pub enum KitElementOrActionOrEnumTypeAndValue {
+    KitElement(String),
+    Action(SymbolRef),
+    EnumTypeAndValue(String, String),
+    KitSound,
+}
+
+impl TryFrom<SymbolRef> for KitElementOrActionOrEnumTypeAndValue {
+    type Error = RytmExternalError;
+
+    fn try_from(value: SymbolRef) -> Result<Self, Self::Error> {
+        let value_str = value.to_string();
+        if KIT_ACTION_TYPES.contains(&value_str.as_str())
+            || KIT_READ_ONLY_ACTION_TYPES.contains(&value_str.as_str())
+        {
+            Ok(Self::Action(value))
+        } else if value_str.contains(':') {
+            let (t, v) = value_str.split_once(':').ok_or_else(||RytmExternalError::from("Invalid value: A kit setter should be followed by either a kit element or action or enum."))?;
+            if KIT_ENUM_TYPES.contains(&t) {
+                return Ok(Self::EnumTypeAndValue(t.to_owned(), v.to_owned()));
+            }
+            Err(format!("Invalid value: {value}. A kit setter or getter should be followed by either a kit element, identifier or enum.").into())
+        } else if KIT_ELEMENTS.contains(&value_str.as_str()) {
+            if value_str == crate::api::kit_element_type::SOUND {
+                return Ok(Self::KitSound);
+            }
+            Ok(Self::KitElement(value_str))
+        } else {
+            Err(format!("Invalid value: {value}. A kit setter or getter should be followed by either a kit element, identifier or enum.").into())
+        }
+    }
+}
+
+pub fn try_get_atom_value_as_kit_element_or_identifier_or_enum_value(
+    index: usize,
+    atoms: &[Atom],
+) -> Result<KitElementOrActionOrEnumTypeAndValue, RytmExternalError> {
+    if let Some(atom) = atoms.get(index) {
+        if let Some(value) = atom.get_value() {
+            match value {
+                    AtomValue::Symbol(symbol) => {
+                        Ok(KitElementOrActionOrEnumTypeAndValue::try_from(symbol)?)
+                    },
+                     _ => Err(format!("Invalid format: {}. A kit setter or getter should be followed by either a kit element, identifier or enum.", string_from_atom_slice(atoms)).into()),
+                }
+        } else {
+            Err(format!("Invalid format: {}. The list should be followed by either a kit element, identifier or enum.", string_from_atom_slice(atoms)).into())
+        }
+    } else {
+        Err(format!("Invalid format: {}. The list should be followed by either a kit element, identifier or enum.", string_from_atom_slice(atoms)).into())
+    }
+}
+
+pub fn try_get_atom_value_assuming_identifier_or_index_or_enum_value(
+    index: usize,
+    atoms: &[Atom],
+) -> Result<AtomValue, RytmExternalError> {
+    atoms.get(index).map_or_else(
+        || {
+            Err(format!(
+                "Invalid format: {}. The list should be followed by an identifier, an index or an enum.",
+                string_from_atom_slice(atoms)
+            )
+            .into())
+        },
+        |atom| {
+            atom.get_value().map_or_else(
+                || {
+                    Err(format!(
+                        "Invalid format: {}. The list should be followed by an identifier, an index or an enum.",
+                        string_from_atom_slice(atoms)
+                    )
+                    .into())
+                },
+                Ok,
+            )
+        },
+    )
+}
+
+pub fn try_get_identifier_value_from_atom_slice(
+    index: usize,
+    atoms: &[Atom],
+) -> Result<&Atom, RytmExternalError> {
+    atoms.get(index).map_or_else(
+        || {
+            Err(format!(
+                "Invalid format: {}. The identifier should be followed by a parameter.",
+                string_from_atom_slice(atoms)
+            )
+            .into())
+        },
+        |atom| {
+            if atom.get_value().is_some() {
+                Ok(atom)
+            } else {
+                Err(format!(
+                    "Invalid format: {}. The identifier should be followed by a parameter.",
+                    string_from_atom_slice(atoms)
+                )
+                .into())
+            }
+        },
+    )
+}
+
+/// Gets a bool from 0 or 1 as an atom.
+pub fn get_bool_from_0_or_1(value: &Atom, identifier: &str) -> Result<bool, RytmExternalError> {
+    match value.get_value().ok_or_else(|| {
+        RytmExternalError::from(format!(
+            "Invalid parameter: {identifier} must be followed by a 0 or 1"
+        ))
+    })? {
+        AtomValue::Int(value) => match value {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(format!(
+                "Invalid parameter: {value}. {identifier} must be followed by a 0 or 1"
+            )
+            .into()),
+        },
+        _ => Err(format!(
+            "Invalid parameter: {}. {identifier} must be followed by a 0 or 1",
+            string_from_atom(value)
+        )
+        .into()),
+    }
+}
+
+pub fn only_allow_numbers_as_identifier_parameter(atom: &Atom) -> Result<(), RytmExternalError> {
+    match atom.get_type() {
+        Some(AtomType::Symbol) | None => Err(InvalidParameter(
+            "Allowed parameters are integers or floats.".to_owned(),
+            string_from_atom(atom),
+        )
+        .into()),
+        _ => Ok(()),
+    }
+}
+
+pub fn string_from_atom_slice(atoms: &[Atom]) -> String {
+    if atoms.is_empty() {
+        return String::new();
+    }
+
+    let mut string = String::new();
+    for atom in atoms {
+        string.push_str(&atom.to_string());
+        string.push(' ');
+    }
+
+    string
+}
+
+pub fn string_from_atom(atom: &Atom) -> String {
+    atom.to_string()
+}
+
+/// Parses a midi channel from the enum value of a channel, `1..=16`, `auto` or `off`.
+///
+/// rytm-rs parses channels from `0..=15` but outputs them as `1..=16`, so they are converted here to read back the same.
+pub fn midi_channel_from_str(
+    value: &str,
+    enum_type: &str,
+) -> Result<MidiChannel, RytmExternalError> {
+    match value.parse::<usize>() {
+        Ok(channel @ 1..=16) => Ok(MidiChannel::Channel(channel - 1)),
+        Ok(_) => Err(format!(
+            "Invalid enum value: {value}. {enum_type} must be a channel between 1 and 16, auto or off."
+        )
+        .into()),
+        Err(_) => Ok(MidiChannel::try_from(value)?),
+    }
+}
+
+/// Finds the sound which the machine parameter locks of a pattern track are reached through.
+///
+/// Patterns reach them through the kit they use, the work buffer pattern (`None`) through the work buffer sounds.
+/// Call [`link_plock_sounds`] for the pattern first so the sound reaches the parameter locks of that pattern.
+pub fn get_plock_sound_for_track(
+    project: &RytmProject,
+    maybe_pattern_index: Option<usize>,
+    track_index: usize,
+) -> Option<&Sound> {
+    let Some(pattern_index) = maybe_pattern_index else {
+        return project.work_buffer().sounds().get(track_index);
+    };
+
+    project
+        .kits()
+        .get(kit_number_of_pattern(project, pattern_index)?)?
+        .sounds()
+        .get(track_index)
+}
+
+/// Links the sounds which the machine parameter locks of a pattern are reached through to the pattern.
+///
+/// rytm-rs links a kit only to the last decoded pattern which uses it and the kits of a default project to none,
+/// so patterns which share a kit would read and write each others locks. Decoding the pattern again links its kit
+/// to it in the same way as when it is received from the device.
+/// The work buffer sounds are linked to the work buffer pattern when they are decoded, which also assigns their
+/// machine parameters to their tracks again.
+pub fn link_plock_sounds(
+    project: &mut RytmProject,
+    maybe_pattern_index: Option<usize>,
+) -> Result<(), RytmExternalError> {
+    let Some(pattern_index) = maybe_pattern_index else {
+        for index in 0..project.work_buffer().sounds().len() {
+            let sound = &project.work_buffer().sounds()[index];
+            // Sounds without a machine have no machine parameters to lock and rytm-rs can't encode them once decoded.
+            if matches!(sound.machine_type(), MachineType::Unset) {
+                continue;
+            }
+            // Encoded sounds carry the id of the sound pool so they're retargeted to the work buffer.
+            let mut sysex = sound.as_sysex()?;
+            ObjectTypeSelector::SoundWorkBuffer(index).retarget_sysex_response(&mut sysex)?;
+            project.update_from_sysex_response(&sysex)?;
+        }
+        return Ok(());
+    };
+
+    // A pattern without a kit has no sounds to link.
+    let Some(kit_number) = kit_number_of_pattern(project, pattern_index) else {
+        return Ok(());
+    };
+    assign_machine_parameters_to_tracks(&mut project.kits_mut()[kit_number])?;
+    let sysex = encode_pattern_for_redecode(&project.patterns()[pattern_index])?;
+    project.update_from_sysex_response(&sysex)?;
+
+    Ok(())
+}
+
+/// Assigns the machine parameters of the sounds of a kit to the tracks of the sounds.
+///
+/// Machine parameters only reach the parameter locks of the track they're assigned to. rytm-rs assigns them when a
+/// kit is decoded, not the ones of a default kit or the ones which replace them when the machine of a sound changes.
+/// Decoding the kit again would assign them but kits don't decode to the same values they're encoded from.
+fn assign_machine_parameters_to_tracks(kit: &mut Kit) -> Result<(), RytmExternalError> {
+    for (track_index, sound) in kit.sounds_mut().iter_mut().enumerate() {
+        let mut value = serde_json::to_value(sound.machine_parameters())?;
+        // Machine parameters are serialized in the variant of their machine, the ones without a machine have none.
+        let Some(parameters) = value
+            .as_object_mut()
+            .and_then(|variant| variant.values_mut().next())
+            .and_then(Value::as_object_mut)
+        else {
+            continue;
+        };
+        if parameters.get("assigned_track") == Some(&Value::from(track_index)) {
+            continue;
+        }
+        parameters.insert("assigned_track".to_owned(), Value::from(track_index));
+        *sound.machine_parameters_mut() = serde_json::from_value(value)?;
+    }
+
+    Ok(())
+}
+
+/// The kit which a pattern uses, when the kit is not set the pattern uses the kit which the work buffer pattern uses.
+pub fn kit_number_of_pattern(project: &RytmProject, pattern_index: usize) -> Option<usize> {
+    let mut kit_number = project.patterns().get(pattern_index)?.kit_number();
+    if kit_number == 0xFF {
+        kit_number = project.work_buffer().pattern().kit_number();
+    }
+
+    (kit_number < project.kits().len()).then_some(kit_number)
+}
+
+/// Reads a file path from the atom at the given index.
+///
+/// Max passes paths in the format of `<volume>:/<path>` on macOS, these are converted to regular paths.
+pub fn try_get_path_from_atom_slice(
+    index: usize,
+    atoms: &[Atom],
+) -> Result<PathBuf, RytmExternalError> {
+    let path = match atoms.get(index).and_then(Atom::get_value) {
+        Some(AtomValue::Symbol(path)) => path.to_string(),
+        _ => {
+            return Err(
+                "Invalid path: A file path should be provided as a symbol. Example: /path/to/project.json"
+                    .into(),
+            )
+        }
+    };
+
+    if cfg!(target_os = "macos") {
+        if let Some((volume, rest)) = path.split_once(":/") {
+            if !volume.contains('/') {
+                return Ok(PathBuf::from(format!("/{rest}")));
+            }
+        }
+    }
+
+    Ok(PathBuf::from(path))
+}
+
+/// Encodes a kit as a sysex dump, use this instead of `Kit::as_sysex` which panics in rytm-rs 0.1.1.
+///
+/// A kit keeps a retrig menu for each of the 13 tracks while the raw kit only has room for 12 of them.
+/// The menu of the fx track is replaced with a copy of the menu of the last track which encodes to the same bytes.
+pub fn kit_as_sysex(kit: &Kit) -> Result<Vec<u8>, RytmExternalError> {
+    let mut value = serde_json::to_value(kit)?;
+    if let Some(menus) = value
+        .get_mut("track_retrig_settings")
+        .and_then(Value::as_array_mut)
+    {
+        if let (Some(last_track), Some(fx_track)) = (menus.get(11).cloned(), menus.get_mut(12)) {
+            *fx_track = last_track;
+        }
+    }
+
+    // Kits borrow strings while they're deserialized which a json value can not lend.
+    let kit: Kit = serde_json::from_slice(&serde_json::to_vec(&value)?)?;
+    Ok(kit.as_sysex()?)
+}
+
+/// Decodes the 7 bit encoded payload of a sysex dump to the raw bytes of the object.
+///
+/// The payload starts after the 10 byte header and ends before the checksum, length and the end byte.
+pub fn decode_sysex_payload(sysex: &[u8]) -> Vec<u8> {
+    sysex[10..sysex.len() - 5]
+        .chunks(8)
+        .flat_map(|chunk| {
+            let msbs = chunk[0];
+            chunk[1..]
+                .iter()
+                .enumerate()
+                .map(move |(i, &byte)| byte | ((msbs << (i + 1)) & 0x80))
+        })
+        .collect()
+}
+
+/// Encodes the raw bytes of an object back to the sysex dump they're decoded from.
+pub fn encode_sysex_payload(sysex: &[u8], raw: &[u8]) -> Vec<u8> {
+    let mut payload = Vec::with_capacity(sysex.len() - 15);
+    for chunk in raw.chunks(7) {
+        payload.push(
+            chunk
+                .iter()
+                .enumerate()
+                .fold(0, |msbs, (i, &byte)| msbs | ((byte & 0x80) >> (i + 1))),
+        );
+        payload.extend(chunk.iter().map(|byte| byte & 0x7F));
+    }
+
+    // Only the lower 14 bits of the sum are kept.
+    let checksum = payload.iter().map(|&byte| u32::from(byte)).sum::<u32>();
+
+    let mut encoded = sysex[..10].to_vec();
+    encoded.extend(payload);
+    encoded.extend([((checksum >> 7) & 0x7F) as u8, (checksum & 0x7F) as u8]);
+    // The data size and the end byte stay the same.
+    encoded.extend(&sysex[sysex.len() - 3..]);
+    encoded
+}
+
+/// Locking which recovers a mutex that is poisoned by a panic while it was locked.
+///
+/// The data is kept as the panic left it and the poison is cleared, so one failed message does not make every
+/// following message which locks the same data fail or abort the host.
+pub trait LockOrRecover<T> {
+    fn lock_or_recover(&self) -> MutexGuard<'_, T>;
+}
+
+impl<T> LockOrRecover<T> for Mutex<T> {
+    fn lock_or_recover(&self) -> MutexGuard<'_, T> {
+        self.lock().unwrap_or_else(|poisoned| {
+            self.clear_poison();
+            poisoned.into_inner()
+        })
+    }
+}
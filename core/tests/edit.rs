@@ -0,0 +1,126 @@
+use rytm_core::rytm::Rytm;
+use rytm_rs::object::pattern::Trig;
+use rytm_rs::prelude::HoldsTrigFlags;
+
+/// Runs `check` with a trig of a pattern as the typed rytm-rs trig.
+fn with_trig<T>(
+    rytm: &Rytm,
+    pattern: usize,
+    track: usize,
+    trig: usize,
+    check: impl FnOnce(&Trig) -> T,
+) -> T {
+    let project = rytm.project.lock().unwrap();
+    check(&project.patterns()[pattern].tracks()[track].trigs()[trig])
+}
+
+/// The filter cutoff lock of a trig.
+///
+/// rytm-rs returns the unused marker `0xFF` for unlocked trigs of a track which has other trigs with the same lock.
+fn cutoff_lock(trig: &Trig) -> Option<usize> {
+    trig.plock_get_filter_cutoff()
+        .unwrap()
+        .filter(|&value| value != 0xFF)
+}
+
+fn enabled_trigs(rytm: &Rytm, pattern: usize, track: usize) -> Vec<usize> {
+    let project = rytm.project.lock().unwrap();
+    project.patterns()[pattern].tracks()[track]
+        .trigs()
+        .iter()
+        .filter(|trig| trig.enabled_trig())
+        .map(Trig::index)
+        .collect()
+}
+
+#[test]
+fn pasted_trigs_keep_their_values_and_parameter_locks() {
+    let rytm = Rytm::default();
+
+    rytm.message("set pattern 1 2 3 enable 1").unwrap();
+    rytm.message("set pattern 1 2 3 note 40").unwrap();
+    rytm.message("set pattern 1 2 3 plockset filtcutoff 64")
+        .unwrap();
+    rytm.message("copy pattern 1 2 3").unwrap();
+    rytm.message("paste pattern 4 5 10").unwrap();
+
+    with_trig(&rytm, 4, 5, 10, |trig| {
+        assert!(trig.enabled_trig());
+        assert_eq!(trig.note(), 40);
+        assert_eq!(cutoff_lock(trig), Some(64));
+    });
+    with_trig(&rytm, 1, 2, 3, |trig| {
+        assert_eq!(cutoff_lock(trig), Some(64));
+    });
+}
+
+#[test]
+fn shifted_trigs_wrap_around_the_length_of_the_track() {
+    let rytm = Rytm::default();
+
+    rytm.message("set pattern 0 1 0 enable 1").unwrap();
+    rytm.message("set pattern 0 1 15 enable 1").unwrap();
+    rytm.message("set pattern 0 1 15 plockset filtcutoff 64")
+        .unwrap();
+    rytm.message("shift pattern 0 1 1").unwrap();
+
+    // The default length of a track is 16 steps.
+    assert_eq!(enabled_trigs(&rytm, 0, 1), vec![0, 1]);
+    with_trig(&rytm, 0, 1, 0, |trig| {
+        assert_eq!(cutoff_lock(trig), Some(64));
+    });
+    with_trig(&rytm, 0, 1, 1, |trig| {
+        assert_eq!(cutoff_lock(trig), None);
+    });
+
+    rytm.message("shift pattern 0 1 -33").unwrap();
+    assert_eq!(enabled_trigs(&rytm, 0, 1), vec![0, 15]);
+}
+
+#[test]
+fn shifting_by_the_largest_amounts_does_not_overflow() {
+    let rytm = Rytm::default();
+
+    rytm.message("set pattern 0 1 3 enable 1").unwrap();
+    rytm.message(&format!("shift pattern 0 1 {}", isize::MAX))
+        .unwrap();
+    rytm.message(&format!("shift pattern 0 1 {}", isize::MIN))
+        .unwrap();
+
+    // isize::MAX is 15 modulo 16 and isize::MIN is 0, the trig moves 15 steps later in total.
+    assert_eq!(enabled_trigs(&rytm, 0, 1), vec![2]);
+}
+
+#[test]
+fn reversed_trigs_keep_their_parameter_locks() {
+    let rytm = Rytm::default();
+
+    rytm.message("set pattern 0 1 2 enable 1").unwrap();
+    rytm.message("set pattern 0 1 2 plockset filtcutoff 64")
+        .unwrap();
+    rytm.message("reverse pattern 0 1").unwrap();
+
+    assert_eq!(enabled_trigs(&rytm, 0, 1), vec![13]);
+    with_trig(&rytm, 0, 1, 13, |trig| {
+        assert_eq!(cutoff_lock(trig), Some(64));
+    });
+    with_trig(&rytm, 0, 1, 2, |trig| {
+        assert_eq!(cutoff_lock(trig), None);
+    });
+}
+
+#[test]
+fn cleared_trigs_lose_their_parameter_locks() {
+    let rytm = Rytm::default();
+
+    rytm.message("set pattern 0 1 2 enable 1").unwrap();
+    rytm.message("set pattern 0 1 2 plockset filtcutoff 64")
+        .unwrap();
+    rytm.message("set pattern 0 1 5 enable 1").unwrap();
+    rytm.message("clear pattern 0 1 2").unwrap();
+
+    assert_eq!(enabled_trigs(&rytm, 0, 1), vec![5]);
+    with_trig(&rytm, 0, 1, 2, |trig| {
+        assert_eq!(cutoff_lock(trig), None);
+    });
+}
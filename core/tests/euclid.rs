@@ -0,0 +1,65 @@
+use rytm_core::euclid::{bjorklund, EuclidLayer};
+use rytm_core::rytm::Rytm;
+use rytm_rs::prelude::HoldsTrigFlags;
+
+/// Renders a rhythm as `x` for pulses and `.` for rests.
+fn steps(rhythm: &[bool]) -> String {
+    rhythm
+        .iter()
+        .map(|&pulse| if pulse { 'x' } else { '.' })
+        .collect()
+}
+
+#[test]
+fn pulses_are_spread_as_evenly_as_possible() {
+    assert_eq!(steps(&bjorklund(3, 8)), "x..x..x.");
+    assert_eq!(steps(&bjorklund(5, 8)), "x.xx.xx.");
+    assert_eq!(steps(&bjorklund(4, 16)), "x...x...x...x...");
+    assert_eq!(steps(&bjorklund(0, 4)), "....");
+    assert_eq!(steps(&bjorklund(4, 4)), "xxxx");
+}
+
+#[test]
+fn rotation_moves_pulses_later_and_wraps_around() {
+    let layer = |rotation| {
+        steps(
+            &EuclidLayer {
+                pulses: 3,
+                steps: 8,
+                rotation,
+            }
+            .render(),
+        )
+    };
+
+    assert_eq!(layer(1), ".x..x..x");
+    assert_eq!(layer(2), "x.x..x..");
+    assert_eq!(layer(8), layer(0));
+}
+
+#[test]
+fn euclid_fills_the_trigs_of_a_track() {
+    let rytm = Rytm::default();
+
+    rytm.message("set pattern 2 1 euclid 3 8 1").unwrap();
+
+    let project = rytm.project.lock().unwrap();
+    let track = &project.patterns()[2].tracks()[1];
+    let enabled = track
+        .trigs()
+        .iter()
+        .take(track.number_of_steps())
+        .map(HoldsTrigFlags::enabled_trig)
+        .collect::<Vec<_>>();
+    // The rhythm repeats over the default length of 16 steps.
+    assert_eq!(steps(&enabled), ".x..x..x.x..x..x");
+}
+
+#[test]
+fn euclid_accepts_zero_pulses_and_rejects_negative_ones() {
+    let rytm = Rytm::default();
+
+    rytm.message("set pattern 2 1 euclid 0 8").unwrap();
+    let error = rytm.message("set pattern 2 1 euclid -1 8").unwrap_err();
+    assert!(error.to_string().contains("non-negative"));
+}
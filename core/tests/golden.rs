@@ -0,0 +1,481 @@
+//! Round trips every identifier and enum through `set` and `get` and compares what is output with the golden files.
+//!
+//! Each identifier is read, set to a value next to the one which is read and read again.
+//! Read only identifiers are only read.
+//! Every message is written to a transcript with the atoms or the error it returns, so a getter which
+//! sets, a setter which does not set or a changed error message shows up as a difference.
+//!
+//! After an intended change of the outputs the golden files are written again with `RYTM_BLESS=1 cargo test`.
+
+use rytm_core::api::*;
+use rytm_core::atom::{Atom, AtomValue};
+use rytm_core::output::Output;
+use rytm_core::rytm::Rytm;
+use std::path::PathBuf;
+
+/// Identifiers which are followed by an index in both `get` and `set`.
+const INDEXED_IDENTIFIERS: &[&str] = &[
+    kit_action_type::CONTROL_IN_1_MOD_AMT,
+    kit_action_type::CONTROL_IN_2_MOD_AMT,
+    sound_action_type::VEL_MOD_AMT,
+    sound_action_type::AT_MOD_AMT,
+];
+
+/// Identifiers of the settings which are set by their index alone.
+///
+/// These are kept per object since a trig also has a `mute` which takes a value.
+const SETTINGS_INDEX_ONLY_IDENTIFIERS: &[&str] =
+    &[settings_action_type::MUTE, settings_action_type::UNMUTE];
+
+/// Identifiers which are only set, with the identifier which reads what they set.
+const READ_BY: &[(&str, &str)] = &[(settings_action_type::UNMUTE, settings_action_type::MUTE)];
+
+/// Identifiers of the globals which are followed by a track index and turn the track on or off.
+const GLOBAL_TRACK_TOGGLES: &[&str] = &[
+    global_action_type::ROUTE_TO_MAIN,
+    global_action_type::SEND_TO_FX,
+];
+
+/// Values which are set instead of a neighbouring value, for identifiers which read a value out of their range.
+///
+/// Velocity and sound lock read 255 until they are set.
+const PROBE_VALUES: &[(&str, &str)] = &[
+    (trig_action_type::VELOCITY, "100"),
+    (trig_action_type::SOUND_LOCK, "5"),
+];
+
+/// Enums which are followed by an index, in `get` as the enum value and in `set` as the next atom.
+const INDEXED_ENUMS: &[&str] = &[
+    kit_enum_type::CONTROL_IN_1_MOD_TARGET,
+    kit_enum_type::CONTROL_IN_2_MOD_TARGET,
+    sound_enum_type::VELOCITY_MOD_TARGET,
+    sound_enum_type::AFTER_TOUCH_MOD_TARGET,
+    global_enum_type::TRACK_CHANNELS,
+];
+
+/// A value for every enum which differs from the value of a default project.
+const ENUM_VALUES: &[(&str, &str)] = &[
+    (pattern_enum_type::SPEED, "2x"),
+    (pattern_enum_type::TIME_MODE, "advanced"),
+    (track_enum_type::ROOT_NOTE, "d"),
+    (track_enum_type::PAD_SCALE, "dorian"),
+    (track_enum_type::DEFAULT_NOTE_LENGTH, "1/64"),
+    (trig_enum_type::MICRO_TIME, "-5/96"),
+    (trig_enum_type::NOTE_LENGTH, "1/64"),
+    (trig_enum_type::RETRIG_LENGTH, "1/64"),
+    (trig_enum_type::RETRIG_RATE, "1/3"),
+    (trig_enum_type::TRIG_CONDITION, "fill"),
+    (kit_enum_type::CONTROL_IN_1_MOD_TARGET, "lfophase"),
+    (kit_enum_type::CONTROL_IN_2_MOD_TARGET, "lfowaveform"),
+    (kit_enum_type::FX_LFO_DESTINATION, "delaytime"),
+    (kit_enum_type::FX_COMP_ATTACK, "0.3"),
+    (kit_enum_type::FX_COMP_RELEASE, "0.6"),
+    (kit_enum_type::FX_COMP_RATIO, "max"),
+    (kit_enum_type::FX_COMP_SIDE_CHAIN_EQ, "hpf"),
+    (settings_enum_type::PARAMETER_MENU_ITEM, "fltr"),
+    (settings_enum_type::FX_PARAMETER_MENU_ITEM, "reverb"),
+    (settings_enum_type::SEQUENCER_MODE, "chain"),
+    (settings_enum_type::PATTERN_MODE, "directjump"),
+    (settings_enum_type::SAMPLE_RECORDER_SOURCE, "bd"),
+    (
+        settings_enum_type::SAMPLE_RECORDER_RECORDING_LENGTH,
+        "8steps",
+    ),
+    (sound_enum_type::MACHINE_TYPE, "bdclassic"),
+    (sound_enum_type::LFO_DESTINATION, "syn2"),
+    (sound_enum_type::VELOCITY_MOD_TARGET, "lfophase"),
+    (sound_enum_type::AFTER_TOUCH_MOD_TARGET, "lfowaveform"),
+    (sound_enum_type::FILTER_TYPE, "bp"),
+    (sound_enum_type::LFO_MULTIPLIER, "x4"),
+    (sound_enum_type::LFO_WAVEFORM, "saw"),
+    (sound_enum_type::LFO_MODE, "hold"),
+    (sound_enum_type::SOUND_SETTINGS_CHROMATIC_MODE, "samp"),
+    (sound_machine_enum_type::BD_ACOUSTIC_WAVEFORM, "asinb"),
+    (sound_machine_enum_type::BD_SHARP_WAVEFORM, "asina"),
+    (sound_machine_enum_type::SY_CHIP_WAVEFORM, "ssaw"),
+    (sound_machine_enum_type::SY_CHIP_SPEED, "64t"),
+    (sound_machine_enum_type::SY_RAW_WAVEFORM_1, "saw"),
+    (sound_machine_enum_type::SY_RAW_WAVEFORM_2, "ssawB"),
+    (global_enum_type::METRONOME_TIME_SIGNATURE, "3/4"),
+    (global_enum_type::ROUTING_USB_IN_OPTIONS, "post-fx"),
+    (global_enum_type::ROUTING_USB_OUT_OPTIONS, "3:4"),
+    (global_enum_type::ROUTING_USB_TO_MAIN_DB, "+12db"),
+    (global_enum_type::OUT_PORT_FUNCTION, "din24"),
+    (global_enum_type::THRU_PORT_FUNCTION, "din48"),
+    (global_enum_type::INPUT_FROM, "usb"),
+    (global_enum_type::OUTPUT_TO, "disabled"),
+    (global_enum_type::PARAM_OUTPUT, "nrpn"),
+    (global_enum_type::PAD_DEST, "ext"),
+    (global_enum_type::PRESSURE_DEST, "int"),
+    (global_enum_type::ENCODER_DEST, "int+ext"),
+    (global_enum_type::MUTE_DEST, "ext"),
+    (global_enum_type::PORTS_OUTPUT_CHANNEL, "track"),
+    (global_enum_type::AUTO_CHANNEL, "off"),
+    (global_enum_type::TRACK_CHANNELS, "9"),
+    (global_enum_type::TRACK_FX_CHANNEL, "5"),
+    (global_enum_type::PROGRAM_CHANGE_IN_CHANNEL, "3"),
+    (global_enum_type::PROGRAM_CHANGE_OUT_CHANNEL, "5"),
+    (global_enum_type::PERFORMANCE_CHANNEL, "2"),
+];
+
+/// The machine which a machine enum belongs to.
+const MACHINES: &[(&str, &str)] = &[
+    (sound_machine_enum_type::BD_ACOUSTIC_WAVEFORM, "bdacoustic"),
+    (sound_machine_enum_type::BD_SHARP_WAVEFORM, "bdsharp"),
+    (sound_machine_enum_type::SY_CHIP_WAVEFORM, "sychip"),
+    (sound_machine_enum_type::SY_CHIP_SPEED, "sychip"),
+    (sound_machine_enum_type::SY_RAW_WAVEFORM_1, "syraw"),
+    (sound_machine_enum_type::SY_RAW_WAVEFORM_2, "syraw"),
+];
+
+fn lookup(table: &[(&str, &'static str)], key: &str) -> &'static str {
+    table
+        .iter()
+        .find(|(k, _)| *k == key)
+        .map(|(_, value)| *value)
+        .unwrap_or_else(|| panic!("{} is missing from the table.", key))
+}
+
+/// Messages which are sent to a rytm and what they return, one line per message and output.
+struct Transcript {
+    rytm: Rytm,
+    lines: Vec<String>,
+}
+
+impl Transcript {
+    fn new() -> Self {
+        Self {
+            rytm: Rytm::default(),
+            lines: Vec::new(),
+        }
+    }
+
+    /// Sends a message and returns the atoms of its last query output.
+    fn send(&mut self, message: &str) -> Option<Vec<Atom>> {
+        self.lines.push(format!("> {}", message));
+        match self.rytm.message(message) {
+            Ok(outputs) => self.push_outputs(outputs),
+            Err(err) => {
+                self.lines.push(format!("! {}", err));
+                None
+            }
+        }
+    }
+
+    /// Sends a message and writes it to the transcript only when it succeeds.
+    fn try_send(&mut self, message: &str) -> bool {
+        match self.rytm.message(message) {
+            Ok(outputs) => {
+                self.lines.push(format!("> {}", message));
+                self.push_outputs(outputs);
+                true
+            }
+            Err(_) => false,
+        }
+    }
+
+    /// Writes the outputs of a message and returns the atoms of its last query output.
+    fn push_outputs(&mut self, outputs: Vec<Output>) -> Option<Vec<Atom>> {
+        let mut last_query = None;
+        for output in outputs {
+            match output {
+                Output::Query(atoms) => {
+                    self.lines.push(format!("< {}", format_atoms(&atoms)));
+                    last_query = Some(atoms);
+                }
+                other => self.lines.push(format!("< {:?}", other)),
+            }
+        }
+        last_query
+    }
+
+    /// Round trips every identifier of an object, the ones in `index_only` are set by their index alone.
+    fn round_trip_identifiers(&mut self, object: &str, identifiers: &[&str], index_only: &[&str]) {
+        for identifier in identifiers {
+            if GLOBAL_TRACK_TOGGLES.contains(identifier) {
+                self.round_trip_track_toggle(object, identifier);
+            } else if index_only.contains(identifier) {
+                self.round_trip_index_only(object, identifier);
+            } else {
+                self.round_trip_identifier(object, identifier);
+            }
+        }
+    }
+
+    fn read_identifiers(&mut self, object: &str, identifiers: &[&str]) {
+        for identifier in identifiers {
+            self.send(&format!("get {} {}", object, identifier));
+        }
+    }
+
+    fn round_trip_index_only(&mut self, object: &str, identifier: &str) {
+        let reader = READ_BY
+            .iter()
+            .find(|(key, _)| *key == identifier)
+            .map_or(identifier, |(_, reader)| *reader);
+        self.send(&format!("get {} {} 1", object, reader));
+        self.send(&format!("set {} {} 1", object, identifier));
+        self.send(&format!("get {} {} 1", object, reader));
+    }
+
+    /// Tracks are on in a default project, so they are turned off.
+    fn round_trip_track_toggle(&mut self, object: &str, identifier: &str) {
+        self.send(&format!("get {} {} 1", object, identifier));
+        self.send(&format!("set {} {} 1 0", object, identifier));
+        self.send(&format!("get {} {} 1", object, identifier));
+    }
+
+    /// Reads an identifier, sets it to a neighbouring value and reads it again.
+    fn round_trip_identifier(&mut self, object: &str, identifier: &str) {
+        let (get, set) = if INDEXED_IDENTIFIERS.contains(&identifier) {
+            (
+                format!("get {} {} 1", object, identifier),
+                format!("set {} {} 1", object, identifier),
+            )
+        } else {
+            (
+                format!("get {} {}", object, identifier),
+                format!("set {} {}", object, identifier),
+            )
+        };
+
+        let value = self
+            .send(&get)
+            .and_then(|atoms| atoms.last().and_then(Atom::get_value));
+        let probe = PROBE_VALUES.iter().find(|(key, _)| *key == identifier);
+        let candidates = match (probe, value) {
+            (Some((_, probe)), _) => vec![(*probe).to_owned()],
+            (None, Some(AtomValue::Int(value))) => {
+                vec![(value + 1).to_string(), (value - 1).to_string()]
+            }
+            (None, Some(AtomValue::Float(value))) => {
+                vec![format!("{:?}", value + 1.0), format!("{:?}", value - 1.0)]
+            }
+            (None, Some(AtomValue::Symbol(_))) => vec!["golden".to_owned()],
+            (None, None) => vec!["1".to_owned()],
+        };
+        // The next value may be out of range, then the previous one is tried.
+        // A candidate which is out of range is left out of the transcript, so it only has the value which is set.
+        let set_any = candidates
+            .iter()
+            .any(|candidate| self.try_send(&format!("{} {}", set, candidate)));
+        if !set_any {
+            self.send(&format!("{} {}", set, candidates[0]));
+        }
+        self.send(&get);
+    }
+
+    /// Reads an enum, sets it to the value in the table and reads it again.
+    fn round_trip_enum(&mut self, object: &str, enum_type: &str) {
+        let value = lookup(ENUM_VALUES, enum_type);
+        if INDEXED_ENUMS.contains(&enum_type) {
+            self.send(&format!("get {} {}:1", object, enum_type));
+            self.send(&format!("set {} {}:{} 1", object, enum_type, value));
+            self.send(&format!("get {} {}:1", object, enum_type));
+        } else {
+            self.send(&format!("get {} {}:", object, enum_type));
+            self.send(&format!("set {} {}:{}", object, enum_type, value));
+            self.send(&format!("get {} {}:", object, enum_type));
+        }
+    }
+
+    /// Compares the transcript with its golden file or writes the golden file when blessing.
+    fn assert_golden(&self, name: &str) {
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("tests")
+            .join("golden")
+            .join(format!("{}.txt", name));
+        let actual = self.lines.join("\n") + "\n";
+
+        if std::env::var_os("RYTM_BLESS").is_some() {
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(&path, actual).unwrap();
+            return;
+        }
+
+        let expected = std::fs::read_to_string(&path).unwrap_or_else(|_| {
+            panic!(
+                "There is no golden file at {}, run the tests with RYTM_BLESS=1 to write it.",
+                path.display()
+            )
+        });
+        let difference = expected
+            .lines()
+            .zip(actual.lines())
+            .position(|(expected, actual)| expected != actual);
+        if let Some(line) = difference {
+            panic!(
+                "{} differs from its golden file at line {}.\nexpected: {}\n  actual: {}",
+                name,
+                line + 1,
+                expected.lines().nth(line).unwrap(),
+                actual.lines().nth(line).unwrap()
+            );
+        }
+        assert_eq!(
+            expected.lines().count(),
+            actual.lines().count(),
+            "{} has a different number of lines than its golden file.",
+            name
+        );
+    }
+}
+
+/// Formats atoms with floats always having a fraction so their type is visible.
+fn format_atoms(atoms: &[Atom]) -> String {
+    atoms
+        .iter()
+        .map(|atom| match atom.get_value() {
+            Some(AtomValue::Float(value)) => format!("{:?}", value),
+            _ => atom.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn round_trip_pattern(object: &str) -> Transcript {
+    let mut transcript = Transcript::new();
+    transcript.read_identifiers(object, pattern_action_type::PATTERN_READ_ONLY_ACTION_TYPES);
+    transcript.round_trip_identifiers(object, pattern_action_type::PATTERN_ACTION_TYPES, &[]);
+    for enum_type in pattern_enum_type::PATTERN_ENUM_TYPES {
+        transcript.round_trip_enum(object, enum_type);
+    }
+    transcript
+}
+
+fn round_trip_track(object: &str) -> Transcript {
+    let mut transcript = Transcript::new();
+    transcript.read_identifiers(object, track_action_type::TRACK_READ_ONLY_ACTION_TYPES);
+    transcript.round_trip_identifiers(object, track_action_type::TRACK_ACTION_TYPES, &[]);
+    for enum_type in track_enum_type::TRACK_ENUM_TYPES {
+        transcript.round_trip_enum(object, enum_type);
+    }
+    transcript
+}
+
+fn round_trip_trig(object: &str) -> Transcript {
+    let mut transcript = Transcript::new();
+    transcript.round_trip_identifiers(object, trig_action_type::TRIG_ACTION_TYPES, &[]);
+    for enum_type in trig_enum_type::TRIG_ENUM_TYPES {
+        transcript.round_trip_enum(object, enum_type);
+    }
+    transcript
+}
+
+fn round_trip_kit(object: &str) -> Transcript {
+    let mut transcript = Transcript::new();
+    transcript.read_identifiers(object, kit_action_type::KIT_READ_ONLY_ACTION_TYPES);
+    transcript.round_trip_identifiers(object, kit_action_type::KIT_ACTION_TYPES, &[]);
+    for enum_type in kit_enum_type::KIT_ENUM_TYPES {
+        transcript.round_trip_enum(object, enum_type);
+    }
+    transcript
+}
+
+fn round_trip_sound(object: &str) -> Transcript {
+    let mut transcript = Transcript::new();
+    transcript.read_identifiers(object, sound_action_type::SOUND_READ_ONLY_ACTION_TYPES);
+    transcript.round_trip_identifiers(object, sound_action_type::SOUND_ACTION_TYPES, &[]);
+    for enum_type in sound_enum_type::SOUND_ENUM_TYPES {
+        transcript.round_trip_enum(object, enum_type);
+    }
+    for enum_type in sound_machine_enum_type::SOUND_MACHINE_ENUM_TYPES {
+        transcript.send(&format!(
+            "set {} machinetype:{}",
+            object,
+            lookup(MACHINES, enum_type)
+        ));
+        transcript.round_trip_enum(object, enum_type);
+    }
+    transcript
+}
+
+fn round_trip_global(object: &str) -> Transcript {
+    let mut transcript = Transcript::new();
+    transcript.read_identifiers(object, global_action_type::GLOBAL_READ_ONLY_ACTION_TYPES);
+    transcript.round_trip_identifiers(object, global_action_type::GLOBAL_ACTION_TYPES, &[]);
+    for enum_type in global_enum_type::GLOBAL_ENUM_TYPES {
+        transcript.round_trip_enum(object, enum_type);
+    }
+    transcript
+}
+
+// Indexed objects are not at index 0 so a handler which ignores the index shows up as a difference.
+
+#[test]
+fn pattern() {
+    round_trip_pattern("pattern 1").assert_golden("pattern");
+}
+
+#[test]
+fn pattern_wb() {
+    round_trip_pattern("pattern_wb").assert_golden("pattern_wb");
+}
+
+#[test]
+fn track() {
+    round_trip_track("pattern 1 2").assert_golden("track");
+}
+
+#[test]
+fn track_wb() {
+    round_trip_track("pattern_wb 2").assert_golden("track_wb");
+}
+
+#[test]
+fn trig() {
+    round_trip_trig("pattern 1 2 3").assert_golden("trig");
+}
+
+#[test]
+fn trig_wb() {
+    round_trip_trig("pattern_wb 2 3").assert_golden("trig_wb");
+}
+
+#[test]
+fn kit() {
+    round_trip_kit("kit 1").assert_golden("kit");
+}
+
+#[test]
+fn kit_wb() {
+    round_trip_kit("kit_wb").assert_golden("kit_wb");
+}
+
+#[test]
+fn sound() {
+    round_trip_sound("sound 1").assert_golden("sound");
+}
+
+#[test]
+fn sound_wb() {
+    round_trip_sound("sound_wb 1").assert_golden("sound_wb");
+}
+
+#[test]
+fn global() {
+    round_trip_global("global 1").assert_golden("global");
+}
+
+#[test]
+fn global_wb() {
+    round_trip_global("global_wb").assert_golden("global_wb");
+}
+
+#[test]
+fn settings() {
+    let mut transcript = Transcript::new();
+    transcript.read_identifiers(
+        "settings",
+        settings_action_type::SETTINGS_READ_ONLY_ACTION_TYPES,
+    );
+    transcript.round_trip_identifiers(
+        "settings",
+        settings_action_type::SETTINGS_ACTION_TYPES,
+        SETTINGS_INDEX_ONLY_IDENTIFIERS,
+    );
+    for enum_type in settings_enum_type::SETTINGS_ENUM_TYPES {
+        transcript.round_trip_enum("settings", enum_type);
+    }
+    transcript.assert_golden("settings");
+}
@@ -0,0 +1,180 @@
+use rytm_core::atom::{Atom, AtomValue, SymbolRef};
+use rytm_core::output::Output;
+use rytm_core::rytm::Rytm;
+
+fn query(atoms: &str) -> Output {
+    Output::Query(Atom::parse_list(atoms))
+}
+
+#[test]
+fn pattern_trig_changes_are_undone_and_redone() {
+    let rytm = Rytm::default();
+
+    rytm.message("set pattern 1 2 3 enable 1").unwrap();
+    rytm.message("undo").unwrap();
+    assert_eq!(
+        rytm.message("get pattern 1 2 3 enable").unwrap(),
+        vec![query("2 3 enable 0")]
+    );
+    rytm.message("redo").unwrap();
+    assert_eq!(
+        rytm.message("get pattern 1 2 3 enable").unwrap(),
+        vec![query("2 3 enable 1")]
+    );
+}
+
+#[test]
+fn pattern_wb_trig_changes_are_undone_and_redone() {
+    let rytm = Rytm::default();
+
+    rytm.message("set pattern_wb 2 3 note 40").unwrap();
+    let changed = rytm.message("get pattern_wb 2 3 note").unwrap();
+    rytm.message("undo").unwrap();
+    assert_ne!(rytm.message("get pattern_wb 2 3 note").unwrap(), changed);
+    rytm.message("redo").unwrap();
+    assert_eq!(rytm.message("get pattern_wb 2 3 note").unwrap(), changed);
+}
+
+/// A `history` line, changes are recorded as a single symbol.
+fn history_line(position: isize, change: &str, value: &str) -> Output {
+    let mut atoms = Atom::parse_list("history undo");
+    atoms.push(Atom::from(AtomValue::Int(position)));
+    atoms.push(Atom::from(SymbolRef::from(change)));
+    atoms.extend(Atom::parse_list(value));
+    Output::Query(atoms)
+}
+
+#[test]
+fn history_lists_the_path_and_the_values_of_a_change() {
+    let rytm = Rytm::default();
+
+    rytm.message("set kit_wb fxdelfeedback 40").unwrap();
+    rytm.message("set kit_wb fxdelfeedback 12").unwrap();
+    assert_eq!(
+        rytm.message("history").unwrap(),
+        vec![
+            history_line(
+                0,
+                "set kit_wb fxdelfeedback 12",
+                "kit_wb 0 fxdelfeedback 40 12"
+            ),
+            history_line(
+                1,
+                "set kit_wb fxdelfeedback 40",
+                "kit_wb 0 fxdelfeedback 49 40"
+            ),
+        ]
+    );
+}
+
+#[test]
+fn sysex_responses_are_undone() {
+    let sender = Rytm::default();
+    sender.message("set kit 2 name hello").unwrap();
+    let outputs = sender.message("send kit 2").unwrap();
+    let [Output::Sysex(sysex)] = &outputs[..] else {
+        panic!("Expected a single sysex output, got {:?}", outputs);
+    };
+
+    let rytm = Rytm::default();
+    rytm.handle_sysex_response(sysex).unwrap();
+    rytm.take_outputs();
+    assert_eq!(
+        rytm.message("get kit 2 name").unwrap(),
+        vec![query("2 name hello")]
+    );
+    rytm.message("undo").unwrap();
+    assert_ne!(
+        rytm.message("get kit 2 name").unwrap(),
+        vec![query("2 name hello")]
+    );
+}
+
+#[test]
+fn imports_are_undone() {
+    let path = std::env::temp_dir().join(format!("rytm-history-{}.syx", std::process::id()));
+    let rytm = Rytm::default();
+    rytm.message("set kit 2 name hello").unwrap();
+    rytm.message(&format!("export kit 2 {}", path.display()))
+        .unwrap();
+
+    rytm.message(&format!("import kit 3 {}", path.display()))
+        .unwrap();
+    rytm.take_outputs();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(
+        rytm.message("get kit 3 name").unwrap(),
+        vec![query("3 name hello")]
+    );
+    rytm.message("undo").unwrap();
+    assert_ne!(
+        rytm.message("get kit 3 name").unwrap(),
+        vec![query("3 name hello")]
+    );
+}
+
+#[test]
+fn live_input_is_undone() {
+    let rytm = Rytm::default();
+    let before = rytm.message("get kit_wb tracklevel 0").unwrap();
+
+    // Track level of the track on the first channel.
+    for byte in [0xB0, 95, 3] {
+        rytm.handle_live_input(byte).unwrap();
+    }
+    assert_eq!(
+        rytm.message("get kit_wb tracklevel 0").unwrap(),
+        vec![query("0 0 tracklevel 3")]
+    );
+    rytm.message("undo").unwrap();
+    assert_eq!(rytm.message("get kit_wb tracklevel 0").unwrap(), before);
+}
+
+#[test]
+fn history_lists_the_values_of_a_pattern_change() {
+    let rytm = Rytm::default();
+
+    rytm.message("set pattern 1 2 3 enable 1").unwrap();
+    assert_eq!(
+        rytm.message("history").unwrap(),
+        vec![history_line(
+            0,
+            "set pattern 1 2 3 enable 1",
+            "pattern 1 2 3 enable 0 1"
+        )]
+    );
+}
+
+#[test]
+fn live_changes_of_a_parameter_are_merged() {
+    let rytm = Rytm::default();
+    let before = rytm.message("get kit_wb tracklevel 0").unwrap();
+
+    // Track levels of the tracks on the first and the second channel.
+    for byte in [0xB0, 95, 3, 95, 5, 95, 7, 0xB1, 95, 9] {
+        rytm.handle_live_input(byte).unwrap();
+    }
+    assert_eq!(
+        rytm.message("history").unwrap(),
+        vec![
+            history_line(0, "live 1 cc 95 9", "kit_wb 0 tracklevel 1 100 9"),
+            history_line(1, "live 0 cc 95 7", "kit_wb 0 tracklevel 0 100 7"),
+        ]
+    );
+    rytm.message("undo").unwrap();
+    rytm.message("undo").unwrap();
+    assert_eq!(rytm.message("get kit_wb tracklevel 0").unwrap(), before);
+}
+
+#[test]
+fn history_lists_the_values_of_an_undone_change() {
+    let rytm = Rytm::default();
+
+    rytm.message("set kit_wb fxdelfeedback 40").unwrap();
+    rytm.message("undo").unwrap();
+
+    let mut line = Atom::parse_list("history redo 0");
+    line.push(Atom::from(SymbolRef::from("set kit_wb fxdelfeedback 40")));
+    line.extend(Atom::parse_list("kit_wb 0 fxdelfeedback 49 40"));
+    assert_eq!(rytm.message("history").unwrap(), vec![Output::Query(line)]);
+}
@@ -0,0 +1,44 @@
+use rytm_core::atom::Atom;
+use rytm_core::output::Output;
+use rytm_core::rytm::Rytm;
+use std::sync::atomic::Ordering::Relaxed;
+
+fn query(atoms: &str) -> Output {
+    Output::Query(Atom::parse_list(atoms))
+}
+
+#[test]
+fn live_input_changes_the_work_buffer_sound_of_the_track() {
+    let rytm = Rytm::default();
+    rytm.message("set sound_wb 0 machinetype:bdhard").unwrap();
+    let pool_sound = rytm.message("get sound 0 filtcutoff").unwrap();
+
+    // Filter cutoff of the track on the first channel.
+    for byte in [0xB0, 74, 90] {
+        rytm.handle_live_input(byte).unwrap();
+    }
+
+    assert_eq!(
+        rytm.message("get sound_wb 0 filtcutoff").unwrap(),
+        vec![query("0 filtcutoff 90")]
+    );
+    assert_eq!(
+        rytm.message("get sound_wb 0 machinetype:").unwrap(),
+        vec![query("0 machinetype bdhard")]
+    );
+    assert_eq!(rytm.message("get sound 0 filtcutoff").unwrap(), pool_sound);
+}
+
+#[test]
+fn live_output_sends_only_changes_which_have_messages() {
+    let rytm = Rytm::default();
+    rytm.live_output.store(true, Relaxed);
+
+    assert_eq!(rytm.message("set kit_wb name hello").unwrap(), vec![]);
+
+    let outputs = rytm.message("set kit_wb fxdeltime 24").unwrap();
+    let [Output::Sysex(messages)] = &outputs[..] else {
+        panic!("Expected the changed parameter, got {:?}", outputs);
+    };
+    assert!(!messages.is_empty());
+}
@@ -0,0 +1,90 @@
+use rytm_core::dump::dump_object;
+use rytm_core::load::load_object;
+use rytm_core::types::ObjectTypeSelector;
+use rytm_rs::RytmProject;
+use serde_json::json;
+
+const OBJECTS: &[ObjectTypeSelector] = &[
+    ObjectTypeSelector::Pattern(1),
+    ObjectTypeSelector::PatternWorkBuffer,
+    ObjectTypeSelector::Kit(1),
+    ObjectTypeSelector::KitWorkBuffer,
+    ObjectTypeSelector::Sound(1),
+    ObjectTypeSelector::SoundWorkBuffer(1),
+    ObjectTypeSelector::Global(1),
+    ObjectTypeSelector::GlobalWorkBuffer,
+    ObjectTypeSelector::Settings,
+];
+
+#[test]
+fn a_dump_loads_back_as_it_is() {
+    // Boxed since the project is too large for the stack.
+    let mut project = Box::<RytmProject>::default();
+
+    for object in OBJECTS {
+        let dump = dump_object(&mut project, *object).unwrap();
+        load_object(&mut project, *object, &dump)
+            .unwrap_or_else(|err| panic!("{:?}: {}", object, err));
+        assert_eq!(
+            dump_object(&mut project, *object).unwrap(),
+            dump,
+            "{:?}",
+            object
+        );
+    }
+}
+
+#[test]
+fn every_key_is_applied() {
+    let mut project = Box::<RytmProject>::default();
+    let sound = ObjectTypeSelector::Sound(1);
+
+    load_object(
+        &mut project,
+        sound,
+        &json!({ "envresetfilter": 1, "accentlev": 10 }),
+    )
+    .unwrap();
+
+    let dump = dump_object(&mut project, sound).unwrap();
+    assert_eq!(dump["envresetfilter"], json!(1));
+    assert_eq!(dump["accentlev"], json!(10));
+}
+
+#[test]
+fn an_invalid_value_is_an_error_and_nothing_is_applied() {
+    let mut project = Box::<RytmProject>::default();
+    let sound = ObjectTypeSelector::Sound(1);
+    let before = dump_object(&mut project, sound).unwrap();
+
+    let err = load_object(
+        &mut project,
+        sound,
+        &json!({ "accentlev": 10, "envresetfilter": 2 }),
+    )
+    .unwrap_err();
+
+    assert!(
+        err.to_string()
+            .starts_with("Invalid load value for envresetfilter:"),
+        "{}",
+        err
+    );
+    assert_eq!(dump_object(&mut project, sound).unwrap(), before);
+}
+
+#[test]
+fn read_only_keys_are_skipped() {
+    let mut project = Box::<RytmProject>::default();
+    let sound = ObjectTypeSelector::Sound(1);
+    let before = dump_object(&mut project, sound).unwrap();
+
+    load_object(
+        &mut project,
+        sound,
+        &json!({ "index": 5, "version": 0, "ispool": 0, "iskit": 1, "iswb": 1, "kitnumber": 3, "type": "kit" }),
+    )
+    .unwrap();
+
+    assert_eq!(dump_object(&mut project, sound).unwrap(), before);
+}
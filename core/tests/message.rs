@@ -0,0 +1,243 @@
+use rytm_core::atom::Atom;
+use rytm_core::output::Output;
+use rytm_core::rytm::Rytm;
+
+fn query(atoms: &str) -> Output {
+    Output::Query(Atom::parse_list(atoms))
+}
+
+#[test]
+fn get_returns_the_value_which_is_set() {
+    let rytm = Rytm::default();
+
+    assert_eq!(rytm.message("set kit_wb name hello").unwrap(), vec![]);
+    assert_eq!(
+        rytm.message("get kit_wb name").unwrap(),
+        vec![query("0 name hello")]
+    );
+}
+
+#[test]
+fn query_sends_a_sysex_request() {
+    let rytm = Rytm::default();
+
+    let outputs = rytm.message("query pattern 0").unwrap();
+    let [Output::Sysex(sysex)] = &outputs[..] else {
+        panic!("Expected a single sysex output, got {:?}", outputs);
+    };
+    assert_eq!(sysex.first(), Some(&0xF0));
+    assert_eq!(sysex.last(), Some(&0xF7));
+}
+
+#[test]
+fn bulk_query_is_driven_by_ticks() {
+    let rytm = Rytm::default();
+
+    assert_eq!(
+        rytm.message("query all").unwrap(),
+        vec![Output::ScheduleBulkTick(0)]
+    );
+
+    rytm.bulk_tick();
+    let outputs = rytm.take_outputs();
+    assert!(matches!(outputs.first(), Some(Output::Sysex(_))));
+    assert_eq!(outputs.last(), Some(&Output::ScheduleBulkTick(50)));
+
+    assert!(rytm
+        .message("query stop")
+        .unwrap()
+        .contains(&Output::CancelBulkTick));
+}
+
+#[test]
+fn invalid_messages_return_errors() {
+    let rytm = Rytm::default();
+
+    assert!(rytm.message("").is_err());
+    assert!(rytm
+        .message("nope")
+        .unwrap_err()
+        .to_string()
+        .starts_with("Invalid selector: nope."));
+    assert_eq!(
+        rytm.message("load kit 0 nodict").unwrap_err().to_string(),
+        "Invalid dictionary: There is no dictionary named nodict."
+    );
+}
+
+#[test]
+fn sysex_is_buffered_until_the_end_of_the_message() {
+    let rytm = Rytm::default();
+
+    assert!(rytm.int(0x10).is_err());
+    // An incomplete message is buffered without output.
+    for byte in [0xF0, 0x00, 0x20] {
+        rytm.int(byte).unwrap();
+    }
+    assert!(rytm.take_outputs().is_empty());
+}
+
+/// An identifier of every object type with a value which differs from a default project.
+const OBJECT_VALUES: &[(&str, &str, &str)] = &[
+    ("pattern 1", "masterlen", "17"),
+    ("pattern_wb", "masterlen", "17"),
+    ("kit 1", "name", "hello"),
+    ("kit_wb", "name", "hello"),
+    ("sound 1", "name", "hello"),
+    ("sound_wb 1", "name", "hello"),
+    ("global 1", "kitreloadonchg", "1"),
+    ("global_wb", "kitreloadonchg", "1"),
+    ("settings", "selectedtrack", "1"),
+];
+
+#[test]
+fn every_object_type_is_dispatched_to_its_getter_and_setter() {
+    for (object, identifier, value) in OBJECT_VALUES {
+        let rytm = Rytm::default();
+        let get = format!("get {} {}", object, identifier);
+        let before = rytm.message(&get).unwrap();
+
+        // A getter which is given a value must not set it.
+        let _ = rytm.message(&format!("{} {}", get, value));
+        assert_eq!(rytm.message(&get).unwrap(), before, "{}", get);
+
+        rytm.message(&format!("set {} {} {}", object, identifier, value))
+            .unwrap();
+        let [Output::Query(atoms)] = &rytm.message(&get).unwrap()[..] else {
+            panic!("Expected a single query output for {}", get);
+        };
+        assert_eq!(atoms.last().unwrap().to_string(), *value, "{}", get);
+
+        // Only the addressed object changes.
+        for (other, other_identifier, _) in OBJECT_VALUES {
+            if other != object && other_identifier == identifier {
+                let other_get = format!("get {} {}", other, identifier);
+                assert_eq!(
+                    rytm.message(&other_get).unwrap(),
+                    Rytm::default().message(&other_get).unwrap(),
+                    "set {} changed {}",
+                    object,
+                    other
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn pattern_addresses_are_validated() {
+    let rytm = Rytm::default();
+
+    for message in [
+        "get pattern 1 13 swing",
+        "get pattern 1 2 64 enable",
+        "get pattern_wb -1 swing",
+        "set pattern 1 13 swing 1",
+        "set pattern_wb 2 64 enable 1",
+    ] {
+        assert!(
+            rytm.message(message)
+                .unwrap_err()
+                .to_string()
+                .starts_with("Invalid index:"),
+            "{}",
+            message
+        );
+    }
+}
+
+#[test]
+fn invalid_pattern_addresses_show_the_formats_of_their_operation() {
+    let rytm = Rytm::default();
+
+    for (message, format) in [
+        ("get pattern 1 2.5 enable", "Invalid getter format:"),
+        ("get pattern_wb 2 3.5 enable", "Invalid getter format:"),
+        ("set pattern 1 2.5 enable 1", "Invalid setter format:"),
+        ("set pattern_wb 2 3.5 enable 1", "Invalid setter format:"),
+    ] {
+        let error = rytm.message(message).unwrap_err().to_string();
+        assert!(error.starts_with(format), "{}: {}", message, error);
+        assert!(
+            error.contains(&format!("{} pattern", &message[..3])),
+            "{}: {}",
+            message,
+            error
+        );
+    }
+}
+
+#[test]
+fn indexed_pattern_setters_target_the_indexed_pattern() {
+    let rytm = Rytm::default();
+
+    rytm.message("set pattern 1 2 3 enable 1").unwrap();
+    assert_eq!(
+        rytm.message("get pattern 1 2 3 enable").unwrap(),
+        vec![query("2 3 enable 1")]
+    );
+    for other in ["pattern 0", "pattern_wb"] {
+        assert_eq!(
+            rytm.message(&format!("get {} 2 3 enable", other)).unwrap(),
+            vec![query("2 3 enable 0")]
+        );
+    }
+}
+
+#[test]
+fn kits_are_sent_as_sysex_which_decodes_to_the_same_kit() {
+    let rytm = Rytm::default();
+    rytm.message("set kit 2 name hello").unwrap();
+
+    let outputs = rytm.message("send kit 2").unwrap();
+    let [Output::Sysex(sysex)] = &outputs[..] else {
+        panic!("Expected a single sysex output, got {:?}", outputs);
+    };
+
+    let receiver = Rytm::default();
+    for &byte in sysex {
+        receiver.int(byte as isize).unwrap();
+    }
+    assert_eq!(
+        receiver.take_outputs().first(),
+        Some(&Output::Notification(Atom::parse_list(
+            "decoded kit 2 0 2998"
+        )))
+    );
+    assert_eq!(
+        receiver.message("get kit 2 name").unwrap(),
+        vec![query("2 name hello")]
+    );
+}
+
+#[test]
+fn dump_outputs_the_object_as_a_dictionary() {
+    let rytm = Rytm::default();
+    rytm.message("set kit 2 name hello").unwrap();
+
+    let outputs = rytm.message("dump kit 2").unwrap();
+    let [Output::Dictionary(atoms, value)] = &outputs[..] else {
+        panic!("Expected a single dictionary output, got {:?}", outputs);
+    };
+    assert_eq!(atoms, &Atom::parse_list("dump kit 2"));
+    assert_eq!(value["name"], "hello");
+    assert!(value["sounds"].is_array());
+}
+
+#[test]
+fn machine_types_can_not_be_parameter_locked() {
+    let rytm = Rytm::default();
+
+    for message in [
+        "set pattern 0 1 2 plockset machinetype:bdhard",
+        "get pattern 0 1 2 plockget machinetype:",
+        "set pattern 0 1 2 plockclear machinetype:",
+    ] {
+        assert_eq!(
+            rytm.message(message).unwrap_err().to_string(),
+            "Invalid enum type: machinetype can not be parameter locked, the machine of a trig is always the machine of its sound.",
+            "{}",
+            message
+        );
+    }
+}
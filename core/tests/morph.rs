@@ -0,0 +1,113 @@
+use rytm_core::atom::Atom;
+use rytm_core::output::Output;
+use rytm_core::rytm::Rytm;
+
+fn query(atoms: &str) -> Output {
+    Output::Query(Atom::parse_list(atoms))
+}
+
+/// Two sounds with different machines, filter types and amp decays.
+fn rytm_with_sounds() -> Rytm {
+    let rytm = Rytm::default();
+    for message in [
+        "set sound 0 machinetype:bdhard",
+        "set sound 0 filtertype:lp2",
+        "set sound 0 ampdecay 0",
+        "set sound 0 filtcutoff 20",
+        "set sound 1 machinetype:bdclassic",
+        "set sound 1 filtertype:hp1",
+        "set sound 1 ampdecay 100",
+        "set sound 1 filtcutoff 120",
+    ] {
+        rytm.message(message).unwrap();
+    }
+    rytm
+}
+
+fn morphed(rytm: &Rytm, position: &str, identifiers: &[&str]) -> Vec<Output> {
+    rytm.message(&format!("morph sound 0 sound 1 {position} sound_wb 0"))
+        .unwrap();
+    identifiers
+        .iter()
+        .flat_map(|identifier| {
+            rytm.message(&format!("get sound_wb 0 {identifier}"))
+                .unwrap()
+        })
+        .collect()
+}
+
+const IDENTIFIERS: &[&str] = &["ampdecay", "filtcutoff", "machinetype:", "filtertype:"];
+
+#[test]
+fn the_first_position_is_the_first_sound() {
+    let rytm = rytm_with_sounds();
+
+    assert_eq!(
+        morphed(&rytm, "0.0", IDENTIFIERS),
+        vec![
+            query("0 ampdecay 0"),
+            query("0 filtcutoff 20"),
+            query("0 machinetype bdhard"),
+            query("0 filtertype lp2"),
+        ]
+    );
+}
+
+#[test]
+fn the_last_position_is_the_second_sound() {
+    let rytm = rytm_with_sounds();
+
+    assert_eq!(
+        morphed(&rytm, "1.0", IDENTIFIERS),
+        vec![
+            query("0 ampdecay 100"),
+            query("0 filtcutoff 120"),
+            query("0 machinetype bdclassic"),
+            query("0 filtertype hp1"),
+        ]
+    );
+}
+
+#[test]
+fn numbers_are_interpolated_and_enums_switch_in_the_middle() {
+    let rytm = rytm_with_sounds();
+
+    assert_eq!(
+        morphed(&rytm, "0.25", IDENTIFIERS),
+        vec![
+            query("0 ampdecay 25"),
+            query("0 filtcutoff 45"),
+            query("0 machinetype bdhard"),
+            query("0 filtertype lp2"),
+        ]
+    );
+    assert_eq!(
+        morphed(&rytm, "0.5", IDENTIFIERS),
+        vec![
+            query("0 ampdecay 50"),
+            query("0 filtcutoff 70"),
+            query("0 machinetype bdclassic"),
+            query("0 filtertype hp1"),
+        ]
+    );
+}
+
+#[test]
+fn machine_parameters_are_interpolated_only_between_the_same_machines() {
+    let rytm = Rytm::default();
+    for message in [
+        "set sound 0 machinetype:bdhard",
+        "set sound 0 dec 10",
+        "set sound 1 machinetype:bdhard",
+        "set sound 1 dec 90",
+    ] {
+        rytm.message(message).unwrap();
+    }
+
+    assert_eq!(morphed(&rytm, "0.5", &["dec"]), vec![query("0 dec 50")]);
+
+    rytm.message("set sound 1 machinetype:bdclassic").unwrap();
+    rytm.message("set sound 1 dec 90").unwrap();
+    assert_eq!(morphed(&rytm, "0.25", &["dec"]), vec![query("0 dec 10")]);
+    assert_eq!(morphed(&rytm, "0.75", &["dec"]), vec![query("0 dec 90")]);
+}
@@ -0,0 +1,198 @@
+use rytm_core::atom::{Atom, AtomType};
+use rytm_core::load::load_object;
+use rytm_core::output::Output;
+use rytm_core::rytm::Rytm;
+use rytm_core::types::ObjectTypeSelector;
+use serde_json::json;
+
+fn query(atoms: &str) -> Output {
+    Output::Query(Atom::parse_list(atoms))
+}
+
+/// Locks a parameter of a trig, reads it back, clears it and reads it back again.
+fn round_trip(rytm: &Rytm, trig: &str, identifier: &str, value: &str) {
+    let get = format!("get {trig} plockget {identifier}");
+    let trig_index = trig.rsplit(' ').next().unwrap();
+
+    assert_eq!(
+        rytm.message(&get).unwrap(),
+        vec![query(&format!("{identifier} {trig_index} unset"))],
+        "{}",
+        identifier
+    );
+    rytm.message(&format!("set {trig} plockset {identifier} {value}"))
+        .unwrap();
+    assert_eq!(
+        rytm.message(&get).unwrap(),
+        vec![query(&format!("{identifier} {trig_index} {value}"))],
+        "{}",
+        identifier
+    );
+    rytm.message(&format!("set {trig} plockclear {identifier}"))
+        .unwrap();
+    assert_eq!(
+        rytm.message(&get).unwrap(),
+        vec![query(&format!("{identifier} {trig_index} unset"))],
+        "{}",
+        identifier
+    );
+}
+
+const DISTORTION_PLOCKS: &[(&str, &str)] = &[
+    ("fxdistamt", "64"),
+    ("fxdistsym", "-20"),
+    ("fxdistdov", "100"),
+    ("fxdistdelpost", "1"),
+    ("fxdistrevpost", "1"),
+];
+
+#[test]
+fn distortion_parameters_are_locked_read_and_cleared() {
+    let rytm = Rytm::default();
+
+    for (identifier, value) in DISTORTION_PLOCKS {
+        round_trip(&rytm, "pattern 0 1 5", identifier, value);
+    }
+}
+
+#[test]
+fn distortion_locks_are_output_like_the_other_fx_locks() {
+    let rytm = Rytm::default();
+    let types = |identifier: &str, value: &str| {
+        rytm.message(&format!("set pattern 0 1 5 plockset {identifier} {value}"))
+            .unwrap();
+        let outputs = rytm
+            .message(&format!("get pattern 0 1 5 plockget {identifier}"))
+            .unwrap();
+        let [Output::Query(atoms)] = &outputs[..] else {
+            panic!("Expected a single query output, got {:?}", outputs);
+        };
+        atoms.iter().map(Atom::get_type).collect::<Vec<_>>()
+    };
+
+    let fx_types = types("fxdeltime", "24");
+    assert_eq!(
+        fx_types,
+        vec![
+            Some(AtomType::Symbol),
+            Some(AtomType::Int),
+            Some(AtomType::Int)
+        ]
+    );
+    assert_eq!(types("fxrevdecay", "40"), fx_types);
+    for (identifier, value) in DISTORTION_PLOCKS {
+        assert_eq!(types(identifier, value), fx_types, "{}", identifier);
+    }
+}
+
+#[test]
+fn machine_locks_of_patterns_which_share_a_kit_are_kept_apart() {
+    let rytm = Rytm::default();
+
+    // Every pattern of a default project uses kit 0 which has a bdhard on track 0.
+    rytm.message("set pattern 0 0 1 plockset dec 10").unwrap();
+    rytm.message("set pattern 1 0 1 plockset dec 20").unwrap();
+    rytm.message("set pattern 2 0 0..3 plockset dec 30")
+        .unwrap();
+
+    assert_eq!(
+        rytm.message("get pattern 0 0 1 plockget dec").unwrap(),
+        vec![query("dec 1 10")]
+    );
+    assert_eq!(
+        rytm.message("get pattern 1 0 1 plockget dec").unwrap(),
+        vec![query("dec 1 20")]
+    );
+    assert_eq!(
+        rytm.message("get pattern 2 0 1 plockget dec").unwrap(),
+        vec![query("dec 1 30")]
+    );
+
+    let outputs = rytm.message("dump pattern 0").unwrap();
+    let [Output::Dictionary(_, dump)] = &outputs[..] else {
+        panic!("Expected a single dictionary output, got {:?}", outputs);
+    };
+    assert_eq!(dump["tracks"][0]["trigs"][1]["plocks"]["dec"], json!(10));
+
+    load_object(
+        &mut rytm.project.lock().unwrap(),
+        ObjectTypeSelector::Pattern(3),
+        dump,
+    )
+    .unwrap();
+    assert_eq!(
+        rytm.message("get pattern 3 0 1 plockget dec").unwrap(),
+        vec![query("dec 1 10")]
+    );
+    assert_eq!(
+        rytm.message("get pattern 1 0 1 plockget dec").unwrap(),
+        vec![query("dec 1 20")]
+    );
+}
+
+#[test]
+fn machine_locks_of_the_work_buffer_pattern_are_reached_through_the_work_buffer_sounds() {
+    let rytm = Rytm::default();
+
+    rytm.message("set sound_wb 0 machinetype:bdhard").unwrap();
+    rytm.message("set pattern_wb 0 1 plockset dec 10").unwrap();
+    rytm.message("set pattern 0 0 1 plockset dec 20").unwrap();
+
+    assert_eq!(
+        rytm.message("get pattern_wb 0 1 plockget dec").unwrap(),
+        vec![query("dec 1 10")]
+    );
+    assert_eq!(
+        rytm.message("get pattern 0 0 1 plockget dec").unwrap(),
+        vec![query("dec 1 20")]
+    );
+}
+
+#[test]
+fn machine_parameters_are_locked_read_and_cleared() {
+    let rytm = Rytm::default();
+
+    // Track 0 of kit 0 which every pattern of a default project uses is a bdhard.
+    round_trip(&rytm, "pattern 0 0 1", "dec", "64");
+}
+
+#[test]
+fn machine_enums_are_locked_read_and_cleared() {
+    let rytm = Rytm::default();
+    let get = "get pattern_wb 1 2 plockget bdsharpwaveform:";
+
+    rytm.message("set sound_wb 1 machinetype:bdsharp").unwrap();
+    assert_eq!(
+        rytm.message(get).unwrap(),
+        vec![query("bdsharpwaveform 2 unset")]
+    );
+    rytm.message("set pattern_wb 1 2 plockset bdsharpwaveform:asina")
+        .unwrap();
+    assert_eq!(
+        rytm.message(get).unwrap(),
+        vec![query("bdsharpwaveform 2 asina")]
+    );
+    rytm.message("set pattern_wb 1 2 plockclear bdsharpwaveform:")
+        .unwrap();
+    assert_eq!(
+        rytm.message(get).unwrap(),
+        vec![query("bdsharpwaveform 2 unset")]
+    );
+}
+
+#[test]
+fn parameters_of_another_machine_are_an_error() {
+    let rytm = Rytm::default();
+    let does_not_belong =
+        "Invalid machine parameter: swd does not belong to the machine bdhard which is assigned to this sound.";
+
+    rytm.message("set sound_wb 0 machinetype:bdhard").unwrap();
+
+    let err = rytm.message("set sound_wb 0 swd 10").unwrap_err();
+    assert!(err.to_string().starts_with(does_not_belong), "{}", err);
+
+    let err = rytm
+        .message("set pattern_wb 0 1 plockset swd 10")
+        .unwrap_err();
+    assert!(err.to_string().starts_with(does_not_belong), "{}", err);
+}
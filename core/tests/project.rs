@@ -0,0 +1,83 @@
+use rytm_core::atom::{Atom, SymbolRef};
+use rytm_core::output::Output;
+use rytm_core::rytm::Rytm;
+use std::path::PathBuf;
+
+fn query(atoms: &str) -> Output {
+    Output::Query(Atom::parse_list(atoms))
+}
+
+/// A path in the temporary directory which is unique to the test.
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("rytm-{}-{}.json", name, std::process::id()))
+}
+
+/// Runs a test on a thread with a stack as large as the one of the Max main thread.
+///
+/// Deserializing a whole project in a debug build needs more than the default stack of a test thread.
+fn with_large_stack(test: impl FnOnce() + Send + 'static) {
+    std::thread::Builder::new()
+        .stack_size(8 * 1024 * 1024)
+        .spawn(test)
+        .unwrap()
+        .join()
+        .unwrap();
+}
+
+#[test]
+fn a_written_project_is_read_back() {
+    with_large_stack(a_written_project_is_read_back_on_a_large_stack);
+}
+
+fn a_written_project_is_read_back_on_a_large_stack() {
+    let path = temp_path("read");
+    let rytm = Rytm::default();
+    rytm.message("set pattern 1 2 3 plockset filtcutoff 64")
+        .unwrap();
+    rytm.message("set kit 4 name hello").unwrap();
+    rytm.message("set sound_wb 1 name wb").unwrap();
+    rytm.message(&format!("write {}", path.display())).unwrap();
+
+    let read = Rytm::default();
+    let result = read.message(&format!("read {}", path.display()));
+    std::fs::remove_file(&path).unwrap();
+    result.unwrap();
+
+    assert_eq!(
+        read.message("get pattern 1 2 3 plockget filtcutoff")
+            .unwrap(),
+        vec![query("filtcutoff 3 64")]
+    );
+    assert_eq!(
+        read.message("get kit 4 name").unwrap(),
+        vec![query("4 name hello")]
+    );
+    // Work buffer sounds are read to the work buffer, not to the sound pool.
+    assert_eq!(
+        read.message("get sound 1 name").unwrap(),
+        rytm.message("get sound 1 name").unwrap()
+    );
+}
+
+#[test]
+fn a_project_differs_from_its_file_by_the_changed_value() {
+    with_large_stack(a_project_differs_from_its_file_by_the_changed_value_on_a_large_stack);
+}
+
+fn a_project_differs_from_its_file_by_the_changed_value_on_a_large_stack() {
+    let path = temp_path("diff");
+    let rytm = Rytm::default();
+    rytm.message("set pattern 1 2 3 plockset filtcutoff 64")
+        .unwrap();
+    rytm.message(&format!("write {}", path.display())).unwrap();
+    // Objects are compared as they are read from the file, which are decoded like the ones received from the device.
+    rytm.message(&format!("read {}", path.display())).unwrap();
+
+    rytm.message("set kit 4 name hello").unwrap();
+    let differences = rytm.message(&format!("diff project {}", path.display()));
+    std::fs::remove_file(&path).unwrap();
+
+    let mut expected = Atom::parse_list("diff kit 4 name hello");
+    expected.push(Atom::from(SymbolRef::from("KIT 4")));
+    assert_eq!(differences.unwrap(), vec![Output::Query(expected)]);
+}
@@ -0,0 +1,86 @@
+use rytm_core::atom::{Atom, AtomValue};
+use rytm_core::output::Output;
+use rytm_core::rytm::Rytm;
+use serde_json::Value;
+
+/// The state which randomize changes, the sounds, kit and pattern of the work buffer.
+fn work_buffer(rytm: &Rytm) -> Value {
+    let project = rytm.project.lock().unwrap();
+    let work_buffer = project.work_buffer();
+    serde_json::json!({
+        "sounds": serde_json::to_value(work_buffer.sounds()).unwrap(),
+        "kit": serde_json::to_value(work_buffer.kit()).unwrap(),
+        "pattern": serde_json::to_value(work_buffer.pattern()).unwrap(),
+    })
+}
+
+const MESSAGES: &[&str] = &[
+    "randomize sound_wb 0 amp filter lfo samp accentlev",
+    "randomize sound_wb 1 mutate 0.3 amp filter lfo samp",
+    "randomize kit_wb fx",
+    "randomize kit_wb mutate 0.3 fx",
+    "randomize pattern_wb all track steps",
+    "randomize pattern_wb 0..3 mutate 0.3 track",
+];
+
+#[test]
+fn the_same_seed_gives_the_same_values() {
+    let randomized = |seed: u64| {
+        let rytm = Rytm::default();
+        for message in MESSAGES {
+            rytm.message(&format!("{message} seed {seed}")).unwrap();
+        }
+        work_buffer(&rytm)
+    };
+
+    assert_eq!(randomized(42), randomized(42));
+    assert_ne!(randomized(42), randomized(43));
+}
+
+#[test]
+fn seeding_restarts_the_generator() {
+    let rytm = Rytm::default();
+
+    rytm.message("randomize sound_wb 0 filter seed 7").unwrap();
+    let first = work_buffer(&rytm);
+    rytm.message("randomize sound_wb 0 filter").unwrap();
+    assert_ne!(work_buffer(&rytm), first);
+    rytm.message("randomize sound_wb 0 filter seed 7").unwrap();
+    assert_eq!(work_buffer(&rytm), first);
+}
+
+fn int_value(output: &[Output]) -> isize {
+    match output {
+        [Output::Query(atoms)] => match atoms.last().and_then(Atom::get_value) {
+            Some(AtomValue::Int(value)) => value,
+            _ => panic!("{:?} is not an int", atoms),
+        },
+        _ => panic!("{:?} is not a single query", output),
+    }
+}
+
+/// Mutating by the whole range clamps values to both ends of their ranges often, values which
+/// the setters don't accept fail the message.
+#[test]
+fn values_stay_in_the_ranges_which_the_setters_accept() {
+    let rytm = Rytm::default();
+    let mut feedback = Vec::new();
+    let mut steps = Vec::new();
+
+    for seed in 0..64 {
+        for message in [
+            "randomize sound_wb 0 mutate 1.0 amp filter lfo samp accentlev",
+            "randomize kit_wb mutate 1.0 fx",
+            "randomize pattern_wb all mutate 1.0 track steps",
+        ] {
+            rytm.message(&format!("{message} seed {seed}")).unwrap();
+        }
+        feedback.push(int_value(
+            &rytm.message("get kit_wb fxdelfeedback").unwrap(),
+        ));
+        steps.push(int_value(&rytm.message("get pattern_wb 0 steps").unwrap()));
+    }
+
+    assert!(feedback.contains(&0) && feedback.contains(&198));
+    assert!(steps.contains(&1) && steps.contains(&64));
+}
@@ -0,0 +1,208 @@
+//! No message from a patch may panic.
+//!
+//! The release build of the external aborts on a panic which takes Max down with it, so every message has to end
+//! in an output or an error. These tests send a sweep of valid and malformed messages and fail on any panic.
+
+use rytm_core::api::*;
+use rytm_core::output::Output;
+use rytm_core::rytm::Rytm;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::Arc;
+
+/// Every selector except `write` and `export` which would write files.
+const SELECTORS: &[&str] = &[
+    "query",
+    "send",
+    "set",
+    "get",
+    "debug",
+    "read",
+    "import",
+    "dump",
+    "load",
+    "copy",
+    "paste",
+    "clear",
+    "shift",
+    "reverse",
+    "copysound",
+    "swapsound",
+    "randomize",
+    "morph",
+    "undo",
+    "redo",
+    "history",
+    "diff",
+];
+
+const OBJECTS: &[&str] = &[
+    "pattern",
+    "pattern_wb",
+    "kit",
+    "kit_wb",
+    "sound",
+    "sound_wb",
+    "global",
+    "global_wb",
+    "settings",
+    "all",
+    "nope",
+];
+
+/// Indices in and out of range, of the wrong type and selections.
+const INDICES: &[&str] = &[
+    "-1", "0", "1", "12", "13", "64", "128", "0.5", "x", "0..200", "all",
+];
+
+/// Objects with indices in range, to reach the identifiers and enums behind them.
+const ADDRESSED_OBJECTS: &[&str] = &[
+    "pattern 1",
+    "pattern 1 2",
+    "pattern 1 2 3",
+    "pattern_wb",
+    "pattern_wb 2",
+    "pattern_wb 2 3",
+    "pattern_wb all all",
+    "kit 1",
+    "kit_wb",
+    "sound 1",
+    "sound_wb 1",
+    "global 1",
+    "global_wb",
+    "settings",
+];
+
+/// Parameters of identifiers and enums in and out of range and of the wrong type.
+const PARAMETERS: &[&str] = &["", "-1", "0", "1", "1000", "0.5", "x", "1 -1", "1 1000"];
+
+/// Sends a message and returns it back if it panics.
+fn panics(rytm: &Rytm, message: &str) -> Option<String> {
+    catch_unwind(AssertUnwindSafe(|| {
+        let _ = rytm.message(message);
+        rytm.bulk_tick();
+    }))
+    .err()
+    .map(|_| message.to_owned())
+}
+
+fn assert_no_panics(messages: impl Iterator<Item = String>) {
+    let rytm = Rytm::default();
+    let panicking = messages
+        .filter_map(|message| panics(&rytm, &message))
+        .collect::<Vec<_>>();
+
+    assert!(
+        panicking.is_empty(),
+        "{} messages panicked:\n{}",
+        panicking.len(),
+        panicking.join("\n")
+    );
+}
+
+/// Identifiers of every object, each is sent to every object.
+const IDENTIFIERS: &[&[&str]] = &[
+    pattern_action_type::PATTERN_ACTION_TYPES,
+    track_action_type::TRACK_ACTION_TYPES,
+    trig_action_type::TRIG_ACTION_TYPES,
+    kit_action_type::KIT_ACTION_TYPES,
+    sound_action_type::SOUND_ACTION_TYPES,
+    global_action_type::GLOBAL_ACTION_TYPES,
+    settings_action_type::SETTINGS_ACTION_TYPES,
+    machine_parameter_type::MACHINE_PARAMETER_TYPES,
+    kit_element_type::KIT_ELEMENTS,
+    plock_type::ALL_PLOCK_TYPES,
+];
+
+/// Enum types of every object, each is sent to every object.
+const ENUM_TYPES: &[&[&str]] = &[
+    pattern_enum_type::PATTERN_ENUM_TYPES,
+    track_enum_type::TRACK_ENUM_TYPES,
+    trig_enum_type::TRIG_ENUM_TYPES,
+    kit_enum_type::KIT_ENUM_TYPES,
+    sound_enum_type::SOUND_ENUM_TYPES,
+    sound_machine_enum_type::SOUND_MACHINE_ENUM_TYPES,
+    global_enum_type::GLOBAL_ENUM_TYPES,
+    settings_enum_type::SETTINGS_ENUM_TYPES,
+];
+
+fn identifiers_and_enums() -> impl Iterator<Item = String> {
+    let identifiers = IDENTIFIERS
+        .iter()
+        .flat_map(|identifiers| identifiers.iter())
+        .map(|identifier| (*identifier).to_owned());
+    let enums = ENUM_TYPES
+        .iter()
+        .flat_map(|enums| enums.iter())
+        .flat_map(|enum_type| {
+            ["", "x", "1", "-1"]
+                .iter()
+                .map(move |value| format!("{}:{}", enum_type, value))
+        });
+    identifiers.chain(enums)
+}
+
+#[test]
+fn no_object_address_panics() {
+    let messages = SELECTORS.iter().flat_map(|selector| {
+        std::iter::once((*selector).to_owned()).chain(OBJECTS.iter().flat_map(move |object| {
+            std::iter::once(format!("{} {}", selector, object)).chain(INDICES.iter().flat_map(
+                move |first| {
+                    INDICES
+                        .iter()
+                        .map(move |second| format!("{} {} {} {}", selector, object, first, second))
+                },
+            ))
+        }))
+    });
+    assert_no_panics(messages);
+}
+
+#[test]
+fn no_identifier_or_enum_panics() {
+    let messages = ["get", "set"].iter().flat_map(|selector| {
+        ADDRESSED_OBJECTS.iter().flat_map(move |object| {
+            identifiers_and_enums().flat_map(move |identifier| {
+                PARAMETERS.iter().map(move |parameter| {
+                    format!("{} {} {} {}", selector, object, identifier, parameter)
+                })
+            })
+        })
+    });
+    assert_no_panics(messages);
+}
+
+#[test]
+fn no_live_input_panics() {
+    let rytm = Rytm::default();
+    for byte in 0..=u8::MAX {
+        for _ in 0..3 {
+            assert!(
+                catch_unwind(AssertUnwindSafe(|| rytm.handle_live_input(byte))).is_ok(),
+                "{}",
+                byte
+            );
+        }
+    }
+}
+
+#[test]
+fn a_poisoned_project_is_recovered() {
+    let rytm = Rytm::default();
+
+    let project = Arc::clone(&rytm.project);
+    let _ = std::thread::spawn(move || {
+        let _guard = project.lock().unwrap();
+        panic!("Poisons the project.");
+    })
+    .join();
+    assert!(rytm.project.is_poisoned());
+
+    rytm.message("set kit_wb name hello").unwrap();
+    assert_eq!(
+        rytm.message("get kit_wb name").unwrap(),
+        vec![Output::Query(rytm_core::atom::Atom::parse_list(
+            "0 name hello"
+        ))]
+    );
+    assert!(!rytm.project.is_poisoned());
+}
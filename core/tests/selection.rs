@@ -0,0 +1,91 @@
+use rytm_core::atom::Atom;
+use rytm_core::output::Output;
+use rytm_core::rytm::Rytm;
+use rytm_core::selection::IndexSelection;
+use rytm_rs::prelude::HoldsTrigFlags;
+
+fn select(selector: &str, count: usize) -> Result<Option<Vec<usize>>, String> {
+    IndexSelection::try_parse(&Atom::parse_list(selector), 0, count, "Trig")
+        .map(|selection| selection.map(|selection| selection.indices))
+        .map_err(|err| err.to_string())
+}
+
+fn query(atoms: &str) -> Output {
+    Output::Query(Atom::parse_list(atoms))
+}
+
+#[test]
+fn selectors_select_indices() {
+    assert_eq!(select("3", 64), Ok(Some(vec![3])));
+    assert_eq!(select("2..5", 64), Ok(Some(vec![2, 3, 4, 5])));
+    assert_eq!(select("0|4|8", 64), Ok(Some(vec![0, 4, 8])));
+    assert_eq!(select("every 4", 16), Ok(Some(vec![0, 4, 8, 12])));
+    assert_eq!(select("all", 12), Ok(Some((0..12).collect())));
+    // Symbols which are not selectors are left for identifiers and enums.
+    assert_eq!(select("enable", 64), Ok(None));
+}
+
+#[test]
+fn invalid_selectors_are_rejected() {
+    assert!(select("every 0", 64).is_err());
+    assert!(select("every -2", 64).is_err());
+    assert!(select("every", 64).is_err());
+    assert!(select("5..2", 64).is_err());
+    assert!(select("0..64", 64).is_err());
+    assert!(select("-1", 64).is_err());
+    assert!(select("64", 64).is_err());
+    assert!(select("0|4|99", 64).is_err());
+    assert!(select("0|x", 64).is_err());
+}
+
+#[test]
+fn a_selection_with_an_out_of_range_member_changes_nothing() {
+    let rytm = Rytm::default();
+
+    assert!(rytm.message("set pattern 0 0|12 3 enable 1").is_err());
+    assert!(rytm.message("set pattern 0 1 60..64 enable 1").is_err());
+
+    let project = rytm.project.lock().unwrap();
+    assert!(project.patterns()[0]
+        .tracks()
+        .iter()
+        .flat_map(|track| track.trigs().iter())
+        .all(|trig| !trig.enabled_trig()));
+}
+
+#[test]
+fn a_failing_plock_selection_reverts_the_locks_which_are_already_set() {
+    let rytm = Rytm::default();
+
+    // Every basic lock of a track takes one of the 72 slots of the pool, fill 71 of them.
+    for plock in [
+        "filtcutoff",
+        "filtres",
+        "filtattack",
+        "amphold",
+        "ampattack",
+    ] {
+        rytm.message(&format!("set pattern 0 all 0 plockset {plock} 10"))
+            .unwrap();
+    }
+    rytm.message("set pattern 0 0..9 0 plockset filtdecay 10")
+        .unwrap();
+    rytm.message("set pattern 0 0 5 plockset ampdecay 40")
+        .unwrap();
+
+    // Track 0 changes its lock, track 1 takes the last slot and track 2 does not fit.
+    let error = rytm
+        .message("set pattern 0 0|1|2 5 plockset ampdecay 90")
+        .unwrap_err();
+    assert!(error.to_string().contains("memory full"));
+
+    assert_eq!(
+        rytm.message("get pattern 0 0 5 plockget ampdecay").unwrap(),
+        vec![query("ampdecay 5 40")]
+    );
+    let project = rytm.project.lock().unwrap();
+    for track in [1, 2] {
+        let trig = &project.patterns()[0].tracks()[track].trigs()[5];
+        assert_eq!(trig.plock_get_amplitude_decay().unwrap(), None);
+    }
+}
@@ -0,0 +1,135 @@
+use rytm_core::atom::Atom;
+use rytm_core::output::Output;
+use rytm_core::rytm::Rytm;
+use rytm_rs::object::sound::types::MachineType;
+
+fn query(atoms: &str) -> Output {
+    Output::Query(Atom::parse_list(atoms))
+}
+
+fn pool_machine_type(rytm: &Rytm, index: usize) -> MachineType {
+    rytm.project.lock().unwrap().pool_sounds()[index].machine_type()
+}
+
+#[test]
+fn copied_sounds_bring_their_machine_and_parameters() {
+    let rytm = Rytm::default();
+
+    rytm.message("set sound 3 machinetype:bdhard").unwrap();
+    rytm.message("set sound 3 dec 90").unwrap();
+    rytm.message("set sound 3 ampdecay 33").unwrap();
+    rytm.message("copysound sound 3 kit 2 sound 4").unwrap();
+
+    {
+        let project = rytm.project.lock().unwrap();
+        let sound = &project.kits()[2].sounds()[4];
+        assert_eq!(sound.machine_type(), MachineType::BdHard);
+        assert_eq!(sound.amplitude().decay(), 33);
+    }
+
+    // The kit sound is copied on to the work buffer to read its machine parameters back.
+    rytm.message("copysound kit 2 sound 4 sound_wb 5").unwrap();
+    assert_eq!(
+        rytm.message("get sound_wb 5 machinetype:").unwrap(),
+        vec![query("5 machinetype bdhard")]
+    );
+    assert_eq!(
+        rytm.message("get sound_wb 5 dec").unwrap(),
+        vec![query("5 dec 90")]
+    );
+    assert_eq!(
+        rytm.message("get sound_wb 5 ampdecay").unwrap(),
+        vec![query("5 ampdecay 33")]
+    );
+
+    // The source is left as it is.
+    assert_eq!(pool_machine_type(&rytm, 3), MachineType::BdHard);
+    assert_eq!(
+        rytm.message("get sound 3 dec").unwrap(),
+        vec![query("3 dec 90")]
+    );
+}
+
+#[test]
+fn swapped_sounds_exchange_their_machines_and_parameters() {
+    let rytm = Rytm::default();
+
+    rytm.message("set sound 1 machinetype:bdhard").unwrap();
+    rytm.message("set sound 1 dec 90").unwrap();
+    rytm.message("set sound 2 machinetype:sdclassic").unwrap();
+    rytm.message("set sound 2 ampdecay 12").unwrap();
+    let first = (
+        rytm.message("get sound 1 dec").unwrap(),
+        rytm.message("get sound 1 ampdecay").unwrap(),
+    );
+    let second = (
+        rytm.message("get sound 2 ampdecay").unwrap(),
+        rytm.message("get sound 2 machinetype:").unwrap(),
+    );
+
+    rytm.message("swapsound sound 1 sound 2").unwrap();
+    assert_eq!(pool_machine_type(&rytm, 1), MachineType::SdClassic);
+    assert_eq!(pool_machine_type(&rytm, 2), MachineType::BdHard);
+    assert_eq!(
+        rytm.message("get sound 1 ampdecay").unwrap(),
+        vec![query("1 ampdecay 12")]
+    );
+    assert_eq!(
+        rytm.message("get sound 2 dec").unwrap(),
+        vec![query("2 dec 90")]
+    );
+
+    // Swapping in the other order restores both sounds.
+    rytm.message("swapsound sound 2 sound 1").unwrap();
+    assert_eq!(
+        (
+            rytm.message("get sound 1 dec").unwrap(),
+            rytm.message("get sound 1 ampdecay").unwrap(),
+        ),
+        first
+    );
+    assert_eq!(
+        (
+            rytm.message("get sound 2 ampdecay").unwrap(),
+            rytm.message("get sound 2 machinetype:").unwrap(),
+        ),
+        second
+    );
+}
+
+#[test]
+fn swapping_a_pool_sound_with_a_kit_sound_is_symmetric() {
+    let rytm = Rytm::default();
+
+    rytm.message("set sound 0 machinetype:bdhard").unwrap();
+    rytm.message("set sound 0 ampdecay 40").unwrap();
+    rytm.message("set sound 1 machinetype:sdclassic").unwrap();
+    rytm.message("set sound 1 ampdecay 70").unwrap();
+    rytm.message("copysound sound 1 kit 6 sound 2").unwrap();
+
+    rytm.message("swapsound sound 0 kit 6 sound 2").unwrap();
+    {
+        let project = rytm.project.lock().unwrap();
+        let sound = &project.kits()[6].sounds()[2];
+        assert_eq!(sound.machine_type(), MachineType::BdHard);
+        assert_eq!(sound.amplitude().decay(), 40);
+    }
+    assert_eq!(pool_machine_type(&rytm, 0), MachineType::SdClassic);
+    assert_eq!(
+        rytm.message("get sound 0 ampdecay").unwrap(),
+        vec![query("0 ampdecay 70")]
+    );
+
+    rytm.message("swapsound kit 6 sound 2 sound 0").unwrap();
+    {
+        let project = rytm.project.lock().unwrap();
+        let sound = &project.kits()[6].sounds()[2];
+        assert_eq!(sound.machine_type(), MachineType::SdClassic);
+        assert_eq!(sound.amplitude().decay(), 70);
+    }
+    assert_eq!(pool_machine_type(&rytm, 0), MachineType::BdHard);
+    assert_eq!(
+        rytm.message("get sound 0 ampdecay").unwrap(),
+        vec![query("0 ampdecay 40")]
+    );
+}
@@ -0,0 +1,104 @@
+use rytm_core::atom::{Atom, SymbolRef};
+use rytm_core::output::Output;
+use rytm_core::rytm::Rytm;
+use std::path::PathBuf;
+
+fn query(atoms: &str) -> Output {
+    Output::Query(Atom::parse_list(atoms))
+}
+
+/// A path in the temporary directory which is unique to the test.
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("rytm-{}-{}.syx", name, std::process::id()))
+}
+
+/// Exports an object and imports the file to another one of the same type.
+fn export_and_import(rytm: &Rytm, from: &str, to: &str, name: &str) {
+    let path = temp_path(name);
+    rytm.message(&format!("export {} {}", from, path.display()))
+        .unwrap();
+    let imported = rytm.message(&format!("import {} {}", to, path.display()));
+    std::fs::remove_file(&path).unwrap();
+    imported.unwrap();
+}
+
+#[test]
+fn an_exported_pattern_is_imported() {
+    let rytm = Rytm::default();
+    rytm.message("set pattern 1 2 3 enable 1").unwrap();
+    rytm.message("set pattern 1 2 3 plockset filtcutoff 64")
+        .unwrap();
+
+    export_and_import(&rytm, "pattern 1", "pattern 5", "pattern");
+    assert_eq!(
+        rytm.message("get pattern 5 2 3 enable").unwrap(),
+        vec![query("2 3 enable 1")]
+    );
+    assert_eq!(
+        rytm.message("get pattern 5 2 3 plockget filtcutoff")
+            .unwrap(),
+        vec![query("filtcutoff 3 64")]
+    );
+}
+
+#[test]
+fn an_exported_kit_is_imported() {
+    let rytm = Rytm::default();
+    rytm.message("set kit 2 name hello").unwrap();
+    rytm.message("set kit 2 fxdeltime 24").unwrap();
+
+    export_and_import(&rytm, "kit 2", "kit 6", "kit");
+    assert_eq!(
+        rytm.message("get kit 6 name").unwrap(),
+        vec![query("6 name hello")]
+    );
+    assert_eq!(
+        rytm.message("get kit 6 fxdeltime").unwrap(),
+        vec![query("6 fxdeltime 24")]
+    );
+}
+
+#[test]
+fn an_invalid_message_leaves_the_project_untouched() {
+    let rytm = Rytm::default();
+    rytm.message("set kit 2 name hello").unwrap();
+    let outputs = rytm.message("send kit 2").unwrap();
+    let [Output::Sysex(sysex)] = &outputs[..] else {
+        panic!("Expected a single sysex output, got {:?}", outputs);
+    };
+
+    // The second kit of the file is cut short after its header.
+    let mut corrupted = sysex[..32].to_vec();
+    corrupted.push(0xF7);
+    let path = temp_path("invalid");
+    std::fs::write(&path, [sysex.clone(), corrupted].concat()).unwrap();
+
+    let imported = rytm.message(&format!("import kit 5 {}", path.display()));
+    std::fs::remove_file(&path).unwrap();
+    assert!(imported.is_err());
+    let mut name = Atom::parse_list("5 name");
+    name.push(Atom::from(SymbolRef::from("KIT 5")));
+    assert_eq!(
+        rytm.message("get kit 5 name").unwrap(),
+        vec![Output::Query(name)]
+    );
+}
+
+#[test]
+fn work_buffer_sounds_are_sent_to_the_work_buffer() {
+    let rytm = Rytm::default();
+    let outputs = rytm.message("send sound_wb 1").unwrap();
+    let [Output::Sysex(sysex)] = &outputs[..] else {
+        panic!("Expected a single sysex output, got {:?}", outputs);
+    };
+
+    let receiver = Rytm::default();
+    receiver.handle_sysex_response(sysex).unwrap();
+    assert_eq!(
+        receiver.take_outputs().first(),
+        Some(&Output::Notification(Atom::parse_list(&format!(
+            "decoded sound_wb 1 1 {}",
+            sysex.len()
+        ))))
+    );
+}
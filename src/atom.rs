@@ -0,0 +1,33 @@
+//! Conversions between the atoms of Max and the atoms of the core.
+
+use median::atom::{Atom, AtomValue};
+use median::symbol::SymbolRef;
+use rytm_core::atom::{Atom as CoreAtom, AtomValue as CoreAtomValue, SymbolRef as CoreSymbolRef};
+use rytm_core::error::RytmExternalError;
+use std::ffi::CString;
+
+/// Converts an atom of an incoming message, objects become atoms which the core does not understand.
+pub fn to_core_atom(atom: &Atom) -> Result<CoreAtom, RytmExternalError> {
+    Ok(match atom.get_value() {
+        Some(AtomValue::Int(value)) => CoreAtom::from(CoreAtomValue::Int(value)),
+        Some(AtomValue::Float(value)) => CoreAtom::from(CoreAtomValue::Float(value)),
+        Some(AtomValue::Symbol(symbol)) => CoreAtom::from(CoreSymbolRef::from(symbol.to_string()?)),
+        _ => CoreAtom::default(),
+    })
+}
+
+/// Converts an atom of an outgoing message.
+pub fn to_max_atom(atom: &CoreAtom) -> Atom {
+    match atom.get_value() {
+        Some(CoreAtomValue::Int(value)) => Atom::from(AtomValue::Int(value)),
+        Some(CoreAtomValue::Float(value)) => Atom::from(AtomValue::Float(value)),
+        Some(CoreAtomValue::Symbol(symbol)) => Atom::from(to_max_symbol(symbol.as_str())),
+        None => Atom::from(to_max_symbol("")),
+    }
+}
+
+/// Symbols of Max end at the first nul, names of the device are padded with them.
+pub fn to_max_symbol(symbol: &str) -> SymbolRef {
+    let symbol = symbol.split('\0').next().unwrap_or_default();
+    SymbolRef::from(CString::new(symbol).unwrap_or_default())
+}
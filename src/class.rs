@@ -1,13 +1,12 @@
+use crate::rytm::Rytm;
+use median::method::*;
 use median::{
+    attr::{AttrBuilder, AttrClip, AttrType},
     builder::MaxWrappedBuilder,
     class::Class,
+    inlet::MaxInlet,
     wrapper::{MaxObjWrapped, MaxObjWrapper},
 };
-use rytm_rs::prelude::*;
-use std::sync::{atomic::AtomicBool, Arc, Mutex};
-
-use crate::rytm::Rytm;
-use median::method::*;
 
 // This trait is for Max specific objects, there is another one for MSP objects.
 impl MaxObjWrapped<Self> for Rytm {
@@ -15,20 +14,86 @@ impl MaxObjWrapped<Self> for Rytm {
     fn new(builder: &mut dyn MaxWrappedBuilder<Self>) -> Self {
         // You can also add inlets/outlets here modifying the builder
         builder.with_default_inlet_assist("sysex input (connect sysexin)");
+        builder.add_inlet_with_assist(MaxInlet::Proxy, "cc and nrpn input (connect midiin)");
 
         Self {
-            project: Arc::new(Mutex::new(RytmProject::default())),
-            buffering_sysex: AtomicBool::new(false),
-            sysex_in_buffer: Arc::new(Mutex::new(Vec::with_capacity(1024 * 18))),
-            sysex_out: builder.add_int_outlet_with_assist("sysex output (connect to midiout)"),
+            core: rytm_core::rytm::Rytm::new(Box::new(crate::dictionary::dictionary_to_json)),
+            sysex_out: builder
+                .add_int_outlet_with_assist("sysex, cc and nrpn output (connect to midiout)"),
             query_out: builder.add_anything_outlet_with_assist("get query results (list)"),
+            notification_out: builder.add_anything_outlet_with_assist("notifications (list)"),
+            bulk_clock: builder.with_clockfn(Self::bulk_tick),
         }
     }
 
     // Setup your class here
     fn class_setup(class: &mut Class<MaxObjWrapper<Self>>) {
-        // TODO: Add attribute for setting the device id
         class.add_method(Method::Int(Self::int_tramp)).unwrap();
+        class
+            .add_attribute(
+                AttrBuilder::new_accessors(
+                    "deviceid",
+                    AttrType::Int64,
+                    Self::device_id_tramp,
+                    Self::set_device_id_tramp,
+                )
+                .clip(AttrClip::MinMax(0.0, 127.0))
+                .build()
+                .unwrap(),
+            )
+            .unwrap();
+        class
+            .add_attribute(
+                AttrBuilder::new_accessors(
+                    "spacing",
+                    AttrType::Int64,
+                    Self::bulk_spacing_tramp,
+                    Self::set_bulk_spacing_tramp,
+                )
+                .clip(AttrClip::Min(0.0))
+                .build()
+                .unwrap(),
+            )
+            .unwrap();
+        class
+            .add_attribute(
+                AttrBuilder::new_accessors(
+                    "liveout",
+                    AttrType::Int64,
+                    Self::live_output_tramp,
+                    Self::set_live_output_tramp,
+                )
+                .clip(AttrClip::MinMax(0.0, 1.0))
+                .build()
+                .unwrap(),
+            )
+            .unwrap();
+        class
+            .add_attribute(
+                AttrBuilder::new_accessors(
+                    "livenotify",
+                    AttrType::Int64,
+                    Self::live_notify_tramp,
+                    Self::set_live_notify_tramp,
+                )
+                .clip(AttrClip::MinMax(0.0, 1.0))
+                .build()
+                .unwrap(),
+            )
+            .unwrap();
+        class
+            .add_attribute(
+                AttrBuilder::new_accessors(
+                    "historydepth",
+                    AttrType::Int64,
+                    Self::history_depth_tramp,
+                    Self::set_history_depth_tramp,
+                )
+                .clip(AttrClip::Min(0.0))
+                .build()
+                .unwrap(),
+            )
+            .unwrap();
         class
             .add_method(Method::Anything(Self::anything_with_selector_tramp))
             .unwrap();
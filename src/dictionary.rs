@@ -0,0 +1,169 @@
+//! Reads named dictionaries of Max to json for the `load` message of the core and writes json to dictionaries for `dump`.
+
+use crate::atom::to_max_symbol;
+use median::atom::{Atom, AtomValue};
+use median::max_sys::{self, t_atom, t_atomarray, t_dictionary, t_object, t_symbol};
+use median::symbol::SymbolRef;
+use rytm_core::error::RytmExternalError;
+use serde_json::{Map, Value};
+use std::ffi::c_void;
+use std::ffi::CString;
+use std::os::raw::c_long;
+use std::ptr::addr_of_mut;
+
+/// Reads a named dictionary to json, nested dictionaries become json objects and atom arrays become json arrays.
+pub fn dictionary_to_json(name: &str) -> Result<Value, RytmExternalError> {
+    let symbol = CString::new(name)
+        .map(SymbolRef::from)
+        .map_err(|_| format!("Invalid dictionary: There is no dictionary named {name}."))?;
+    let dictionary = unsafe { max_sys::dictobj_findregistered_retain(symbol.inner()) };
+    if dictionary.is_null() {
+        return Err(format!("Invalid dictionary: There is no dictionary named {name}.").into());
+    }
+
+    let value = unsafe { dictionary_fields(dictionary) };
+    unsafe { max_sys::dictobj_release(dictionary) };
+
+    value
+}
+
+unsafe fn dictionary_fields(dictionary: *mut t_dictionary) -> Result<Value, RytmExternalError> {
+    let mut key_count: c_long = 0;
+    let mut keys: *mut *mut t_symbol = std::ptr::null_mut();
+    if max_sys::dictionary_getkeys(dictionary, addr_of_mut!(key_count), addr_of_mut!(keys)) != 0 {
+        return Err("Invalid dictionary: The keys of the dictionary could not be read.".into());
+    }
+
+    let fields = std::slice::from_raw_parts(keys, key_count as usize)
+        .iter()
+        .map(|&key| {
+            let mut atom_count: c_long = 0;
+            let mut atoms: *mut t_atom = std::ptr::null_mut();
+            max_sys::dictionary_getatoms(
+                dictionary,
+                key,
+                addr_of_mut!(atom_count),
+                addr_of_mut!(atoms),
+            );
+
+            // The atoms are owned by the dictionary.
+            let atoms = std::slice::from_raw_parts(atoms as *const Atom, atom_count as usize);
+            let mut values = atoms
+                .iter()
+                .map(|atom| atom_to_json(atom))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            let value = if values.len() == 1
+                && max_sys::dictionary_entryisatomarray(dictionary, key) == 0
+            {
+                values.remove(0)
+            } else {
+                Value::Array(values)
+            };
+
+            Ok((SymbolRef::from(key).to_string()?, value))
+        })
+        .collect::<Result<Map<_, _>, RytmExternalError>>();
+
+    max_sys::dictionary_freekeys(dictionary, key_count, keys);
+
+    fields.map(Value::Object)
+}
+
+unsafe fn atom_to_json(atom: &Atom) -> Result<Value, RytmExternalError> {
+    Ok(match atom.get_value() {
+        Some(AtomValue::Int(value)) => value.into(),
+        Some(AtomValue::Float(value)) => value.into(),
+        Some(AtomValue::Symbol(value)) => value.to_string()?.into(),
+        // Dictionaries in arrays are stored as objects.
+        Some(AtomValue::Object(object)) => dictionary_fields(object.cast::<t_dictionary>())?,
+        None => Value::Null,
+    })
+}
+
+// From ext_atomarray.h, an atom array with this flag frees the dictionaries and arrays in it when it is freed.
+const ATOMARRAY_FLAG_FREECHILDREN: c_long = 1;
+
+/// Writes a json object to a new registered dictionary and calls `send` with its name.
+///
+/// The dictionary is freed after `send` returns, so a `dict` which receives the name in `send` copies it.
+pub fn with_json_dictionary(
+    value: &Value,
+    send: impl FnOnce(SymbolRef),
+) -> Result<(), RytmExternalError> {
+    let Value::Object(fields) = value else {
+        return Err("Invalid dictionary: Only json objects can be written to a dictionary.".into());
+    };
+
+    let dictionary = unsafe { json_to_dictionary(fields) };
+    let mut name: *mut t_symbol = std::ptr::null_mut();
+    let dictionary = unsafe { max_sys::dictobj_register(dictionary, addr_of_mut!(name)) };
+    if dictionary.is_null() || name.is_null() {
+        return Err("Invalid dictionary: The dictionary could not be registered.".into());
+    }
+
+    send(SymbolRef::from(name));
+    unsafe { max_sys::object_free(dictionary.cast::<c_void>()) };
+
+    Ok(())
+}
+
+unsafe fn json_to_dictionary(fields: &Map<String, Value>) -> *mut t_dictionary {
+    let dictionary = max_sys::dictionary_new();
+
+    for (key, value) in fields {
+        let key = to_max_symbol(key).inner();
+        match value {
+            Value::Object(fields) => {
+                let nested = json_to_dictionary(fields);
+                max_sys::dictionary_appenddictionary(dictionary, key, nested.cast::<t_object>());
+            }
+            // Arrays are always atom arrays, so arrays of one value are read back as arrays.
+            Value::Array(values) => {
+                let array = json_to_atomarray(values);
+                max_sys::dictionary_appendatomarray(dictionary, key, array.cast::<t_object>());
+            }
+            value => {
+                let mut atom = json_to_atom(value);
+                max_sys::dictionary_appendatoms(
+                    dictionary,
+                    key,
+                    1,
+                    addr_of_mut!(atom).cast::<t_atom>(),
+                );
+            }
+        }
+    }
+
+    dictionary
+}
+
+unsafe fn json_to_atomarray(values: &[Value]) -> *mut t_atomarray {
+    let mut atoms = values
+        .iter()
+        .map(|value| json_to_atom(value))
+        .collect::<Vec<_>>();
+    let array = max_sys::atomarray_new(atoms.len() as c_long, atoms.as_mut_ptr().cast::<t_atom>());
+    max_sys::atomarray_flags(array, ATOMARRAY_FLAG_FREECHILDREN);
+    array
+}
+
+unsafe fn json_to_atom(value: &Value) -> Atom {
+    match value {
+        Value::Number(number) => number.as_i64().map_or_else(
+            || Atom::from(AtomValue::Float(number.as_f64().unwrap_or_default())),
+            |number| Atom::from(AtomValue::Int(number as isize)),
+        ),
+        Value::String(string) => Atom::from(to_max_symbol(string)),
+        Value::Bool(boolean) => Atom::from(AtomValue::Int(isize::from(*boolean))),
+        // Dictionaries and arrays in arrays are stored as objects.
+        Value::Object(fields) => Atom::from(AtomValue::Object(
+            json_to_dictionary(fields).cast::<c_void>(),
+        )),
+        Value::Array(values) => Atom::from(AtomValue::Object(
+            json_to_atomarray(values).cast::<c_void>(),
+        )),
+        // A missing value is `none` like in the differences of objects.
+        Value::Null => Atom::from(to_max_symbol("none")),
+    }
+}
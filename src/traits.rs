@@ -5,7 +5,7 @@ use median::{
     symbol::SymbolRef,
 };
 
-use crate::RYTM_EXTERNAL_DEBUG;
+use rytm_core::{error::RytmExternalError, RYTM_EXTERNAL_DEBUG};
 
 // TODO: Revise debugging with right files maybe in a macro.
 // This is a WIP for now.
@@ -222,25 +222,37 @@ impl Post for &String {
 
 impl Post for SymbolRef {
     fn obj_post(&self, obj: *mut max_sys::t_object) {
-        median::object::post(
-            obj,
-            self.to_string().expect("Couldn't post symbol.").as_bytes(),
-        );
+        median::object::post(obj, self.to_string().unwrap_or_default().as_bytes());
     }
 
     fn obj_error(&self, obj: *mut max_sys::t_object) {
-        median::object::error(
-            obj,
-            self.to_string().expect("Couldn't post symbol.").as_bytes(),
-        );
+        median::object::error(obj, self.to_string().unwrap_or_default().as_bytes());
     }
 
     fn post(&self) {
-        median::post(self.to_string().expect("Couldn't post symbol.").as_bytes());
+        median::post(self.to_string().unwrap_or_default().as_bytes());
     }
 
     fn error(&self) {
-        median::error(self.to_string().expect("Couldn't post symbol.").as_bytes());
+        median::error(self.to_string().unwrap_or_default().as_bytes());
+    }
+}
+
+impl Post for RytmExternalError {
+    fn obj_post(&self, obj: *mut max_sys::t_object) {
+        median::object::error(obj, self.to_string());
+    }
+
+    fn obj_error(&self, obj: *mut max_sys::t_object) {
+        median::object::error(obj, self.to_string());
+    }
+
+    fn post(&self) {
+        median::error(self.to_string());
+    }
+
+    fn error(&self) {
+        median::error(self.to_string());
     }
 }
 
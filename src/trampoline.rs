@@ -1,12 +1,16 @@
-use median::max_sys::{t_atom, t_atom_long, t_symbol};
+use median::max_sys::{t_atom, t_atom_long, t_max_err, t_symbol};
 use median::object::MaxObj;
 use median::wrapper::MaxObjWrapper;
+use rytm_core::util::LockOrRecover;
+use std::ffi::c_void;
 use std::os::raw::c_long;
+use std::sync::atomic::Ordering::Relaxed;
 
 use median::method;
 use median::wrapper::WrapperWrapped;
 
 use crate::rytm::Rytm;
+use crate::traits::Post;
 
 impl Rytm {
     pub extern "C" fn int_tramp(wrapper: &::median::wrapper::MaxObjWrapper<Self>, v: t_atom_long) {
@@ -27,4 +31,153 @@ impl Rytm {
             }
         });
     }
+
+    pub extern "C" fn device_id_tramp(
+        wrapper: &MaxObjWrapper<Self>,
+        _attr: c_void,
+        ac: *mut c_long,
+        av: *mut *mut t_atom,
+    ) -> t_max_err {
+        median::attr::get(ac, av, || {
+            t_atom_long::from(
+                WrapperWrapped::wrapped(wrapper)
+                    .core
+                    .device_id
+                    .load(Relaxed),
+            )
+        })
+    }
+
+    pub extern "C" fn set_device_id_tramp(
+        wrapper: &MaxObjWrapper<Self>,
+        _attr: c_void,
+        ac: c_long,
+        av: *mut t_atom,
+    ) -> t_max_err {
+        median::attr::set(ac, av, |device_id: t_atom_long| {
+            // Device ids are 7 bit values in sysex messages.
+            WrapperWrapped::wrapped(wrapper)
+                .core
+                .device_id
+                .store(device_id.clamp(0, 127) as u8, Relaxed);
+        })
+    }
+
+    pub extern "C" fn bulk_spacing_tramp(
+        wrapper: &MaxObjWrapper<Self>,
+        _attr: c_void,
+        ac: *mut c_long,
+        av: *mut *mut t_atom,
+    ) -> t_max_err {
+        median::attr::get(ac, av, || {
+            WrapperWrapped::wrapped(wrapper)
+                .core
+                .bulk_spacing
+                .load(Relaxed) as t_atom_long
+        })
+    }
+
+    pub extern "C" fn set_bulk_spacing_tramp(
+        wrapper: &MaxObjWrapper<Self>,
+        _attr: c_void,
+        ac: c_long,
+        av: *mut t_atom,
+    ) -> t_max_err {
+        median::attr::set(ac, av, |spacing: t_atom_long| {
+            WrapperWrapped::wrapped(wrapper)
+                .core
+                .bulk_spacing
+                .store(spacing.max(0) as i64, Relaxed);
+        })
+    }
+
+    pub extern "C" fn live_output_tramp(
+        wrapper: &MaxObjWrapper<Self>,
+        _attr: c_void,
+        ac: *mut c_long,
+        av: *mut *mut t_atom,
+    ) -> t_max_err {
+        median::attr::get(ac, av, || {
+            t_atom_long::from(
+                WrapperWrapped::wrapped(wrapper)
+                    .core
+                    .live_output
+                    .load(Relaxed),
+            )
+        })
+    }
+
+    pub extern "C" fn set_live_output_tramp(
+        wrapper: &MaxObjWrapper<Self>,
+        _attr: c_void,
+        ac: c_long,
+        av: *mut t_atom,
+    ) -> t_max_err {
+        median::attr::set(ac, av, |live_output: t_atom_long| {
+            WrapperWrapped::wrapped(wrapper)
+                .core
+                .live_output
+                .store(live_output != 0, Relaxed);
+        })
+    }
+
+    pub extern "C" fn live_notify_tramp(
+        wrapper: &MaxObjWrapper<Self>,
+        _attr: c_void,
+        ac: *mut c_long,
+        av: *mut *mut t_atom,
+    ) -> t_max_err {
+        median::attr::get(ac, av, || {
+            t_atom_long::from(
+                WrapperWrapped::wrapped(wrapper)
+                    .core
+                    .live_notify
+                    .load(Relaxed),
+            )
+        })
+    }
+
+    pub extern "C" fn set_live_notify_tramp(
+        wrapper: &MaxObjWrapper<Self>,
+        _attr: c_void,
+        ac: c_long,
+        av: *mut t_atom,
+    ) -> t_max_err {
+        median::attr::set(ac, av, |live_notify: t_atom_long| {
+            WrapperWrapped::wrapped(wrapper)
+                .core
+                .live_notify
+                .store(live_notify != 0, Relaxed);
+        })
+    }
+
+    pub extern "C" fn history_depth_tramp(
+        wrapper: &MaxObjWrapper<Self>,
+        _attr: c_void,
+        ac: *mut c_long,
+        av: *mut *mut t_atom,
+    ) -> t_max_err {
+        median::attr::get(ac, av, || {
+            WrapperWrapped::wrapped(wrapper)
+                .core
+                .history
+                .lock_or_recover()
+                .depth() as t_atom_long
+        })
+    }
+
+    pub extern "C" fn set_history_depth_tramp(
+        wrapper: &MaxObjWrapper<Self>,
+        _attr: c_void,
+        ac: c_long,
+        av: *mut t_atom,
+    ) -> t_max_err {
+        median::attr::set(ac, av, |depth: t_atom_long| {
+            WrapperWrapped::wrapped(wrapper)
+                .core
+                .history
+                .lock_or_recover()
+                .set_depth(depth.max(0) as usize);
+        })
+    }
 }